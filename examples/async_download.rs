@@ -1,3 +1,4 @@
+use futures::StreamExt;
 use graph_error::GraphFailure;
 use graph_rs::prelude::*;
 use std::ffi::OsString;
@@ -10,6 +11,8 @@ static USER_ID: &str = "USER_ID";
 async fn main() -> Result<(), GraphFailure> {
     download().await?;
     download_with_format().await?;
+    download_to_writer().await?;
+    download_stream().await?;
     Ok(())
 }
 
@@ -42,3 +45,37 @@ async fn download_with_format() -> Result<(), GraphFailure> {
     println!("{:#?}", path_buf);
     Ok(())
 }
+
+// Write the content of the file to any tokio::io::AsyncWrite
+// instead of a file in a directory.
+async fn download_to_writer() -> Result<(), GraphFailure> {
+    let client = Graph::new_async(ACCESS_TOKEN);
+
+    let download_client = client
+        .v1()
+        .drives(USER_ID)
+        .drive()
+        .download(":/download.txt:", "./examples");
+
+    let mut buf: Vec<u8> = Vec::new();
+    let len = download_client.write_to(&mut buf).await?;
+    println!("{:#?}", len);
+    Ok(())
+}
+
+// Read the content of the file as a stream of bytes.
+async fn download_stream() -> Result<(), GraphFailure> {
+    let client = Graph::new_async(ACCESS_TOKEN);
+
+    let download_client = client
+        .v1()
+        .drives(USER_ID)
+        .drive()
+        .download(":/download.txt:", "./examples");
+
+    let mut stream = download_client.stream().await?;
+    while let Some(bytes) = stream.next().await {
+        println!("{:#?}", bytes?.len());
+    }
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use graph_error::GraphFailure;
+use graph_rs::http::{GraphResponse, UploadContent};
+use graph_rs::models::DriveItem;
+use graph_rs::prelude::*;
+
+// This example shows uploading anything that implements tokio::io::AsyncRead.
+// Content smaller than 4 MiB is uploaded using a single request and larger
+// content is uploaded using an upload session that reads one chunk at a time.
+
+static ACCESS_TOKEN: &str = "ACCESS_TOKEN";
+
+// The file you want to upload.
+static PATH_TO_FILE: &str = "path/to/file/file.ext";
+
+// The path where you wan to place the file in OneDrive
+// including the file name.
+static PATH_IN_ONE_DRIVE: &str = ":/Documents/file.ext:";
+
+#[tokio::main]
+async fn main() -> Result<(), GraphFailure> {
+    let client = Graph::new_async(ACCESS_TOKEN);
+
+    let file = tokio::fs::File::open(PATH_TO_FILE).await?;
+    let len = file.metadata().await?.len();
+
+    let drive_item: GraphResponse<DriveItem> = client
+        .v1()
+        .me()
+        .drive()
+        .upload_replace_content(PATH_IN_ONE_DRIVE, UploadContent::async_reader(file, len))
+        .send()
+        .await?;
+
+    println!("{:#?}", drive_item);
+    Ok(())
+}
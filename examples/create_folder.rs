@@ -1,3 +1,4 @@
+use graph_rs::models::DriveItem;
 use graph_rs::prelude::*;
 use std::collections::HashMap;
 
@@ -15,7 +16,7 @@ fn create_new_folder() {
     let client = Graph::new(ACCESS_TOKEN);
     let folder: HashMap<String, serde_json::Value> = HashMap::new();
 
-    let drive_item: GraphResponse<serde_json::Value> = client
+    let drive_item: GraphResponse<DriveItem> = client
         .v1()
         .me()
         .drive()
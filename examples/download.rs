@@ -9,7 +9,9 @@ fn main() {
     download();
     download_and_format("pdf");
     download_and_rename("FILE_NAME");
-    download_by_path(":/Documents/item.txt:")
+    download_by_path(":/Documents/item.txt:");
+    download_resume_and_verify();
+    download_to_writer();
 }
 
 pub fn download() {
@@ -84,6 +86,55 @@ fn download_by_path(path: &str) {
     println!("{:#?}", path_buf.metadata());
 }
 
+// Resume an interrupted download and check the downloaded file
+// against the hashes of the drive item.
+fn download_resume_and_verify() {
+    let client = Graph::new(ACCESS_TOKEN);
+
+    let drive_item = client.v1().me().drive().get_item(ITEM_ID).send().unwrap();
+
+    let download_client = client
+        .v1()
+        .me()
+        .drive()
+        .download(ITEM_ID, "./examples/example_files");
+
+    // If a previous download was interrupted the bytes that were
+    // not downloaded are requested and appended to the file.
+    download_client.resume(true);
+    download_client.on_progress(|written, size| println!("{} of {:?} bytes", written, size));
+
+    if let Some(hashes) = drive_item
+        .body()
+        .file()
+        .as_ref()
+        .and_then(|file| file.hashes().clone())
+    {
+        download_client.verify_hashes(hashes);
+    }
+
+    let path_buf: PathBuf = download_client.send().unwrap();
+
+    println!("{:#?}", path_buf.metadata());
+}
+
+// Write the content of the file to any std::io::Write instead
+// of a file in a directory.
+fn download_to_writer() {
+    let client = Graph::new(ACCESS_TOKEN);
+
+    let download_client = client
+        .v1()
+        .me()
+        .drive()
+        .download(ITEM_ID, "./examples/example_files");
+
+    let mut buf: Vec<u8> = Vec::new();
+    let len = download_client.write_to(&mut buf).unwrap();
+
+    println!("{:#?}", len);
+}
+
 // The default settings for downloading is to create
 // any missing directory. You can change this by passing a
 // download config. This will will fail if the directory does not exist.
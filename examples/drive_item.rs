@@ -1,5 +1,6 @@
 use from_as::*;
 use graph_rs::http::BlockingHttpClient;
+use graph_rs::models::DriveItem;
 use graph_rs::oauth::OAuth;
 use graph_rs::prelude::*;
 use std::convert::TryFrom;
@@ -19,8 +20,7 @@ fn main() {
 }
 
 fn drive_root(graph: &mut Graph<BlockingHttpClient>) {
-    let drive_item: GraphResponse<serde_json::Value> =
-        graph.v1().me().drive().root().send().unwrap();
+    let drive_item: GraphResponse<DriveItem> = graph.v1().me().drive().root().send().unwrap();
     println!("{:#?}", drive_item);
 }
 
@@ -1,3 +1,4 @@
+use graph_rs::models::DriveItem;
 use graph_rs::prelude::*;
 
 fn main() {
@@ -17,7 +18,7 @@ fn get_drive_item(item_id: &str) {
 // The resource_id is the id for this location (sites, users, etc).
 fn get_sites_drive_item(item_id: &str, sites_id: &str) {
     let graph = Graph::new("ACCESS_TOKEN");
-    let drive_item: GraphResponse<serde_json::Value> = graph
+    let drive_item: GraphResponse<DriveItem> = graph
         .v1()
         .sites(sites_id)
         .drive()
@@ -7,6 +7,8 @@ extern crate rocket;
 extern crate serde_json;
 extern crate reqwest;
 use from_as::*;
+use graph_rs::oauth::jwt::{JwtParser, Validation};
+use graph_rs::oauth::jwtkeys::JWTKeys;
 use graph_rs::oauth::{IdToken, OAuth};
 use rocket::Data;
 use rocket_codegen::routes;
@@ -74,6 +76,19 @@ fn redirect(id_token: Data) -> String {
     // and pass the IdToken to OAuth.
     let token: IdToken = IdToken::try_from(s).unwrap();
     println!("IdToken:\n{:#?}\n", token);
+
+    // Verify the signature of the id token using the signing keys from
+    // the Microsoft identity platform and check that the audience and
+    // nonce match the ones used in the authorization request.
+    let keys = JWTKeys::discovery().unwrap();
+    let jwt = JwtParser::parse(token.get_id_token().as_str()).unwrap();
+    let mut validation = Validation::new();
+    validation
+        .issuer("https://login.microsoftonline.com/{tenantid}/v2.0")
+        .audience("<YOUR_CLIENT_ID>")
+        .nonce("7362CAEA-9CA5");
+    jwt.validate(&keys, &validation).unwrap();
+
     let mut oauth = oauth_open_id();
     oauth.id_token(token);
     access_token(&mut oauth);
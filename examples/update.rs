@@ -1,3 +1,4 @@
+use graph_rs::models::DriveItem;
 use graph_rs::prelude::*;
 
 // This example shows choosing a file in the root of a drive (normally where
@@ -21,7 +22,7 @@ fn update() {
     // Fields that are not included will not be changed.
     let value = serde_json::json!({ "name": DRIVE_FILE_NEW_NAME });
 
-    let updated: GraphResponse<serde_json::Value> = graph
+    let updated: GraphResponse<DriveItem> = graph
         .v1()
         .me()
         .drive()
@@ -1,4 +1,5 @@
-use graph_rs::http::GraphResponse;
+use graph_rs::http::{GraphResponse, UploadContent};
+use graph_rs::models::DriveItem;
 use graph_rs::prelude::*;
 
 static ACCESS_TOKEN: &str = "ACCESS_TOKEN";
@@ -20,12 +21,15 @@ fn main() {
     upload_new();
     // Using a drives, sites, groups, or users path.
     sites_upload_new();
+    // Upload bytes in memory or a reader.
+    upload_bytes();
+    upload_reader();
 }
 
 // Uploading a file using the drive id and parent id.
 fn upload_file() {
     let graph = Graph::new(ACCESS_TOKEN);
-    let drive_item: GraphResponse<serde_json::Value> = graph
+    let drive_item: GraphResponse<DriveItem> = graph
         .v1()
         .me()
         .drive()
@@ -39,7 +43,7 @@ fn upload_file() {
 fn upload_new() {
     let graph = Graph::new(ACCESS_TOKEN);
 
-    let drive_item: GraphResponse<serde_json::Value> = graph
+    let drive_item: GraphResponse<DriveItem> = graph
         .v1()
         .me()
         .drive()
@@ -56,7 +60,7 @@ fn sites_upload_new() {
     // Get the latest metadata for the root drive folder items.
     let graph = Graph::new(ACCESS_TOKEN);
 
-    let drive_item: GraphResponse<serde_json::Value> = graph
+    let drive_item: GraphResponse<DriveItem> = graph
         .v1()
         .sites(RESOURCE_ID)
         .drive()
@@ -65,3 +69,38 @@ fn sites_upload_new() {
         .unwrap();
     println!("{:#?}", drive_item);
 }
+
+// Upload bytes in memory to a new file in the parent folder. Content
+// smaller than 4 MiB is uploaded using a single request and larger
+// content is uploaded using an upload session.
+fn upload_bytes() {
+    let graph = Graph::new(ACCESS_TOKEN);
+
+    let content: Vec<u8> = b"Hello, World!".to_vec();
+    let drive_item: GraphResponse<DriveItem> = graph
+        .v1()
+        .me()
+        .drive()
+        .upload_new_content(DRIVE_PARENT_ID, "hello.txt", content)
+        .send()
+        .unwrap();
+    println!("{:#?}", drive_item);
+}
+
+// Upload anything that implements std::io::Read. The length of
+// the content must be known before the upload starts.
+fn upload_reader() {
+    let graph = Graph::new(ACCESS_TOKEN);
+
+    let file = std::fs::File::open(LOCAL_FILE_PATH).unwrap();
+    let len = file.metadata().unwrap().len();
+
+    let drive_item: GraphResponse<DriveItem> = graph
+        .v1()
+        .me()
+        .drive()
+        .upload_replace_content(":/Documents/file.txt:", UploadContent::reader(file, len))
+        .send()
+        .unwrap();
+    println!("{:#?}", drive_item);
+}
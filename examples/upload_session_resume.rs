@@ -0,0 +1,85 @@
+use graph_rs::error::GraphResult;
+use graph_rs::http::{
+    BlockingHttpClient, NextSession, Session, UploadSessionClient, UploadSessionState,
+};
+use graph_rs::prelude::*;
+use std::path::Path;
+
+// This example shows uploading a large file using an upload session
+// that can be resumed if the upload is interrupted or the process exits.
+// The file is read one chunk at a time while it is uploaded.
+// See https://docs.microsoft.com/en-us/onedrive/developer/rest-api/api/driveitem_createuploadsession?view=odsp-graph-online
+
+static ACCESS_TOKEN: &str = "ACCESS_TOKEN";
+
+// The file you want to upload.
+static PATH_TO_FILE: &str = "path/to/file/file.ext";
+
+// The path where you wan to place the file in OneDrive
+// including the file name.
+static PATH_IN_ONE_DRIVE: &str = ":/Documents/file.ext:";
+
+// The file the state of the upload session is saved to.
+static UPLOAD_SESSION_STATE: &str = "./upload_session.json";
+
+fn main() {
+    let session = if Path::new(UPLOAD_SESSION_STATE).exists() {
+        resume_upload_session()
+    } else {
+        new_upload_session()
+    };
+
+    match session {
+        Ok(session) => upload(session),
+        Err(e) => println!("Error: {:#?}", e),
+    }
+}
+
+fn new_upload_session() -> GraphResult<UploadSessionClient<BlockingHttpClient>> {
+    let client = Graph::new(ACCESS_TOKEN);
+
+    let mut upload = Session::default();
+    upload.microsoft_graph_conflict_behavior = Some("rename".into());
+
+    let mut session = client
+        .v1()
+        .me()
+        .drive()
+        .upload_session(PATH_IN_ONE_DRIVE, PATH_TO_FILE, &upload)
+        .send()?;
+
+    // The chunk size must be a multiple of 320 KiB.
+    session.set_chunk_size(320 * 1024 * 16)?;
+
+    // Save the upload url and file so the upload can be resumed later.
+    std::fs::write(UPLOAD_SESSION_STATE, serde_json::to_vec(&session.state())?)?;
+    Ok(session)
+}
+
+// Continue the upload from the nextExpectedRanges of the upload session.
+fn resume_upload_session() -> GraphResult<UploadSessionClient<BlockingHttpClient>> {
+    let state: UploadSessionState = serde_json::from_slice(&std::fs::read(UPLOAD_SESSION_STATE)?)?;
+    UploadSessionClient::resume(state)
+}
+
+fn upload(mut session: UploadSessionClient<BlockingHttpClient>) {
+    // Retry a chunk up to 3 times if the request fails.
+    session.set_chunk_retries(3);
+    session.on_progress(|uploaded, file_size| {
+        println!("Uploaded {} of {} bytes", uploaded, file_size);
+    });
+
+    for next in session {
+        match next {
+            Ok(NextSession::Next(_)) => {},
+            Ok(NextSession::Done(response)) => {
+                println!("Session finished. DriveItem: {:#?}", response.body());
+                std::fs::remove_file(UPLOAD_SESSION_STATE).unwrap();
+            },
+            Err(e) => {
+                // The upload can be resumed by running the example again.
+                println!("Error: {:#?}", e);
+            },
+        }
+    }
+}
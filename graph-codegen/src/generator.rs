@@ -0,0 +1,149 @@
+use crate::model::{ModelParser, ModelSpec};
+use crate::naming::snake_case;
+use crate::openapi::OpenApi;
+use crate::parser::Parser;
+use crate::request::ClientSpec;
+use graph_error::GraphResult;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+// The types in graph_rs::types and the modules they are in.
+static TYPES: [(&str, &str); 3] = [
+    ("Collection", "collection::Collection"),
+    ("Content", "content::Content"),
+    ("DeltaRequest", "delta::DeltaRequest"),
+];
+
+/// Generates the source of request builders and models from
+/// an OpenAPI document.
+///
+/// # Example
+/// ```rust,ignore
+/// let open_api = OpenApi::from_file("openapi.yaml")?;
+/// let generator = Generator::new(&open_api, "/me");
+/// generator.write_to("./generated")?;
+/// ```
+pub struct Generator<'a> {
+    open_api: &'a OpenApi,
+    prefix: String,
+}
+
+impl<'a> Generator<'a> {
+    pub fn new(open_api: &'a OpenApi, prefix: &str) -> Generator<'a> {
+        Generator {
+            open_api,
+            prefix: prefix.to_string(),
+        }
+    }
+
+    pub fn clients(&self) -> Vec<ClientSpec> {
+        Parser::new(self.open_api, &self.prefix).clients()
+    }
+
+    pub fn models(&self) -> Vec<ModelSpec> {
+        ModelParser::new(self.open_api).models()
+    }
+
+    /// The source of a request builder module.
+    pub fn client_module(&self, client: &ClientSpec) -> String {
+        let mut models = BTreeSet::new();
+        let mut types = BTreeSet::new();
+        let mut graph_response = false;
+        for request in client.requests.iter() {
+            let idents = request
+                .response
+                .split(|c: char| c == '<' || c == '>' || c == ',' || c.is_whitespace())
+                .filter(|ident| !ident.is_empty() && !ident.contains("::"));
+            for ident in idents {
+                if ident.eq("GraphResponse") {
+                    graph_response = true;
+                } else if let Some((_, path)) = TYPES.iter().find(|(t, _)| ident.eq(*t)) {
+                    types.insert(path.to_string());
+                } else {
+                    models.insert(ident.to_string());
+                }
+            }
+        }
+
+        let mut s = String::new();
+        s.push_str("use crate::client::Graph;\n");
+        if graph_response {
+            s.push_str("use crate::http::{GraphResponse, IntoResponse};\n");
+        } else {
+            s.push_str("use crate::http::IntoResponse;\n");
+        }
+        if !models.is_empty() {
+            s.push_str(&format!("use crate::models::{};\n", import_list(&models)));
+        }
+        if !types.is_empty() {
+            s.push_str(&format!("use crate::types::{};\n", import_list(&types)));
+        }
+        s.push_str("use handlebars::*;\n");
+        s.push_str("use reqwest::Method;\n\n");
+
+        s.push_str(&format!("register_client!({},);\n\n", client.name));
+        s.push_str(&format!("impl<'a, Client> {}<'a, Client>\n", client.name));
+        s.push_str("where\n");
+        s.push_str("    Client: crate::http::RequestClient,\n");
+        s.push_str("{\n");
+        for request in client.requests.iter() {
+            s.push_str(&format!("    {}\n", request.to_macro()));
+        }
+        s.push_str("}\n");
+        s
+    }
+
+    /// The source of a module containing the models.
+    pub fn models_module(&self, models: &[ModelSpec]) -> String {
+        let mut s = String::new();
+        s.push_str("use serde_json::Value;\n");
+        s.push_str("use std::collections::HashMap;\n");
+        for model in models.iter() {
+            s.push('\n');
+            s.push_str(&model.to_struct());
+        }
+        s
+    }
+
+    /// Write a module for each request builder, a mod.rs that
+    /// declares them and a models.rs containing the models.
+    pub fn write_to<P: AsRef<Path>>(&self, dir: P) -> GraphResult<()> {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir)?;
+
+        let mut modules = Vec::new();
+        for client in self.clients().iter() {
+            let module = snake_case(client.name.trim_end_matches("Request"));
+            fs::write(
+                dir.join(format!("{}.rs", module)),
+                self.client_module(client),
+            )?;
+            modules.push(module);
+        }
+
+        modules.sort();
+        let mut mod_rs = String::new();
+        for module in modules.iter() {
+            mod_rs.push_str(&format!("mod {};\n", module));
+        }
+        mod_rs.push('\n');
+        for module in modules.iter() {
+            mod_rs.push_str(&format!("pub use {}::*;\n", module));
+        }
+        fs::write(dir.join("mod.rs"), mod_rs)?;
+        fs::write(dir.join("models.rs"), self.models_module(&self.models()))?;
+        Ok(())
+    }
+}
+
+fn import_list(idents: &BTreeSet<String>) -> String {
+    if idents.len() == 1 {
+        idents.iter().next().cloned().unwrap_or_default()
+    } else {
+        format!(
+            "{{{}}}",
+            idents.iter().cloned().collect::<Vec<String>>().join(", ")
+        )
+    }
+}
@@ -0,0 +1,39 @@
+//! # Code generation
+//! Generates request builders and models from a Microsoft Graph
+//! OpenAPI document. The request builders use the same macros as
+//! the request builders in graph-rs and the models are in the
+//! style of the models in `graph_rs::models`.
+//!
+//! The Microsoft Graph OpenAPI documents can be found at
+//! https://github.com/microsoftgraph/msgraph-metadata
+//!
+//! # Example
+//! ```rust,ignore
+//! use graph_codegen::{Generator, OpenApi};
+//!
+//! let open_api = OpenApi::from_file("openapi.yaml")?;
+//! let generator = Generator::new(&open_api, "/me");
+//!
+//! for client in generator.clients() {
+//!     println!("{}", generator.client_module(&client));
+//! }
+//!
+//! // Or write the modules to a directory.
+//! generator.write_to("./generated")?;
+//! ```
+#[macro_use]
+extern crate serde_derive;
+
+mod generator;
+mod model;
+mod naming;
+mod openapi;
+mod parser;
+mod request;
+
+pub use generator::*;
+pub use model::*;
+pub use naming::*;
+pub use openapi::*;
+pub use parser::Parser;
+pub use request::*;
@@ -0,0 +1,24 @@
+use graph_codegen::{Generator, OpenApi};
+use std::env;
+use std::process;
+
+static USAGE: &str = "Usage: graph-codegen <openapi file> <resource path> <output directory>
+Example: graph-codegen ./openapi.yaml /me ./generated";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.len() != 3 {
+        eprintln!("{}", USAGE);
+        process::exit(1);
+    }
+
+    let open_api = OpenApi::from_file(&args[0]).unwrap_or_else(|e| {
+        eprintln!("Unable to read {}: {}", args[0], e);
+        process::exit(1);
+    });
+    let generator = Generator::new(&open_api, &args[1]);
+    if let Err(e) = generator.write_to(&args[2]) {
+        eprintln!("Unable to write to {}: {}", args[2], e);
+        process::exit(1);
+    }
+}
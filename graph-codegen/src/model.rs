@@ -0,0 +1,290 @@
+use crate::naming::{camel_case, model_name, snake_case};
+use crate::openapi::OpenApi;
+use crate::parser::{is_object, reference};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+
+static KEYWORDS: [&str; 14] = [
+    "as", "box", "const", "crate", "enum", "final", "fn", "impl", "loop", "match", "mod", "move",
+    "ref", "type",
+];
+
+/// A field of a model.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct FieldSpec {
+    pub name: String,
+    /// The name of the property when it is not the camel case
+    /// of the field name.
+    pub rename: Option<String>,
+    /// The type of the field without the Option.
+    pub field_type: String,
+}
+
+/// A model in the style of the models in `graph_rs::models`.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ModelSpec {
+    pub name: String,
+    /// The name of the schema such as `microsoft.graph.driveItem`.
+    pub schema: String,
+    pub description: Option<String>,
+    pub fields: Vec<FieldSpec>,
+}
+
+impl ModelSpec {
+    pub fn field(&self, name: &str) -> Option<&FieldSpec> {
+        self.fields.iter().find(|field| field.name.eq(name))
+    }
+
+    /// The model as a struct.
+    pub fn to_struct(&self) -> String {
+        let mut s = String::new();
+        if let Some(description) = self.description.as_ref() {
+            for line in wrap(description, 72) {
+                s.push_str(&format!("/// {}\n", line));
+            }
+        }
+        let resource = self.schema.rsplit('.').next().unwrap_or(&self.schema);
+        s.push_str(&format!(
+            "/// [{}](https://docs.microsoft.com/en-us/graph/api/resources/{}?view=graph-rest-1.0)\n",
+            resource,
+            resource.to_lowercase()
+        ));
+        s.push_str(
+            "#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]\n",
+        );
+        s.push_str("#[serde(rename_all = \"camelCase\")]\n");
+        s.push_str("#[set = \"pub\"]\n");
+        s.push_str("#[get = \"pub\"]\n");
+        s.push_str(&format!("pub struct {} {{\n", self.name));
+        s.push_str("    #[serde(rename = \"@odata.type\")]\n");
+        s.push_str("    #[serde(skip_serializing_if = \"Option::is_none\")]\n");
+        s.push_str("    odata_type: Option<String>,\n");
+        for field in self.fields.iter() {
+            if let Some(rename) = field.rename.as_ref() {
+                s.push_str(&format!("    #[serde(rename = \"{}\")]\n", rename));
+            }
+            s.push_str("    #[serde(skip_serializing_if = \"Option::is_none\")]\n");
+            s.push_str(&format!(
+                "    {}: Option<{}>,\n",
+                field.name, field.field_type
+            ));
+        }
+        s.push_str("    #[serde(flatten)]\n");
+        s.push_str("    additional_data: HashMap<String, Value>,\n");
+        s.push_str("}\n");
+        s
+    }
+}
+
+/// Parses the schemas of an OpenAPI document into models.
+///
+/// Schemas that are enums are not models and the fields that use
+/// them are strings. Fields that would make a model contain itself
+/// are boxed.
+pub struct ModelParser<'a> {
+    open_api: &'a OpenApi,
+}
+
+impl<'a> ModelParser<'a> {
+    pub fn new(open_api: &'a OpenApi) -> ModelParser<'a> {
+        ModelParser { open_api }
+    }
+
+    pub fn models(&self) -> Vec<ModelSpec> {
+        let mut models: Vec<ModelSpec> = self
+            .open_api
+            .components
+            .schemas
+            .iter()
+            .filter(|(_, schema)| is_object(schema) && !is_collection_response(schema))
+            .map(|(name, schema)| self.parse(name, schema))
+            .collect();
+        box_recursive_fields(&mut models);
+        models
+    }
+
+    pub fn model(&self, schema: &str) -> Option<ModelSpec> {
+        let name = model_name(schema);
+        self.models().into_iter().find(|model| model.name.eq(&name))
+    }
+
+    fn parse(&self, name: &str, schema: &Value) -> ModelSpec {
+        let model = model_name(name);
+        let mut properties = Vec::new();
+        self.properties(schema, &mut properties);
+
+        let mut fields: Vec<FieldSpec> = Vec::new();
+        for (property, value) in properties.iter() {
+            let field = field_name(&model, property);
+            if field.eq("odata_type") || fields.iter().any(|f| f.name.eq(&field)) {
+                continue;
+            }
+            let rename = if camel_case(&field).ne(property) {
+                Some(property.to_string())
+            } else {
+                None
+            };
+            fields.push(FieldSpec {
+                name: field,
+                rename,
+                field_type: self.field_type(value),
+            });
+        }
+
+        ModelSpec {
+            name: model,
+            schema: name.to_string(),
+            description: self.description(schema),
+            fields,
+        }
+    }
+
+    // The properties of a schema including those of the schemas
+    // in allOf such as microsoft.graph.entity.
+    fn properties(&self, schema: &Value, properties: &mut Vec<(String, Value)>) {
+        let schema = self.open_api.follow(schema);
+        if let Some(all_of) = schema["allOf"].as_array() {
+            for schema in all_of.iter() {
+                self.properties(schema, properties);
+            }
+        }
+        if let Some(map) = schema["properties"].as_object() {
+            for (name, value) in map.iter() {
+                if !properties.iter().any(|(n, _)| n.eq(name)) {
+                    properties.push((name.to_string(), value.clone()));
+                }
+            }
+        }
+    }
+
+    fn description(&self, schema: &Value) -> Option<String> {
+        if let Some(description) = schema["description"].as_str() {
+            return Some(description.to_string());
+        }
+        schema["allOf"]
+            .as_array()?
+            .iter()
+            .find_map(|schema| schema["description"].as_str())
+            .map(|s| s.to_string())
+    }
+
+    fn field_type(&self, schema: &Value) -> String {
+        if let Some(reference) = reference(schema) {
+            return match self.open_api.resolve(reference) {
+                Some(resolved) if is_object(resolved) => {
+                    model_name(reference.rsplit('/').next().unwrap_or(reference))
+                },
+                Some(resolved) => self.field_type(resolved),
+                None => "Value".into(),
+            };
+        }
+
+        match schema["type"].as_str() {
+            Some("string") => "String".into(),
+            Some("boolean") => "bool".into(),
+            Some("integer") => match schema["format"].as_str() {
+                Some("int64") => "i64".into(),
+                _ => "i32".into(),
+            },
+            Some("number") => "f64".into(),
+            Some("array") => format!("Vec<{}>", self.field_type(&schema["items"])),
+            _ => {
+                // Types such as anyOf: [{ type: number }, { type: string }]
+                // use the first type.
+                let first = ["anyOf", "oneOf"]
+                    .iter()
+                    .filter_map(|key| schema[*key].as_array())
+                    .flatten()
+                    .next();
+                match first {
+                    Some(first) => self.field_type(first),
+                    None => "Value".into(),
+                }
+            },
+        }
+    }
+}
+
+fn is_collection_response(schema: &Value) -> bool {
+    schema["properties"]["value"]["type"].as_str() == Some("array") &&
+        schema["properties"]["@odata.nextLink"].is_object()
+}
+
+// The field name of a property. Properties such as
+// @microsoft.graph.downloadUrl use the last segment and
+// keywords are prefixed with the name of the model the same
+// way Event uses event_type.
+fn field_name(model: &str, property: &str) -> String {
+    let last = property.rsplit('.').next().unwrap_or(property);
+    let name = snake_case(last.trim_start_matches('@'));
+    if name.eq("self") {
+        "self_url".into()
+    } else if KEYWORDS.contains(&name.as_str()) {
+        format!("{}_{}", snake_case(model), name)
+    } else {
+        name
+    }
+}
+
+// A model that contains itself, directly or through other models, has
+// an infinite size. Fields that are models and can reach the model they
+// are in are boxed. Fields in a Vec do not need to be.
+fn box_recursive_fields(models: &mut [ModelSpec]) {
+    let names: BTreeSet<String> = models.iter().map(|m| m.name.clone()).collect();
+    let edges: BTreeMap<String, Vec<String>> = models
+        .iter()
+        .map(|model| {
+            let fields = model
+                .fields
+                .iter()
+                .filter(|field| names.contains(&field.field_type))
+                .map(|field| field.field_type.clone())
+                .collect();
+            (model.name.clone(), fields)
+        })
+        .collect();
+
+    for model in models.iter_mut() {
+        for field in model.fields.iter_mut() {
+            if names.contains(&field.field_type) && reaches(&edges, &field.field_type, &model.name)
+            {
+                field.field_type = format!("Box<{}>", field.field_type);
+            }
+        }
+    }
+}
+
+fn reaches(edges: &BTreeMap<String, Vec<String>>, from: &str, to: &str) -> bool {
+    let mut visited = BTreeSet::new();
+    let mut stack = vec![from.to_string()];
+    while let Some(name) = stack.pop() {
+        if name.eq(to) {
+            return true;
+        }
+        if visited.insert(name.clone()) {
+            if let Some(next) = edges.get(&name) {
+                stack.extend(next.iter().cloned());
+            }
+        }
+    }
+    false
+}
+
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        if !line.is_empty() && line.len() + word.len() + 1 > width {
+            lines.push(line);
+            line = String::new();
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(word);
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
@@ -0,0 +1,57 @@
+/// Convert a name such as `ListChildFolders` or `child-folders`
+/// to `list_child_folders`.
+pub fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut s = String::new();
+    for (i, c) in chars.iter().enumerate() {
+        if c.is_ascii_uppercase() {
+            let prev = if i > 0 { chars.get(i - 1) } else { None };
+            let next = chars.get(i + 1);
+            let boundary = match prev {
+                Some(p) if p.is_ascii_lowercase() || p.is_ascii_digit() => true,
+                Some(p) if p.is_ascii_uppercase() => next.map_or(false, |n| n.is_ascii_lowercase()),
+                _ => false,
+            };
+            if boundary && !s.ends_with('_') {
+                s.push('_');
+            }
+            s.push(c.to_ascii_lowercase());
+        } else if c.is_ascii_alphanumeric() {
+            s.push(*c);
+        } else if !s.is_empty() && !s.ends_with('_') {
+            s.push('_');
+        }
+    }
+    s.trim_end_matches('_').to_string()
+}
+
+/// Convert a name such as `contactFolder` or `child_folder`
+/// to `ContactFolder`.
+pub fn pascal_case(name: &str) -> String {
+    name.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// Convert a snake case name such as `download_url` to `downloadUrl`.
+pub fn camel_case(name: &str) -> String {
+    let pascal = pascal_case(name);
+    let mut chars = pascal.chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+/// The name of the model for a schema such as
+/// `microsoft.graph.driveItem`.
+pub fn model_name(schema: &str) -> String {
+    pascal_case(schema.rsplit('.').next().unwrap_or(schema))
+}
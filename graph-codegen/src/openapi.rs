@@ -0,0 +1,113 @@
+use crate::request::HttpMethod;
+use graph_error::GraphResult;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+/// The parts of an OpenAPI document that are used to generate
+/// request builders and models.
+///
+/// The Microsoft Graph OpenAPI documents can be downloaded from
+/// https://github.com/microsoftgraph/msgraph-metadata
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OpenApi {
+    #[serde(default)]
+    pub paths: BTreeMap<String, PathItem>,
+    #[serde(default)]
+    pub components: Components,
+}
+
+impl OpenApi {
+    /// Read an OpenAPI document from a file. Files with a json extension
+    /// are read as JSON and all other files are read as YAML.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> GraphResult<OpenApi> {
+        let path = path.as_ref();
+        let reader = BufReader::new(File::open(path)?);
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => Ok(serde_json::from_reader(reader)?),
+            _ => Ok(serde_yaml::from_reader(reader)?),
+        }
+    }
+
+    pub fn from_yaml_str(s: &str) -> GraphResult<OpenApi> {
+        Ok(serde_yaml::from_str(s)?)
+    }
+
+    /// Get the schema or response that a reference such as
+    /// `#/components/schemas/microsoft.graph.driveItem` points to.
+    pub fn resolve(&self, reference: &str) -> Option<&Value> {
+        let name = reference.rsplit('/').next()?;
+        if reference.starts_with("#/components/schemas/") {
+            self.components.schemas.get(name)
+        } else if reference.starts_with("#/components/responses/") {
+            self.components.responses.get(name)
+        } else {
+            None
+        }
+    }
+
+    /// Follow a `$ref` if the value has one. Otherwise the value
+    /// is returned as is.
+    pub fn follow<'a>(&'a self, value: &'a Value) -> &'a Value {
+        match value["$ref"].as_str() {
+            Some(reference) => self.resolve(reference).unwrap_or(value),
+            None => value,
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PathItem {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub get: Option<Operation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub put: Option<Operation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post: Option<Operation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub patch: Option<Operation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delete: Option<Operation>,
+}
+
+impl PathItem {
+    pub fn operations(&self) -> Vec<(HttpMethod, &Operation)> {
+        let mut operations = Vec::new();
+        let methods = [
+            (HttpMethod::Get, &self.get),
+            (HttpMethod::Put, &self.put),
+            (HttpMethod::Post, &self.post),
+            (HttpMethod::Patch, &self.patch),
+            (HttpMethod::Delete, &self.delete),
+        ];
+        for (method, operation) in methods.iter() {
+            if let Some(operation) = operation {
+                operations.push((*method, operation));
+            }
+        }
+        operations
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Operation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operation_id: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_body: Option<Value>,
+    #[serde(default)]
+    pub responses: BTreeMap<String, Value>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Components {
+    #[serde(default)]
+    pub schemas: BTreeMap<String, Value>,
+    #[serde(default)]
+    pub responses: BTreeMap<String, Value>,
+}
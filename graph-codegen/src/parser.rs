@@ -0,0 +1,316 @@
+use crate::naming::{model_name, pascal_case, snake_case};
+use crate::openapi::{OpenApi, Operation};
+use crate::request::{id_template, ClientSpec, HttpMethod, RequestSpec};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+static CONTENT: &str = "GraphResponse<Content>";
+static VALUE: &str = "serde_json::Value";
+
+/// Parses the paths of an OpenAPI document into request builders.
+///
+/// Only paths under the resource path given, such as `/me` or
+/// `/users/{user-id}`, are used and the resource path is removed
+/// from the request path because the request builders are created
+/// from the me, users, groups, drives and sites clients.
+///
+/// # Example
+/// ```rust,ignore
+/// let open_api = OpenApi::from_file("openapi.yaml")?;
+/// let parser = Parser::new(&open_api, "/me");
+/// for client in parser.clients() {
+///     println!("{:#?}", client);
+/// }
+/// ```
+pub struct Parser<'a> {
+    open_api: &'a OpenApi,
+    prefix: Vec<String>,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(open_api: &'a OpenApi, prefix: &str) -> Parser<'a> {
+        Parser {
+            open_api,
+            prefix: prefix
+                .split('/')
+                .filter(|s| !s.is_empty())
+                .map(|s| s.to_string())
+                .collect(),
+        }
+    }
+
+    /// The request builders grouped by the tag of each operation.
+    pub fn clients(&self) -> Vec<ClientSpec> {
+        let mut clients: BTreeMap<String, ClientSpec> = BTreeMap::new();
+        for (path, item) in self.open_api.paths.iter() {
+            for (method, operation) in item.operations() {
+                if let Some(mut request) = self.request(path, method, operation) {
+                    let name = client_name(operation, &request.path);
+                    let client = clients
+                        .entry(name.clone())
+                        .or_insert_with(|| ClientSpec::new(&name));
+                    // Operation ids such as me.drive.root.ListChildren and
+                    // me.drive.items.ListChildren have the same last segment
+                    // so the segment before it is added to the method name.
+                    // If the name is still taken the operation is skipped.
+                    if client.find(&request.name).is_some() {
+                        match qualified_operation_name(operation) {
+                            Some(name) if client.find(&name).is_none() => request.name = name,
+                            _ => continue,
+                        }
+                    }
+                    client.requests.push(request);
+                }
+            }
+        }
+        clients.into_iter().map(|(_, client)| client).collect()
+    }
+
+    pub fn client(&self, name: &str) -> Option<ClientSpec> {
+        self.clients()
+            .into_iter()
+            .find(|client| client.name.eq(name))
+    }
+
+    /// Parse a single operation. Returns None if the path is not under
+    /// the resource path or the request macros do not support it.
+    pub fn request(
+        &self,
+        path: &str,
+        method: HttpMethod,
+        operation: &Operation,
+    ) -> Option<RequestSpec> {
+        let template = self.path_template(path)?;
+        let has_body = method.allows_body() && operation.request_body.is_some();
+        let ids = template.matches("{{id").count();
+        if ids > 4 || (has_body && ids > 3) {
+            return None;
+        }
+
+        let name = request_name(&operation_name(operation)?, &template);
+        let response = self.response_type(&name, operation);
+        Some(RequestSpec {
+            name,
+            method,
+            path: template,
+            has_body,
+            response,
+        })
+    }
+
+    /// The handlebars template for a path such as
+    /// `/me/contactFolders/{contactFolder-id}/childFolders` which,
+    /// when the resource path is `/me`, is `contactFolders/{{id}}/childFolders`.
+    pub fn path_template(&self, path: &str) -> Option<String> {
+        let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+        if segments.len() <= self.prefix.len() {
+            return None;
+        }
+
+        for (prefix, segment) in self.prefix.iter().zip(segments.iter()) {
+            if is_parameter(prefix) {
+                if !is_parameter(segment) {
+                    return None;
+                }
+            } else if prefix.ne(segment) {
+                return None;
+            }
+        }
+
+        let mut ids = 0;
+        let mut parts = Vec::new();
+        for segment in segments[self.prefix.len()..].iter() {
+            if is_parameter(segment) {
+                ids += 1;
+                parts.push(id_template(ids));
+            } else {
+                let segment = segment.trim_start_matches("microsoft.graph.");
+                if segment.ends_with("()") {
+                    parts.push(segment.trim_end_matches("()").to_string());
+                } else if segment.contains('(') {
+                    // Functions that take parameters are not supported.
+                    return None;
+                } else {
+                    parts.push(segment.to_string());
+                }
+            }
+        }
+        Some(parts.join("/"))
+    }
+
+    /// The response type of an operation such as `Collection<Contact>`.
+    pub fn response_type(&self, name: &str, operation: &Operation) -> String {
+        let response = ["200", "201", "2XX"]
+            .iter()
+            .find_map(|code| operation.responses.get(*code));
+        let response = match response {
+            Some(response) => self.open_api.follow(response),
+            None => return CONTENT.into(),
+        };
+
+        let schema = &response["content"]["application/json"]["schema"];
+        if schema.is_null() {
+            return CONTENT.into();
+        }
+
+        let response_type = self.schema_type(schema);
+        if name.eq("delta") && response_type.starts_with("Collection<") {
+            format!("DeltaRequest<{}>", response_type)
+        } else {
+            response_type
+        }
+    }
+
+    fn schema_type(&self, schema: &Value) -> String {
+        if let Some(items) = collection_items(schema) {
+            return format!("Collection<{}>", self.schema_type(items));
+        }
+
+        if let Some(reference) = reference(schema) {
+            return match self.open_api.resolve(reference) {
+                Some(resolved) if collection_items(resolved).is_some() => {
+                    self.schema_type(resolved)
+                },
+                Some(resolved) if is_object(resolved) => {
+                    model_name(reference.rsplit('/').next().unwrap_or(reference))
+                },
+                _ => VALUE.into(),
+            };
+        }
+        VALUE.into()
+    }
+}
+
+fn is_parameter(segment: &str) -> bool {
+    segment.starts_with('{') && segment.ends_with('}')
+}
+
+// The $ref of a schema or the first $ref in anyOf, allOf or oneOf.
+pub(crate) fn reference(schema: &Value) -> Option<&str> {
+    if let Some(reference) = schema["$ref"].as_str() {
+        return Some(reference);
+    }
+    ["anyOf", "allOf", "oneOf"]
+        .iter()
+        .filter_map(|key| schema[*key].as_array())
+        .flatten()
+        .find_map(|value| value["$ref"].as_str())
+}
+
+// The items of a collection response which is an object with a value
+// array. The value array may also be in one of the allOf schemas.
+fn collection_items(schema: &Value) -> Option<&Value> {
+    let value = &schema["properties"]["value"];
+    if value["type"].as_str() == Some("array") {
+        return Some(&value["items"]);
+    }
+    schema["allOf"]
+        .as_array()?
+        .iter()
+        .find_map(|schema| collection_items(schema))
+}
+
+pub(crate) fn is_object(schema: &Value) -> bool {
+    schema["enum"].is_null() &&
+        (schema["type"].as_str() == Some("object") ||
+            schema["properties"].is_object() ||
+            schema["allOf"].is_array())
+}
+
+// The last segment of the operation id in snake case. An operation id
+// of me.contactFolders.ListChildFolders is list_child_folders.
+fn operation_name(operation: &Operation) -> Option<String> {
+    let operation_id = operation.operation_id.as_ref()?;
+    let name = snake_case(operation_id.rsplit('.').next()?);
+    if name.is_empty() {
+        None
+    } else {
+        Some(name)
+    }
+}
+
+// The method names follow the names of the hand written request builders.
+// An operation on the collection of the request builder is only the verb,
+// so ListContacts on contacts is list and GetContacts on contacts/{{id}}
+// is get. The other operations on a single item of a collection use the
+// singular name of the collection, so GetItems on drive/items/{{id}} is
+// get_item and CreateChildFolders on contactFolders/{{id}}/childFolders
+// is create_child_folder.
+fn request_name(name: &str, template: &str) -> String {
+    let (verb, noun) = match name.find('_') {
+        Some(i) => (&name[..i], &name[i + 1..]),
+        None => return name.to_string(),
+    };
+    if !["list", "get", "create", "update", "delete"].contains(&verb) {
+        return name.to_string();
+    }
+
+    let segments: Vec<&str> = template.split('/').collect();
+    let collections: Vec<&str> = segments
+        .iter()
+        .filter(|segment| !segment.starts_with("{{"))
+        .cloned()
+        .collect();
+    if collections.len() == 1 && snake_case(collections[0]).eq(noun) {
+        return verb.to_string();
+    }
+    if verb.eq("list") {
+        return name.to_string();
+    }
+
+    let mut noun = format!("_{}_", noun);
+    for (i, segment) in segments.iter().enumerate() {
+        let single_item = match segments.get(i + 1) {
+            Some(next) => next.starts_with("{{"),
+            None => verb.eq("create"),
+        };
+        if single_item && !segment.starts_with("{{") {
+            let collection = snake_case(segment);
+            noun = noun.replacen(
+                &format!("_{}_", collection),
+                &format!("_{}_", singular(&collection)),
+                1,
+            );
+        }
+    }
+    format!("{}_{}", verb, noun.trim_matches('_'))
+}
+
+// The singular of the last word of a collection name such as child_folders.
+fn singular(name: &str) -> String {
+    if name.ends_with("ies") {
+        format!("{}y", &name[..name.len() - 3])
+    } else if name.ends_with('s') && !name.ends_with("ss") {
+        name[..name.len() - 1].to_string()
+    } else {
+        name.to_string()
+    }
+}
+
+// The last two segments of the operation id in snake case without the
+// list verb. An operation id of me.drive.root.ListChildren is root_children
+// which is the name the hand written drive request builder uses.
+fn qualified_operation_name(operation: &Operation) -> Option<String> {
+    let operation_id = operation.operation_id.as_ref()?;
+    let mut segments = operation_id.rsplit('.');
+    let last = snake_case(segments.next()?);
+    let parent = snake_case(segments.next()?);
+    if last.starts_with("list_") {
+        Some(format!("{}_{}", parent, &last[5..]))
+    } else {
+        Some(format!("{}_{}", parent, last))
+    }
+}
+
+// The name of the request builder is the last segment of the tag. A tag
+// of me.contactFolder is ContactFolderRequest. Operations without a tag
+// use the first segment of the path.
+fn client_name(operation: &Operation, path: &str) -> String {
+    let name = operation
+        .tags
+        .first()
+        .and_then(|tag| tag.rsplit('.').next())
+        .or_else(|| path.split('/').next())
+        .unwrap_or_default();
+    format!("{}Request", pascal_case(name))
+}
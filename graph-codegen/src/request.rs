@@ -0,0 +1,127 @@
+use std::fmt;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum HttpMethod {
+    Get,
+    Put,
+    Post,
+    Patch,
+    Delete,
+}
+
+impl HttpMethod {
+    /// The name of the macro that registers a request using this method.
+    pub fn macro_name(self) -> &'static str {
+        match self {
+            HttpMethod::Get => "get",
+            HttpMethod::Put => "put",
+            HttpMethod::Post => "post",
+            HttpMethod::Patch => "patch",
+            HttpMethod::Delete => "delete",
+        }
+    }
+
+    /// Whether the request macros for this method can take a body.
+    pub fn allows_body(self) -> bool {
+        match self {
+            HttpMethod::Put | HttpMethod::Post | HttpMethod::Patch => true,
+            HttpMethod::Get | HttpMethod::Delete => false,
+        }
+    }
+}
+
+impl fmt::Display for HttpMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.macro_name())
+    }
+}
+
+/// A single request of a request builder such as
+/// `get!( | get, Contact => "contacts/{{id}}" );`
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RequestSpec {
+    /// The name of the method on the request builder.
+    pub name: String,
+    pub method: HttpMethod,
+    /// The handlebars template of the path. Ids are passed to
+    /// the template as id, id2, id3 and id4.
+    pub path: String,
+    pub has_body: bool,
+    /// The response type such as `Collection<Contact>`.
+    pub response: String,
+}
+
+impl RequestSpec {
+    /// The number of ids the request takes.
+    pub fn ids(&self) -> usize {
+        self.path.matches("{{id").count()
+    }
+
+    /// The request as a call to one of the request macros.
+    pub fn to_macro(&self) -> String {
+        let ids = "|".repeat(self.ids());
+        let name = if ids.is_empty() {
+            self.name.clone()
+        } else {
+            format!("{} {}", ids, self.name)
+        };
+
+        if self.has_body {
+            format!(
+                "{}!( [ {}, {} => \"{}\" ] );",
+                self.method.macro_name(),
+                name,
+                self.response,
+                self.path
+            )
+        } else {
+            format!(
+                "{}!( {}, {} => \"{}\" );",
+                self.method.macro_name(),
+                name,
+                self.response,
+                self.path
+            )
+        }
+    }
+
+    /// Render the path with the given ids the same way the
+    /// request builder does.
+    pub fn render_path(&self, ids: &[&str]) -> String {
+        let mut path = self.path.clone();
+        for (i, id) in ids.iter().enumerate() {
+            path = path.replace(&id_template(i + 1), id);
+        }
+        path
+    }
+}
+
+/// A request builder and the requests it registers.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ClientSpec {
+    /// The name of the request builder such as `ContactRequest`.
+    pub name: String,
+    pub requests: Vec<RequestSpec>,
+}
+
+impl ClientSpec {
+    pub fn new(name: &str) -> ClientSpec {
+        ClientSpec {
+            name: name.into(),
+            requests: Vec::new(),
+        }
+    }
+
+    pub fn find(&self, name: &str) -> Option<&RequestSpec> {
+        self.requests.iter().find(|request| request.name.eq(name))
+    }
+}
+
+/// The template variable of the nth id of a path starting from 1.
+pub(crate) fn id_template(n: usize) -> String {
+    if n == 1 {
+        "{{id}}".to_string()
+    } else {
+        format!("{{{{id{}}}}}", n)
+    }
+}
@@ -119,6 +119,12 @@ impl GraphError {
             .clone()
     }
 
+    /// The number of seconds to wait before retrying the request
+    /// when the response includes a Retry-After header.
+    pub fn retry_after(&self) -> Option<u64> {
+        self.headers.as_ref()?.retry_after()
+    }
+
     pub fn detailed_error_code(&self) -> Option<String> {
         self.error_message
             .error
@@ -183,10 +189,12 @@ pub enum ErrorType {
     UnsupportedMediaType,
     RequestRangeNotSatisfiable,
     UnprocessableEntity,
+    FailedDependency,
     TooManyRequests,
     InternalServerError,
     NotImplemented,
     ServiceUnavailable,
+    GatewayTimeout,
     InsufficientStorage,
     BandwidthLimitExceeded,
     UnknownError,
@@ -211,10 +219,12 @@ impl ErrorType {
             ErrorType::UnsupportedMediaType => "The content type of the request is a format that is not supported by the service.",
             ErrorType::RequestRangeNotSatisfiable => "The specified byte range is invalid or unavailable.",
             ErrorType::UnprocessableEntity => "Cannot process the request because it is semantically incorrect.",
+            ErrorType::FailedDependency => "The request in a batch was not processed because a request it depends on failed.",
             ErrorType::TooManyRequests => "Client application has been throttled and should not attempt to repeat the request until an amount of time has elapsed.",
             ErrorType::InternalServerError => "There was an internal server error while processing the request.",
             ErrorType::NotImplemented => "The requested feature isn’t implemented.",
             ErrorType::ServiceUnavailable => "The service is temporarily unavailable. You may repeat the request after a delay. There may be a Retry-After header.",
+            ErrorType::GatewayTimeout => "The server, while acting as a proxy, did not receive a timely response from the upstream server it needed to access in attempting to complete the request. May occur together with 503.",
             ErrorType::InsufficientStorage => "The maximum storage quota has been reached.",
             ErrorType::BandwidthLimitExceeded => "Your app has been throttled for exceeding the maximum bandwidth cap. Your app can retry the request again after more time has elapsed.",
             ErrorType::UnknownError => "Unknown error or failure",
@@ -237,10 +247,12 @@ impl ErrorType {
             415 => Some(ErrorType::UnsupportedMediaType),
             416 => Some(ErrorType::RequestRangeNotSatisfiable),
             422 => Some(ErrorType::UnprocessableEntity),
+            424 => Some(ErrorType::FailedDependency),
             429 => Some(ErrorType::TooManyRequests),
             500 => Some(ErrorType::InternalServerError),
             501 => Some(ErrorType::NotImplemented),
             503 => Some(ErrorType::ServiceUnavailable),
+            504 => Some(ErrorType::GatewayTimeout),
             507 => Some(ErrorType::InsufficientStorage),
             509 => Some(ErrorType::BandwidthLimitExceeded),
             _ => None,
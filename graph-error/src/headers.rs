@@ -1,4 +1,4 @@
-use reqwest::header::HeaderMap;
+use reqwest::header::{HeaderMap, RETRY_AFTER, WWW_AUTHENTICATE};
 
 #[allow(dead_code)]
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -41,6 +41,55 @@ pub struct GraphHeaders {
     header_map: HeaderMap,
 }
 
+impl GraphHeaders {
+    pub fn url(&self) -> &str {
+        self.url.as_str()
+    }
+
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    pub fn header_map(&self) -> &HeaderMap {
+        &self.header_map
+    }
+
+    /// The number of seconds given in the Retry-After header. Graph
+    /// returns this header on throttled (429) and unavailable (503)
+    /// responses. Only the delay-seconds form of the header is supported.
+    pub fn retry_after(&self) -> Option<u64> {
+        self.header_map
+            .get(RETRY_AFTER)?
+            .to_str()
+            .ok()?
+            .trim()
+            .parse()
+            .ok()
+    }
+
+    /// Whether the request failed with a 401 because the access token was
+    /// expired or otherwise invalid. The WWW-Authenticate header of these
+    /// responses includes the error code invalid_token.
+    pub fn is_invalid_token(&self) -> bool {
+        self.status == 401 &&
+            self.header_map
+                .get(WWW_AUTHENTICATE)
+                .and_then(|value| value.to_str().ok())
+                .map(|value| value.contains("invalid_token"))
+                .unwrap_or_default()
+    }
+}
+
+impl From<HeaderMap> for GraphHeaders {
+    fn from(header_map: HeaderMap) -> Self {
+        GraphHeaders {
+            url: Default::default(),
+            status: Default::default(),
+            header_map,
+        }
+    }
+}
+
 impl From<reqwest::blocking::Response> for GraphHeaders {
     fn from(r: reqwest::blocking::Response) -> Self {
         GraphHeaders {
@@ -12,6 +12,17 @@ pub enum GraphRsError {
     DownloadFileExists { name: String },
     #[snafu(display("Could not determine file name or the file name exceeded 255 characters"))]
     DownloadFileName,
+    #[snafu(display(
+        "The {} of the downloaded file does not match. Expected {} but found {}",
+        hash,
+        expected,
+        found
+    ))]
+    DownloadHashMismatch {
+        hash: String,
+        expected: String,
+        found: String,
+    },
     #[snafu(display("File name has invalid characters. Must be UTF-8"))]
     FileNameInvalidUTF8,
     #[snafu(display("Missing or invalid: Error: {}", msg))]
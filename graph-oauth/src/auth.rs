@@ -1,10 +1,16 @@
 use crate::accesstoken::AccessToken;
+use crate::authority::{Authority, AzureCloud};
+use crate::clientcertificate::{ClientCertificate, CLIENT_ASSERTION_TYPE};
+use crate::devicecode::{DeviceCode, DeviceCodePoller, DEVICE_CODE_GRANT_TYPE};
 use crate::grants::{GrantRequest, GrantType};
 use crate::idtoken::IdToken;
+use crate::jwt::JwtParser;
+use crate::loopback::LoopbackListener;
 use crate::oautherror::OAuthError;
 use crate::strum::IntoEnumIterator;
+use crate::tokencache::{TokenCache, TokenCacheHandle, TokenCacheKey};
 use from_as::*;
-use graph_error::GraphFailure;
+use graph_error::{GraphError, GraphFailure};
 use ring::rand::SecureRandom;
 use serde::export::PhantomData;
 use std::collections::btree_map::BTreeMap;
@@ -12,11 +18,15 @@ use std::collections::{BTreeSet, HashMap};
 use std::convert::TryFrom;
 use std::fmt;
 use std::process::Output;
+use std::sync::Arc;
 use url::form_urlencoded::Serializer;
 use url::Url;
 
 pub type OAuthReq<T> = Result<T, GraphFailure>;
 
+/// The grant type sent to the token endpoint for the on behalf of grant.
+pub(crate) const ON_BEHALF_OF_GRANT_TYPE: &str = "urn:ietf:params:oauth:grant-type:jwt-bearer";
+
 /// OAuthCredential list fields representing common OAuth credentials needed
 /// to perform authentication in various formats such as token flow and
 /// client credentials flow.
@@ -68,6 +78,10 @@ pub enum OAuthCredential {
     AdminConsent,
     Username,
     Password,
+    DeviceCodeURL,
+    DeviceCode,
+    Assertion,
+    RequestedTokenUse,
 }
 
 impl OAuthCredential {
@@ -104,6 +118,10 @@ impl OAuthCredential {
             OAuthCredential::AdminConsent => "admin_consent",
             OAuthCredential::Username => "username",
             OAuthCredential::Password => "password",
+            OAuthCredential::DeviceCodeURL => "device_code_url",
+            OAuthCredential::DeviceCode => "device_code",
+            OAuthCredential::Assertion => "assertion",
+            OAuthCredential::RequestedTokenUse => "requested_token_use",
         }
     }
 
@@ -117,6 +135,8 @@ impl OAuthCredential {
             OAuthCredential::CodeVerifier |
             OAuthCredential::CodeChallenge |
             OAuthCredential::Password |
+            OAuthCredential::DeviceCode |
+            OAuthCredential::Assertion |
             OAuthCredential::AccessCode => true,
             _ => false,
         }
@@ -156,6 +176,12 @@ pub struct OAuth {
     access_token: Option<AccessToken>,
     scopes: BTreeSet<String>,
     credentials: BTreeMap<String, String>,
+    #[serde(skip)]
+    token_cache: TokenCacheHandle,
+    #[serde(skip)]
+    client_certificate: Option<Arc<ClientCertificate>>,
+    #[serde(default)]
+    azure_cloud: AzureCloud,
 }
 
 impl OAuth {
@@ -172,6 +198,9 @@ impl OAuth {
             access_token: None,
             scopes: BTreeSet::new(),
             credentials: BTreeMap::new(),
+            token_cache: Default::default(),
+            client_certificate: None,
+            azure_cloud: AzureCloud::Global,
         }
     }
 
@@ -196,6 +225,7 @@ impl OAuth {
             OAuthCredential::PostLogoutRedirectURI |
             OAuthCredential::AccessTokenURL |
             OAuthCredential::AuthorizeURL |
+            OAuthCredential::DeviceCodeURL |
             OAuthCredential::LogoutURL => {
                 Url::parse(v.as_ref()).unwrap();
             },
@@ -225,6 +255,7 @@ impl OAuth {
             OAuthCredential::PostLogoutRedirectURI |
             OAuthCredential::AccessTokenURL |
             OAuthCredential::AuthorizeURL |
+            OAuthCredential::DeviceCodeURL |
             OAuthCredential::LogoutURL => {
                 Url::parse(v.as_ref()).unwrap();
             },
@@ -682,6 +713,108 @@ impl OAuth {
         self.insert(OAuthCredential::Password, value)
     }
 
+    /// Set the authorize, access token, refresh token, logout and device
+    /// code urls using the authority and the Azure cloud it is in.
+    ///
+    /// The cloud is also used by the Graph client to choose the host
+    /// for requests when it is created from OAuth.
+    ///
+    /// # Example
+    /// ```
+    /// # use graph_oauth::oauth::{Authority, AzureCloud, OAuth, OAuthCredential};
+    /// # let mut oauth = OAuth::new();
+    /// oauth.authority(&Authority::Tenant("tenant_id".into()), AzureCloud::UsGovernment);
+    /// assert_eq!(
+    ///     oauth.get(OAuthCredential::AccessTokenURL),
+    ///     Some("https://login.microsoftonline.us/tenant_id/oauth2/v2.0/token".to_string())
+    /// );
+    /// assert_eq!(oauth.get_azure_cloud(), AzureCloud::UsGovernment);
+    /// ```
+    pub fn authority(&mut self, authority: &Authority, cloud: AzureCloud) -> &mut OAuth {
+        self.azure_cloud = cloud;
+        self.authorize_url(authority.authorize_url(cloud).as_str())
+            .access_token_url(authority.token_url(cloud).as_str())
+            .refresh_token_url(authority.token_url(cloud).as_str())
+            .logout_url(authority.logout_url(cloud).as_str());
+
+        // The device code grant is not supported by Azure AD B2C.
+        match authority {
+            Authority::B2C { .. } => self.remove(OAuthCredential::DeviceCodeURL),
+            _ => self.device_code_url(authority.device_code_url(cloud).as_str()),
+        }
+    }
+
+    /// Get the Azure cloud set by the authority. Defaults to the global cloud.
+    ///
+    /// # Example
+    /// ```
+    /// # use graph_oauth::oauth::{AzureCloud, OAuth};
+    /// # let oauth = OAuth::new();
+    /// assert_eq!(oauth.get_azure_cloud(), AzureCloud::Global);
+    /// ```
+    pub fn get_azure_cloud(&self) -> AzureCloud {
+        self.azure_cloud
+    }
+
+    /// Set the device code url used to request a device code
+    /// for the device code grant.
+    ///
+    /// # Example
+    /// ```
+    /// # use graph_oauth::oauth::OAuth;
+    /// # let mut oauth = OAuth::new();
+    /// oauth.device_code_url("https://login.microsoftonline.com/common/oauth2/v2.0/devicecode");
+    /// ```
+    pub fn device_code_url(&mut self, value: &str) -> &mut OAuth {
+        self.insert(OAuthCredential::DeviceCodeURL, value)
+    }
+
+    /// Set the device code returned by a device authorization request.
+    /// This is set when polling for an access token using the device
+    /// code grant and does not usually need to be set by the caller.
+    ///
+    /// # Example
+    /// ```
+    /// # use graph_oauth::oauth::{OAuth, OAuthCredential};
+    /// # let mut oauth = OAuth::new();
+    /// oauth.device_code("device_code");
+    /// assert!(oauth.contains(OAuthCredential::DeviceCode))
+    /// ```
+    pub fn device_code(&mut self, value: &str) -> &mut OAuth {
+        self.insert(OAuthCredential::DeviceCode, value)
+    }
+
+    /// Set the assertion for the on behalf of grant. This is the access
+    /// token that was sent to the API making the on behalf of request.
+    ///
+    /// # Example
+    /// ```
+    /// # use graph_oauth::oauth::{OAuth, OAuthCredential};
+    /// # let mut oauth = OAuth::new();
+    /// oauth.assertion("assertion");
+    /// assert!(oauth.contains(OAuthCredential::Assertion))
+    /// ```
+    pub fn assertion(&mut self, value: &str) -> &mut OAuth {
+        self.insert(OAuthCredential::Assertion, value)
+    }
+
+    /// Set the certificate used to sign client assertions for the client
+    /// credentials and authorization code grants. A new client assertion
+    /// is signed for each access token request and is sent in place of
+    /// the client secret.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// # use graph_oauth::oauth::{ClientCertificate, OAuth};
+    /// # let mut oauth = OAuth::new();
+    /// let certificate = ClientCertificate::from_pem(&certificate, &private_key)?;
+    /// oauth.client_certificate(certificate);
+    /// ```
+    pub fn client_certificate(&mut self, certificate: ClientCertificate) -> &mut OAuth {
+        self.client_certificate = Some(Arc::new(certificate));
+        self
+    }
+
     /// Add a scope' for the OAuth URL.
     ///
     /// # Example
@@ -860,6 +993,34 @@ impl OAuth {
         }
     }
 
+    /// Set the token cache that access token requests read from before
+    /// sending a request to the token endpoint and write to after
+    /// an access token is returned. The token cache is not
+    /// serialized with OAuth.
+    ///
+    /// # Example
+    /// ```
+    /// # use graph_oauth::oauth::{OAuth, InMemoryTokenCache};
+    /// let mut oauth = OAuth::new();
+    /// oauth.token_cache(InMemoryTokenCache::new());
+    /// ```
+    pub fn token_cache<T: TokenCache + 'static>(&mut self, token_cache: T) -> &mut OAuth {
+        self.token_cache = TokenCacheHandle::new(Arc::new(token_cache));
+        self
+    }
+
+    /// Set a token cache that is shared with other OAuth instances.
+    pub fn token_cache_arc(&mut self, token_cache: Arc<dyn TokenCache>) -> &mut OAuth {
+        self.token_cache = TokenCacheHandle::new(token_cache);
+        self
+    }
+
+    /// Get the key that access tokens for this OAuth instance
+    /// are stored under in the token cache.
+    pub fn token_cache_key(&self) -> TokenCacheKey {
+        TokenCacheKey::from(self)
+    }
+
     pub fn build(&mut self) -> GrantSelector<AccessTokenGrant> {
         GrantSelector {
             oauth: self.clone(),
@@ -935,6 +1096,46 @@ impl OAuth {
         self.get(c).ok_or_else(|| OAuthError::credential_error(c))
     }
 
+    // The client certificate is only used by grants that can authenticate
+    // the client using a client assertion.
+    fn client_certificate_for(&self, grant: GrantType) -> Option<Arc<ClientCertificate>> {
+        match grant {
+            GrantType::ClientCredentials | GrantType::AuthorizationCode | GrantType::OnBehalfOf => {
+                self.client_certificate.clone()
+            },
+            _ => None,
+        }
+    }
+
+    // Set the redirect uri of the loopback listener and the PKCE code
+    // challenge and state if they have not been set. Returns the state
+    // that the redirect must match.
+    fn loopback_redirect(
+        &mut self,
+        grant: GrantType,
+        listener: &LoopbackListener,
+    ) -> OAuthReq<String> {
+        if grant != GrantType::AuthorizationCode {
+            return OAuthError::grant_error(
+                grant,
+                GrantRequest::Authorization,
+                "Loopback authorization is only supported for the authorization code grant",
+            );
+        }
+
+        self.redirect_uri(listener.redirect_uri().as_str())
+            .response_mode("query");
+        if !self.contains(OAuthCredential::CodeVerifier) {
+            self.generate_sha256_challenge_and_verifier()?;
+        }
+        if !self.contains(OAuthCredential::State) {
+            let mut buf = [0; 16];
+            ring::rand::SystemRandom::new().fill(&mut buf)?;
+            self.state(base64::encode_config(&buf, base64::URL_SAFE_NO_PAD).as_str());
+        }
+        self.get_or_else(OAuthCredential::State)
+    }
+
     fn form_encode_credentials(
         &mut self,
         pairs: Vec<OAuthCredential>,
@@ -1143,6 +1344,39 @@ impl OAuth {
                 );
                 Ok(encoder.finish())
             }
+            // The device authorization request is a post request so the
+            // credentials are returned as a form body instead of a url.
+            GrantType::DeviceCode => {
+                self.pre_request_check(GrantType::DeviceCode, request_type);
+                if request_type == GrantRequest::RefreshToken {
+                    encoder.append_pair("refresh_token", &self.get_refresh_token()?);
+                }
+                self.form_encode_credentials(
+                    GrantType::DeviceCode.available_credentials(request_type),
+                    &mut encoder,
+                );
+                Ok(encoder.finish())
+            }
+            GrantType::OnBehalfOf => match request_type {
+                GrantRequest::Authorization => {
+                    OAuthError::grant_error(
+                        GrantType::OnBehalfOf,
+                        GrantRequest::Authorization,
+                        "Grant type does not use request type. The on behalf of grant exchanges an existing access token"
+                    )
+                },
+                GrantRequest::AccessToken | GrantRequest::RefreshToken => {
+                    self.pre_request_check(GrantType::OnBehalfOf, request_type);
+                    if request_type == GrantRequest::RefreshToken {
+                        encoder.append_pair("refresh_token", &self.get_refresh_token()?);
+                    }
+                    self.form_encode_credentials(
+                        GrantType::OnBehalfOf.available_credentials(request_type),
+                        &mut encoder,
+                    );
+                    Ok(encoder.finish())
+                },
+            },
         }
     }
 
@@ -1205,6 +1439,25 @@ impl OAuth {
                     let _ = self.entry(OAuthCredential::GrantType, "password");
                 }
             },
+            GrantType::DeviceCode => {
+                if request_type.eq(&GrantRequest::AccessToken) {
+                    let _ = self.entry(OAuthCredential::GrantType, DEVICE_CODE_GRANT_TYPE);
+                } else if request_type.eq(&GrantRequest::RefreshToken) {
+                    let _ = self.entry(OAuthCredential::GrantType, "refresh_token");
+                }
+            },
+            // The grant type is replaced because the same grant is used
+            // to request the access token and then refresh it.
+            GrantType::OnBehalfOf => match request_type {
+                GrantRequest::Authorization => {},
+                GrantRequest::AccessToken => {
+                    self.insert(OAuthCredential::GrantType, ON_BEHALF_OF_GRANT_TYPE);
+                    let _ = self.entry(OAuthCredential::RequestedTokenUse, "on_behalf_of");
+                },
+                GrantRequest::RefreshToken => {
+                    self.insert(OAuthCredential::GrantType, "refresh_token");
+                },
+            },
         }
     }
 }
@@ -1363,6 +1616,36 @@ impl GrantSelector<AccessTokenGrant> {
             grant: GrantType::ResourceOwnerPasswordCredentials,
         }
     }
+
+    /// Create a new instance for the device code grant.
+    ///
+    /// # See
+    /// [Microsoft Device Code Flow](https://docs.microsoft.com/en-us/azure/active-directory/develop/v2-oauth2-device-code)
+    ///
+    /// # Example
+    /// ```
+    /// # use graph_oauth::oauth::OAuth;
+    /// # let mut oauth = OAuth::new();
+    /// let device_code = oauth.build().device_code();
+    /// ```
+    pub fn device_code(self) -> DeviceCodeGrant {
+        DeviceCodeGrant { oauth: self.oauth }
+    }
+
+    /// Create a new instance for the on behalf of grant.
+    ///
+    /// # See
+    /// [Microsoft On Behalf Of Flow](https://docs.microsoft.com/en-us/azure/active-directory/develop/v2-oauth2-on-behalf-of-flow)
+    ///
+    /// # Example
+    /// ```
+    /// # use graph_oauth::oauth::OAuth;
+    /// # let mut oauth = OAuth::new();
+    /// let on_behalf_of = oauth.build().on_behalf_of();
+    /// ```
+    pub fn on_behalf_of(self) -> OnBehalfOfGrant {
+        OnBehalfOfGrant { oauth: self.oauth }
+    }
 }
 
 impl GrantSelector<AsyncAccessTokenGrant> {
@@ -1491,6 +1774,36 @@ impl GrantSelector<AsyncAccessTokenGrant> {
             grant: GrantType::ResourceOwnerPasswordCredentials,
         }
     }
+
+    /// Create a new instance for the device code grant.
+    ///
+    /// # See
+    /// [Microsoft Device Code Flow](https://docs.microsoft.com/en-us/azure/active-directory/develop/v2-oauth2-device-code)
+    ///
+    /// # Example
+    /// ```
+    /// # use graph_oauth::oauth::OAuth;
+    /// # let mut oauth = OAuth::new();
+    /// let device_code = oauth.build_async().device_code();
+    /// ```
+    pub fn device_code(self) -> AsyncDeviceCodeGrant {
+        AsyncDeviceCodeGrant { oauth: self.oauth }
+    }
+
+    /// Create a new instance for the on behalf of grant.
+    ///
+    /// # See
+    /// [Microsoft On Behalf Of Flow](https://docs.microsoft.com/en-us/azure/active-directory/develop/v2-oauth2-on-behalf-of-flow)
+    ///
+    /// # Example
+    /// ```
+    /// # use graph_oauth::oauth::OAuth;
+    /// # let mut oauth = OAuth::new();
+    /// let on_behalf_of = oauth.build_async().on_behalf_of();
+    /// ```
+    pub fn on_behalf_of(self) -> AsyncOnBehalfOfGrant {
+        AsyncOnBehalfOfGrant { oauth: self.oauth }
+    }
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, AsFile, FromFile)]
@@ -1508,15 +1821,51 @@ impl AuthorizationRequest {
 pub struct AccessTokenRequest {
     uri: String,
     params: HashMap<String, String>,
+    #[serde(skip)]
+    token_cache: TokenCacheHandle,
+    #[serde(skip)]
+    cache_key: TokenCacheKey,
+    #[serde(skip)]
+    read_cache: bool,
+    #[serde(skip)]
+    client_certificate: Option<Arc<ClientCertificate>>,
 }
 
 impl AccessTokenRequest {
+    /// Set whether an access token that has not expired in the token
+    /// cache is returned instead of sending the request. Requests for
+    /// refresh tokens never read from the token cache.
+    pub fn read_cache(&mut self, value: bool) -> &mut AccessTokenRequest {
+        self.read_cache = value;
+        self
+    }
+
+    // Client assertions expire so a new one is signed each time the
+    // request is sent.
+    fn sign_client_assertion(&mut self) -> OAuthReq<()> {
+        sign_client_assertion(
+            self.client_certificate.as_ref(),
+            self.uri.as_str(),
+            &mut self.params,
+        )
+    }
+
     /// Send the request for an access token. The response body
     /// be will converted to an access token and returned.
+    ///
+    /// If a token cache was set on OAuth and it has an access token that
+    /// has not expired the cached access token is returned instead.
     pub fn send(&mut self) -> OAuthReq<AccessToken> {
+        if let Some(access_token) = self.token_cache.cached(&self.cache_key, self.read_cache)? {
+            return Ok(access_token);
+        }
+
+        self.sign_client_assertion()?;
         let client = reqwest::blocking::Client::new();
         let builder = client.post(self.uri.as_str()).form(&self.params);
-        AccessToken::try_from(builder)
+        let access_token = AccessToken::try_from(builder)?;
+        self.token_cache.store(&self.cache_key, &access_token)?;
+        Ok(access_token)
     }
 
     /// Send the request for an access token. This method
@@ -1527,6 +1876,7 @@ impl AccessTokenRequest {
     where
         for<'de> T: serde::Deserialize<'de>,
     {
+        self.sign_client_assertion()?;
         let client = reqwest::blocking::Client::new();
         let builder = client.post(self.uri.as_str()).form(&self.params);
         let response = builder.send()?;
@@ -1538,15 +1888,49 @@ impl AccessTokenRequest {
 pub struct AsyncAccessTokenRequest {
     uri: String,
     params: HashMap<String, String>,
+    #[serde(skip)]
+    token_cache: TokenCacheHandle,
+    #[serde(skip)]
+    cache_key: TokenCacheKey,
+    #[serde(skip)]
+    read_cache: bool,
+    #[serde(skip)]
+    client_certificate: Option<Arc<ClientCertificate>>,
 }
 
 impl AsyncAccessTokenRequest {
+    /// Set whether an access token that has not expired in the token
+    /// cache is returned instead of sending the request. Requests for
+    /// refresh tokens never read from the token cache.
+    pub fn read_cache(&mut self, value: bool) -> &mut AsyncAccessTokenRequest {
+        self.read_cache = value;
+        self
+    }
+
+    fn sign_client_assertion(&mut self) -> OAuthReq<()> {
+        sign_client_assertion(
+            self.client_certificate.as_ref(),
+            self.uri.as_str(),
+            &mut self.params,
+        )
+    }
+
     /// Send the request for an access token. The response body
     /// be will converted to an access token and returned.
+    ///
+    /// If a token cache was set on OAuth and it has an access token that
+    /// has not expired the cached access token is returned instead.
     pub async fn send(&mut self) -> OAuthReq<AccessToken> {
+        if let Some(access_token) = self.token_cache.cached(&self.cache_key, self.read_cache)? {
+            return Ok(access_token);
+        }
+
+        self.sign_client_assertion()?;
         let client = reqwest::Client::new();
         let builder = client.post(self.uri.as_str()).form(&self.params);
-        AccessToken::try_from_async(builder).await
+        let access_token = AccessToken::try_from_async(builder).await?;
+        self.token_cache.store(&self.cache_key, &access_token)?;
+        Ok(access_token)
     }
 
     /// Send the request for an access token. This method
@@ -1557,6 +1941,7 @@ impl AsyncAccessTokenRequest {
     where
         for<'de> T: serde::Deserialize<'de>,
     {
+        self.sign_client_assertion()?;
         let client = reqwest::Client::new();
         let builder = client.post(self.uri.as_str()).form(&self.params);
         let response = builder.send().await?;
@@ -1649,6 +2034,36 @@ impl AccessTokenGrant {
         }
     }
 
+    /// Sign in using the default browser and a loopback listener that
+    /// receives the redirect. This is for desktop and command line apps
+    /// using the authorization code grant.
+    ///
+    /// The redirect uri is set to the loopback listener and a PKCE code
+    /// challenge and state are generated if they have not been set. Once
+    /// the user signs in the code is exchanged for an access token which
+    /// is stored in OAuth and returned.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// # use graph_oauth::oauth::{LoopbackListener, OAuth};
+    /// # let mut oauth = OAuth::new();
+    /// let listener = LoopbackListener::bind(8000)?;
+    /// let mut request = oauth.build().authorization_code_grant();
+    /// let access_token = request.loopback_authorization(listener)?;
+    /// ```
+    pub fn loopback_authorization(&mut self, listener: LoopbackListener) -> OAuthReq<AccessToken> {
+        let state = self.oauth.loopback_redirect(self.grant, &listener)?;
+        if listener.opens_browser() {
+            self.browser_authorization().open()?;
+        }
+
+        let code = listener.accept(state.as_str())?;
+        self.oauth.access_code(code.as_str());
+        let access_token = self.access_token().read_cache(false).send()?;
+        self.oauth.access_token(access_token.clone());
+        Ok(access_token)
+    }
+
     /// Make a request for an access token. The token is stored in OAuth and
     /// will be used to make for making requests for refresh tokens. The below
     /// example shows how access tokens are stored and retrieved for OAuth:
@@ -1703,6 +2118,10 @@ impl AccessTokenGrant {
                 .oauth
                 .params(self.grant.available_credentials(GrantRequest::AccessToken))
                 .unwrap(),
+            token_cache: self.oauth.token_cache.clone(),
+            cache_key: self.oauth.token_cache_key(),
+            read_cache: true,
+            client_certificate: self.oauth.client_certificate_for(self.grant),
         }
     }
 
@@ -1720,6 +2139,10 @@ impl AccessTokenGrant {
                 .oauth
                 .params(self.grant.available_credentials(GrantRequest::RefreshToken))
                 .unwrap(),
+            token_cache: self.oauth.token_cache.clone(),
+            cache_key: self.oauth.token_cache_key(),
+            read_cache: false,
+            client_certificate: self.oauth.client_certificate_for(self.grant),
         }
     }
 }
@@ -1762,6 +2185,39 @@ impl AsyncAccessTokenGrant {
         }
     }
 
+    /// Sign in using the default browser and a loopback listener that
+    /// receives the redirect. This is for desktop and command line apps
+    /// using the authorization code grant.
+    ///
+    /// The redirect uri is set to the loopback listener and a PKCE code
+    /// challenge and state are generated if they have not been set. Once
+    /// the user signs in the code is exchanged for an access token which
+    /// is stored in OAuth and returned.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// # use graph_oauth::oauth::{LoopbackListener, OAuth};
+    /// # let mut oauth = OAuth::new();
+    /// let listener = LoopbackListener::bind(8000)?;
+    /// let mut request = oauth.build_async().authorization_code_grant();
+    /// let access_token = request.loopback_authorization(listener).await?;
+    /// ```
+    pub async fn loopback_authorization(
+        &mut self,
+        listener: LoopbackListener,
+    ) -> OAuthReq<AccessToken> {
+        let state = self.oauth.loopback_redirect(self.grant, &listener)?;
+        if listener.opens_browser() {
+            self.browser_authorization().open()?;
+        }
+
+        let code = listener.async_accept(state.as_str()).await?;
+        self.oauth.access_code(code.as_str());
+        let access_token = self.access_token().read_cache(false).send().await?;
+        self.oauth.access_token(access_token.clone());
+        Ok(access_token)
+    }
+
     /// Make a request for an access token. The token is stored in OAuth and
     /// will be used to make for making requests for refresh tokens. The below
     /// example shows how access tokens are stored and retrieved for OAuth:
@@ -1816,6 +2272,10 @@ impl AsyncAccessTokenGrant {
                 .oauth
                 .params(self.grant.available_credentials(GrantRequest::AccessToken))
                 .unwrap(),
+            token_cache: self.oauth.token_cache.clone(),
+            cache_key: self.oauth.token_cache_key(),
+            read_cache: true,
+            client_certificate: self.oauth.client_certificate_for(self.grant),
         }
     }
 
@@ -1833,8 +2293,389 @@ impl AsyncAccessTokenGrant {
                 .oauth
                 .params(self.grant.available_credentials(GrantRequest::RefreshToken))
                 .unwrap(),
+            token_cache: self.oauth.token_cache.clone(),
+            cache_key: self.oauth.token_cache_key(),
+            read_cache: false,
+            client_certificate: self.oauth.client_certificate_for(self.grant),
+        }
+    }
+}
+
+/// The device code grant for devices that cannot open a browser or
+/// have limited input such as headless servers.
+///
+/// A device code is requested first and the user code and verification
+/// uri it contains are shown to the user. The user signs in on another
+/// device while the token endpoint is polled until the user finishes
+/// signing in.
+///
+/// # Example
+/// ```rust,ignore
+/// # use graph_oauth::oauth::OAuth;
+/// let mut oauth = OAuth::new();
+/// oauth
+///     .client_id("<CLIENT_ID>")
+///     .device_code_url("https://login.microsoftonline.com/common/oauth2/v2.0/devicecode")
+///     .access_token_url("https://login.microsoftonline.com/common/oauth2/v2.0/token")
+///     .add_scope("Files.Read")
+///     .add_scope("offline_access");
+///
+/// let mut request = oauth.build().device_code();
+/// let device_code = request.device_authorization().unwrap();
+/// println!("{}", device_code.message().unwrap());
+///
+/// let access_token = request.poll(&device_code).unwrap();
+/// oauth.access_token(access_token);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, AsFile, FromFile)]
+pub struct DeviceCodeGrant {
+    oauth: OAuth,
+}
+
+impl DeviceCodeGrant {
+    /// Request a device code. The user code and verification uri
+    /// of the device code should be shown to the user.
+    pub fn device_authorization(&mut self) -> OAuthReq<DeviceCode> {
+        self.oauth
+            .pre_request_check(GrantType::DeviceCode, GrantRequest::Authorization);
+        let uri = self.oauth.get_or_else(OAuthCredential::DeviceCodeURL)?;
+        let params = self
+            .oauth
+            .params(GrantType::DeviceCode.available_credentials(GrantRequest::Authorization))?;
+        let client = reqwest::blocking::Client::new();
+        let response = client.post(uri.as_str()).form(&params).send()?;
+        if let Ok(error) = GraphError::try_from(&response) {
+            return Err(GraphFailure::from(error));
+        }
+        Ok(response.json()?)
+    }
+
+    /// Poll the token endpoint until the user has signed in. This blocks
+    /// the current thread, waiting the interval given by the device code
+    /// between each request.
+    ///
+    /// An error is returned if the user declines the request or if the
+    /// device code expires before the user signs in.
+    pub fn poll(&mut self, device_code: &DeviceCode) -> OAuthReq<AccessToken> {
+        let (uri, params) = self.poll_request(device_code)?;
+        let client = reqwest::blocking::Client::new();
+        let mut poller = DeviceCodePoller::new(device_code);
+
+        loop {
+            std::thread::sleep(poller.interval());
+            let response = client.post(uri.as_str()).form(&params).send()?;
+            if response.status().is_success() {
+                let access_token = AccessToken::try_from(response)?;
+                self.oauth
+                    .token_cache
+                    .store(&self.oauth.token_cache_key(), &access_token)?;
+                return Ok(access_token);
+            }
+            poller.update(response.json()?)?;
+        }
+    }
+
+    /// Request a refresh token. Assumes an access token has already
+    /// been retrieved.
+    pub fn refresh_token(&mut self) -> AccessTokenRequest {
+        self.oauth
+            .pre_request_check(GrantType::DeviceCode, GrantRequest::RefreshToken);
+        AccessTokenRequest {
+            uri: self
+                .oauth
+                .get_or_else(OAuthCredential::RefreshTokenURL)
+                .unwrap(),
+            params: self
+                .oauth
+                .params(GrantType::DeviceCode.available_credentials(GrantRequest::RefreshToken))
+                .unwrap(),
+            token_cache: self.oauth.token_cache.clone(),
+            cache_key: self.oauth.token_cache_key(),
+            read_cache: false,
+            client_certificate: None,
         }
     }
+
+    fn poll_request(
+        &mut self,
+        device_code: &DeviceCode,
+    ) -> OAuthReq<(String, HashMap<String, String>)> {
+        self.oauth.device_code(device_code.device_code());
+        self.oauth
+            .pre_request_check(GrantType::DeviceCode, GrantRequest::AccessToken);
+        let uri = self.oauth.get_or_else(OAuthCredential::AccessTokenURL)?;
+        let params = self
+            .oauth
+            .params(GrantType::DeviceCode.available_credentials(GrantRequest::AccessToken))?;
+        Ok((uri, params))
+    }
+}
+
+/// The device code grant for async requests. See `DeviceCodeGrant`.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, AsFile, FromFile)]
+pub struct AsyncDeviceCodeGrant {
+    oauth: OAuth,
+}
+
+impl AsyncDeviceCodeGrant {
+    /// Request a device code. The user code and verification uri
+    /// of the device code should be shown to the user.
+    pub async fn device_authorization(&mut self) -> OAuthReq<DeviceCode> {
+        self.oauth
+            .pre_request_check(GrantType::DeviceCode, GrantRequest::Authorization);
+        let uri = self.oauth.get_or_else(OAuthCredential::DeviceCodeURL)?;
+        let params = self
+            .oauth
+            .params(GrantType::DeviceCode.available_credentials(GrantRequest::Authorization))?;
+        let client = reqwest::Client::new();
+        let response = client.post(uri.as_str()).form(&params).send().await?;
+        if let Ok(error) = GraphError::try_from(&response) {
+            return Err(GraphFailure::from(error));
+        }
+        Ok(response.json().await?)
+    }
+
+    /// Poll the token endpoint until the user has signed in, waiting the
+    /// interval given by the device code between each request.
+    ///
+    /// An error is returned if the user declines the request or if the
+    /// device code expires before the user signs in.
+    pub async fn poll(&mut self, device_code: &DeviceCode) -> OAuthReq<AccessToken> {
+        let (uri, params) = self.poll_request(device_code)?;
+        let client = reqwest::Client::new();
+        let mut poller = DeviceCodePoller::new(device_code);
+
+        loop {
+            tokio::time::delay_for(poller.interval()).await;
+            let response = client.post(uri.as_str()).form(&params).send().await?;
+            if response.status().is_success() {
+                let access_token = AccessToken::try_from(response.text().await?.as_str())?;
+                self.oauth
+                    .token_cache
+                    .store(&self.oauth.token_cache_key(), &access_token)?;
+                return Ok(access_token);
+            }
+            poller.update(response.json().await?)?;
+        }
+    }
+
+    /// Request a refresh token. Assumes an access token has already
+    /// been retrieved.
+    pub fn refresh_token(&mut self) -> AsyncAccessTokenRequest {
+        self.oauth
+            .pre_request_check(GrantType::DeviceCode, GrantRequest::RefreshToken);
+        AsyncAccessTokenRequest {
+            uri: self
+                .oauth
+                .get_or_else(OAuthCredential::RefreshTokenURL)
+                .unwrap(),
+            params: self
+                .oauth
+                .params(GrantType::DeviceCode.available_credentials(GrantRequest::RefreshToken))
+                .unwrap(),
+            token_cache: self.oauth.token_cache.clone(),
+            cache_key: self.oauth.token_cache_key(),
+            read_cache: false,
+            client_certificate: None,
+        }
+    }
+
+    fn poll_request(
+        &mut self,
+        device_code: &DeviceCode,
+    ) -> OAuthReq<(String, HashMap<String, String>)> {
+        self.oauth.device_code(device_code.device_code());
+        self.oauth
+            .pre_request_check(GrantType::DeviceCode, GrantRequest::AccessToken);
+        let uri = self.oauth.get_or_else(OAuthCredential::AccessTokenURL)?;
+        let params = self
+            .oauth
+            .params(GrantType::DeviceCode.available_credentials(GrantRequest::AccessToken))?;
+        Ok((uri, params))
+    }
+}
+
+/// The on behalf of grant used by an API to call another API, such as
+/// Graph, as the user that called it.
+///
+/// The access token sent to the API by the user is exchanged for a new
+/// access token that the API can use. The app must authenticate using
+/// a client secret, a client assertion or a client certificate.
+///
+/// # Example
+/// ```rust,ignore
+/// # use graph_oauth::oauth::OAuth;
+/// let mut oauth = OAuth::new();
+/// oauth
+///     .client_id("<CLIENT_ID>")
+///     .client_secret("<CLIENT_SECRET>")
+///     .access_token_url("https://login.microsoftonline.com/<TENANT_ID>/oauth2/v2.0/token")
+///     .refresh_token_url("https://login.microsoftonline.com/<TENANT_ID>/oauth2/v2.0/token")
+///     .add_scope("https://graph.microsoft.com/user.read")
+///     .add_scope("offline_access");
+///
+/// let mut request = oauth.build().on_behalf_of();
+/// request.user_access_token(&incoming_access_token);
+/// let access_token = request.access_token()?.send()?;
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, AsFile, FromFile)]
+pub struct OnBehalfOfGrant {
+    oauth: OAuth,
+}
+
+impl OnBehalfOfGrant {
+    /// Set the access token that was sent to the API by the user.
+    pub fn assertion(&mut self, assertion: &str) -> &mut OnBehalfOfGrant {
+        self.oauth.assertion(assertion);
+        self
+    }
+
+    /// Set the access token that was sent to the API by the user.
+    pub fn user_access_token(&mut self, access_token: &AccessToken) -> &mut OnBehalfOfGrant {
+        self.assertion(access_token.bearer_token())
+    }
+
+    /// Request an access token for the user. Returns an error if a
+    /// credential required by the grant has not been set.
+    pub fn access_token(&mut self) -> OAuthReq<AccessTokenRequest> {
+        let (uri, params) = on_behalf_of_request(&mut self.oauth, GrantRequest::AccessToken)?;
+        Ok(AccessTokenRequest {
+            uri,
+            params,
+            token_cache: self.oauth.token_cache.clone(),
+            cache_key: on_behalf_of_cache_key(&self.oauth),
+            read_cache: true,
+            client_certificate: self.oauth.client_certificate_for(GrantType::OnBehalfOf),
+        })
+    }
+
+    /// Request a refresh token. Assumes an access token with a
+    /// refresh token has already been retrieved.
+    pub fn refresh_token(&mut self) -> OAuthReq<AccessTokenRequest> {
+        let (uri, params) = on_behalf_of_request(&mut self.oauth, GrantRequest::RefreshToken)?;
+        Ok(AccessTokenRequest {
+            uri,
+            params,
+            token_cache: self.oauth.token_cache.clone(),
+            cache_key: on_behalf_of_cache_key(&self.oauth),
+            read_cache: false,
+            client_certificate: self.oauth.client_certificate_for(GrantType::OnBehalfOf),
+        })
+    }
+}
+
+/// The on behalf of grant for async requests. See `OnBehalfOfGrant`.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, AsFile, FromFile)]
+pub struct AsyncOnBehalfOfGrant {
+    oauth: OAuth,
+}
+
+impl AsyncOnBehalfOfGrant {
+    /// Set the access token that was sent to the API by the user.
+    pub fn assertion(&mut self, assertion: &str) -> &mut AsyncOnBehalfOfGrant {
+        self.oauth.assertion(assertion);
+        self
+    }
+
+    /// Set the access token that was sent to the API by the user.
+    pub fn user_access_token(&mut self, access_token: &AccessToken) -> &mut AsyncOnBehalfOfGrant {
+        self.assertion(access_token.bearer_token())
+    }
+
+    /// Request an access token for the user. Returns an error if a
+    /// credential required by the grant has not been set.
+    pub fn access_token(&mut self) -> OAuthReq<AsyncAccessTokenRequest> {
+        let (uri, params) = on_behalf_of_request(&mut self.oauth, GrantRequest::AccessToken)?;
+        Ok(AsyncAccessTokenRequest {
+            uri,
+            params,
+            token_cache: self.oauth.token_cache.clone(),
+            cache_key: on_behalf_of_cache_key(&self.oauth),
+            read_cache: true,
+            client_certificate: self.oauth.client_certificate_for(GrantType::OnBehalfOf),
+        })
+    }
+
+    /// Request a refresh token. Assumes an access token with a
+    /// refresh token has already been retrieved.
+    pub fn refresh_token(&mut self) -> OAuthReq<AsyncAccessTokenRequest> {
+        let (uri, params) = on_behalf_of_request(&mut self.oauth, GrantRequest::RefreshToken)?;
+        Ok(AsyncAccessTokenRequest {
+            uri,
+            params,
+            token_cache: self.oauth.token_cache.clone(),
+            cache_key: on_behalf_of_cache_key(&self.oauth),
+            read_cache: false,
+            client_certificate: self.oauth.client_certificate_for(GrantType::OnBehalfOf),
+        })
+    }
+}
+
+// The uri and params of an on behalf of request. The credentials that
+// the grant requires are checked before the request is created.
+fn on_behalf_of_request(
+    oauth: &mut OAuth,
+    request_type: GrantRequest,
+) -> OAuthReq<(String, HashMap<String, String>)> {
+    oauth.pre_request_check(GrantType::OnBehalfOf, request_type);
+    for oac in GrantType::OnBehalfOf.available_credentials(request_type) {
+        match oac {
+            // One of the client secret, client assertion or
+            // client certificate is required.
+            OAuthCredential::ClientSecret |
+            OAuthCredential::ClientAssertion |
+            OAuthCredential::ClientAssertionType => {},
+            OAuthCredential::Scopes => {
+                if oauth.scopes.is_empty() {
+                    return Err(OAuthError::credential_error(oac));
+                }
+            },
+            OAuthCredential::RefreshToken => {
+                oauth.get_refresh_token()?;
+            },
+            _ => {
+                oauth.get_or_else(oac)?;
+            },
+        }
+    }
+
+    if !oauth.contains(OAuthCredential::ClientSecret) &&
+        !oauth.contains(OAuthCredential::ClientAssertion) &&
+        oauth.client_certificate.is_none()
+    {
+        return Err(OAuthError::credential_error(OAuthCredential::ClientSecret));
+    }
+
+    let uri = match request_type {
+        GrantRequest::RefreshToken => oauth.get_or_else(OAuthCredential::RefreshTokenURL)?,
+        _ => oauth.get_or_else(OAuthCredential::AccessTokenURL)?,
+    };
+    let params = oauth.params(GrantType::OnBehalfOf.available_credentials(request_type))?;
+    Ok((uri, params))
+}
+
+// Access tokens from the on behalf of grant are cached per user. The user
+// is the oid or sub claim of the assertion or a hash of the assertion when
+// it cannot be parsed.
+fn on_behalf_of_cache_key(oauth: &OAuth) -> TokenCacheKey {
+    let assertion = oauth.get(OAuthCredential::Assertion).unwrap_or_default();
+    let claims = JwtParser::parse(assertion.as_str())
+        .ok()
+        .and_then(|jwt| jwt.claims())
+        .unwrap_or_default();
+    let account = ["oid", "sub"]
+        .iter()
+        .find_map(|key| {
+            claims
+                .iter()
+                .find(|claim| claim.key().eq(key))
+                .and_then(|claim| claim.value().as_str().map(|s| s.to_string()))
+        })
+        .unwrap_or_else(|| {
+            let digest = ring::digest::digest(&ring::digest::SHA256, assertion.as_bytes());
+            base64::encode_config(digest.as_ref(), base64::URL_SAFE_NO_PAD)
+        });
+    oauth.token_cache_key().with_account(Some(account))
 }
 
 impl From<AccessTokenGrant> for OAuth {
@@ -1849,6 +2690,32 @@ impl AsRef<OAuth> for AccessTokenGrant {
     }
 }
 
+// Sign a client assertion using the certificate and add it to the params
+// of an access token request. The client secret is not sent when a client
+// assertion is used.
+fn sign_client_assertion(
+    client_certificate: Option<&Arc<ClientCertificate>>,
+    uri: &str,
+    params: &mut HashMap<String, String>,
+) -> OAuthReq<()> {
+    if let Some(client_certificate) = client_certificate {
+        let client_id = params
+            .get(OAuthCredential::ClientId.alias())
+            .ok_or_else(|| OAuthError::credential_error(OAuthCredential::ClientId))?;
+        let client_assertion = client_certificate.client_assertion(client_id, uri)?;
+        params.remove(OAuthCredential::ClientSecret.alias());
+        params.insert(
+            OAuthCredential::ClientAssertion.to_string(),
+            client_assertion,
+        );
+        params.insert(
+            OAuthCredential::ClientAssertionType.to_string(),
+            CLIENT_ASSERTION_TYPE.to_string(),
+        );
+    }
+    Ok(())
+}
+
 impl fmt::Debug for OAuth {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut map_debug: BTreeMap<&str, &str> = BTreeMap::new();
@@ -0,0 +1,150 @@
+/// The Azure cloud that the Microsoft identity platform and Microsoft Graph
+/// endpoints are hosted in. Each national cloud is a separate instance
+/// with its own sign in and Graph hosts.
+///
+/// # See
+/// [National cloud deployments](https://docs.microsoft.com/en-us/graph/deployments)
+///
+/// # Example
+/// ```
+/// # use graph_oauth::oauth::AzureCloud;
+/// assert_eq!(AzureCloud::UsGovernment.login_url(), "https://login.microsoftonline.us");
+/// assert_eq!(AzureCloud::UsGovernment.graph_url(), "https://graph.microsoft.us");
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum AzureCloud {
+    /// The global Azure cloud.
+    Global,
+    /// Azure US Government (L4).
+    UsGovernment,
+    /// Azure US Government Department of Defense (L5).
+    UsGovernmentDoD,
+    /// Azure China operated by 21Vianet.
+    China,
+    /// Azure Germany.
+    Germany,
+}
+
+impl AzureCloud {
+    /// The host of the Microsoft identity platform for the cloud.
+    pub fn login_url(&self) -> &'static str {
+        match self {
+            AzureCloud::Global => "https://login.microsoftonline.com",
+            AzureCloud::UsGovernment | AzureCloud::UsGovernmentDoD => {
+                "https://login.microsoftonline.us"
+            },
+            AzureCloud::China => "https://login.chinacloudapi.cn",
+            AzureCloud::Germany => "https://login.microsoftonline.de",
+        }
+    }
+
+    /// The host of Microsoft Graph for the cloud.
+    pub fn graph_url(&self) -> &'static str {
+        match self {
+            AzureCloud::Global => "https://graph.microsoft.com",
+            AzureCloud::UsGovernment => "https://graph.microsoft.us",
+            AzureCloud::UsGovernmentDoD => "https://dod-graph.microsoft.us",
+            AzureCloud::China => "https://microsoftgraph.chinacloudapi.cn",
+            AzureCloud::Germany => "https://graph.microsoft.de",
+        }
+    }
+
+    // The host suffix used by Azure AD B2C tenants in the cloud.
+    fn b2c_host(&self) -> &'static str {
+        match self {
+            AzureCloud::China => "b2clogin.cn",
+            _ => "b2clogin.com",
+        }
+    }
+}
+
+impl Default for AzureCloud {
+    fn default() -> Self {
+        AzureCloud::Global
+    }
+}
+
+/// The authority that users sign in with and that issues tokens.
+///
+/// # See
+/// [Microsoft identity platform endpoints](https://docs.microsoft.com/en-us/azure/active-directory/develop/active-directory-v2-protocols#endpoints)
+///
+/// # Example
+/// ```
+/// # use graph_oauth::oauth::{Authority, AzureCloud};
+/// let authority = Authority::Tenant("contoso.onmicrosoft.com".into());
+/// assert_eq!(
+///     authority.token_url(AzureCloud::Global),
+///     "https://login.microsoftonline.com/contoso.onmicrosoft.com/oauth2/v2.0/token"
+/// );
+///
+/// let authority = Authority::B2C {
+///     tenant: "contoso".into(),
+///     policy: "B2C_1_signupsignin".into(),
+/// };
+/// assert_eq!(
+///     authority.authorize_url(AzureCloud::Global),
+///     "https://contoso.b2clogin.com/contoso.onmicrosoft.com/B2C_1_signupsignin/oauth2/v2.0/authorize"
+/// );
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum Authority {
+    /// Users with both a personal Microsoft account and a work or
+    /// school account.
+    Common,
+    /// Users with work or school accounts.
+    Organizations,
+    /// Users with a personal Microsoft account.
+    Consumers,
+    /// Users of a single tenant using the tenant id or domain name.
+    Tenant(String),
+    /// Azure AD B2C using the name of the tenant, without
+    /// .onmicrosoft.com, and the user flow policy.
+    B2C { tenant: String, policy: String },
+}
+
+impl Authority {
+    /// The url of the authority in the cloud.
+    pub fn url(&self, cloud: AzureCloud) -> String {
+        match self {
+            Authority::Common => format!("{}/common", cloud.login_url()),
+            Authority::Organizations => format!("{}/organizations", cloud.login_url()),
+            Authority::Consumers => format!("{}/consumers", cloud.login_url()),
+            Authority::Tenant(tenant) => format!("{}/{}", cloud.login_url(), tenant),
+            Authority::B2C { tenant, policy } => format!(
+                "https://{}.{}/{}.onmicrosoft.com/{}",
+                tenant,
+                cloud.b2c_host(),
+                tenant,
+                policy
+            ),
+        }
+    }
+
+    pub fn authorize_url(&self, cloud: AzureCloud) -> String {
+        format!("{}/oauth2/v2.0/authorize", self.url(cloud))
+    }
+
+    pub fn token_url(&self, cloud: AzureCloud) -> String {
+        format!("{}/oauth2/v2.0/token", self.url(cloud))
+    }
+
+    pub fn logout_url(&self, cloud: AzureCloud) -> String {
+        format!("{}/oauth2/v2.0/logout", self.url(cloud))
+    }
+
+    pub fn device_code_url(&self, cloud: AzureCloud) -> String {
+        format!("{}/oauth2/v2.0/devicecode", self.url(cloud))
+    }
+
+    /// The url of the OpenID Connect metadata document.
+    pub fn open_id_configuration_url(&self, cloud: AzureCloud) -> String {
+        format!("{}/v2.0/.well-known/openid-configuration", self.url(cloud))
+    }
+}
+
+impl Default for Authority {
+    fn default() -> Self {
+        Authority::Common
+    }
+}
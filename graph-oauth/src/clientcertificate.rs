@@ -0,0 +1,179 @@
+use crate::auth::OAuthReq;
+use chrono::{Duration, Utc};
+use graph_error::GraphFailure;
+use ring::rand::{SecureRandom, SystemRandom};
+use ring::signature::{RsaKeyPair, RSA_PKCS1_SHA256};
+use std::fmt;
+
+/// The client assertion type sent with a client assertion that
+/// was signed using a certificate.
+pub(crate) const CLIENT_ASSERTION_TYPE: &str =
+    "urn:ietf:params:oauth:client-assertion-type:jwt-bearer";
+
+/// An X.509 certificate and the private key for the certificate that
+/// are used to sign client assertions instead of using a client secret.
+///
+/// The certificate must be uploaded to the app registration. A new
+/// client assertion is signed for each access token request made
+/// using the client credentials or authorization code grants.
+///
+/// # See
+/// [Microsoft identity platform application authentication certificate credentials](https://docs.microsoft.com/en-us/azure/active-directory/develop/active-directory-certificate-credentials)
+///
+/// # Example
+/// ```rust,ignore
+/// # use graph_oauth::oauth::{ClientCertificate, OAuth};
+/// let certificate = std::fs::read("./certificate.pem")?;
+/// let private_key = std::fs::read("./private_key.pem")?;
+///
+/// let mut oauth = OAuth::new();
+/// oauth
+///     .client_id("<CLIENT_ID>")
+///     .access_token_url("https://login.microsoftonline.com/<TENANT_ID>/oauth2/v2.0/token")
+///     .add_scope("https://graph.microsoft.com/.default")
+///     .client_certificate(ClientCertificate::from_pem(&certificate, &private_key)?);
+///
+/// let mut request = oauth.build().client_credentials();
+/// let access_token = request.access_token().send()?;
+/// ```
+pub struct ClientCertificate {
+    thumbprint: String,
+    key_pair: RsaKeyPair,
+}
+
+impl ClientCertificate {
+    /// Create a client certificate from a PEM encoded certificate and
+    /// a PEM encoded PKCS#8 (BEGIN PRIVATE KEY) or PKCS#1 (BEGIN RSA
+    /// PRIVATE KEY) RSA private key.
+    pub fn from_pem(certificate: &[u8], private_key: &[u8]) -> OAuthReq<ClientCertificate> {
+        let certificate = pem_to_der(certificate, "CERTIFICATE")?;
+        if let Ok(private_key) = pem_to_der(private_key, "PRIVATE KEY") {
+            return ClientCertificate::from_der(&certificate, &private_key);
+        }
+
+        let private_key = pem_to_der(private_key, "RSA PRIVATE KEY")?;
+        let key_pair = RsaKeyPair::from_der(&private_key)
+            .map_err(|_| GraphFailure::invalid("RSA private key"))?;
+        Ok(ClientCertificate::new(&certificate, key_pair))
+    }
+
+    /// Create a client certificate from a DER encoded certificate and
+    /// a DER encoded PKCS#8 RSA private key.
+    pub fn from_der(certificate: &[u8], private_key: &[u8]) -> OAuthReq<ClientCertificate> {
+        let key_pair = RsaKeyPair::from_pkcs8(private_key)
+            .map_err(|_| GraphFailure::invalid("PKCS#8 RSA private key"))?;
+        Ok(ClientCertificate::new(certificate, key_pair))
+    }
+
+    fn new(certificate: &[u8], key_pair: RsaKeyPair) -> ClientCertificate {
+        let digest = ring::digest::digest(&ring::digest::SHA1_FOR_LEGACY_USE_ONLY, certificate);
+        ClientCertificate {
+            thumbprint: base64::encode_config(digest.as_ref(), base64::URL_SAFE_NO_PAD),
+            key_pair,
+        }
+    }
+
+    /// The base64url encoded SHA-1 thumbprint of the certificate
+    /// that is used as the x5t header of client assertions.
+    pub fn thumbprint(&self) -> &str {
+        self.thumbprint.as_str()
+    }
+
+    /// Sign a new client assertion for the client id. The audience is
+    /// the token endpoint that the assertion will be sent to. The
+    /// assertion expires after 10 minutes.
+    pub fn client_assertion(&self, client_id: &str, audience: &str) -> OAuthReq<String> {
+        let header = serde_json::json!({
+            "alg": "RS256",
+            "typ": "JWT",
+            "x5t": self.thumbprint,
+        });
+
+        let now = Utc::now();
+        let claims = serde_json::json!({
+            "aud": audience,
+            "iss": client_id,
+            "sub": client_id,
+            "jti": jti()?,
+            "nbf": now.timestamp(),
+            "exp": (now + Duration::minutes(10)).timestamp(),
+        });
+
+        let message = format!(
+            "{}.{}",
+            base64::encode_config(&serde_json::to_vec(&header)?, base64::URL_SAFE_NO_PAD),
+            base64::encode_config(&serde_json::to_vec(&claims)?, base64::URL_SAFE_NO_PAD)
+        );
+
+        let mut signature = vec![0; self.key_pair.public_modulus_len()];
+        self.key_pair.sign(
+            &RSA_PKCS1_SHA256,
+            &SystemRandom::new(),
+            message.as_bytes(),
+            &mut signature,
+        )?;
+
+        Ok(format!(
+            "{}.{}",
+            message,
+            base64::encode_config(&signature, base64::URL_SAFE_NO_PAD)
+        ))
+    }
+}
+
+// Certificates are compared by thumbprint so that OAuth can still
+// be compared for equality.
+impl PartialEq for ClientCertificate {
+    fn eq(&self, other: &Self) -> bool {
+        self.thumbprint == other.thumbprint
+    }
+}
+
+impl Eq for ClientCertificate {}
+
+impl fmt::Debug for ClientCertificate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClientCertificate")
+            .field("thumbprint", &self.thumbprint)
+            .field("private_key", &"[REDACTED]")
+            .finish()
+    }
+}
+
+// A random unique id for the jti claim formatted as a version 4 uuid.
+fn jti() -> OAuthReq<String> {
+    let mut bytes = [0u8; 16];
+    SystemRandom::new().fill(&mut bytes)?;
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+    Ok(format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    ))
+}
+
+/// Decode the DER bytes of a PEM encoded block with the given label,
+/// such as CERTIFICATE, PRIVATE KEY or RSA PRIVATE KEY.
+pub fn pem_to_der(pem: &[u8], label: &str) -> OAuthReq<Vec<u8>> {
+    let pem = std::str::from_utf8(pem)?;
+    let begin = format!("-----BEGIN {}-----", label);
+    let end = format!("-----END {}-----", label);
+    let start = pem
+        .find(begin.as_str())
+        .ok_or_else(|| GraphFailure::invalid(&format!("PEM {}", label)))? +
+        begin.len();
+    let stop = pem[start..]
+        .find(end.as_str())
+        .ok_or_else(|| GraphFailure::invalid(&format!("PEM {}", label)))? +
+        start;
+    let encoded: String = pem[start..stop]
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect();
+    Ok(base64::decode(&encoded)?)
+}
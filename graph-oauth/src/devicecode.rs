@@ -0,0 +1,152 @@
+use crate::auth::OAuthReq;
+use crate::grants::{GrantRequest, GrantType};
+use crate::oautherror::OAuthError;
+use from_as::*;
+use graph_error::GraphFailure;
+use std::convert::TryFrom;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// The grant type sent to the token endpoint when polling for
+/// an access token using a device code.
+pub(crate) const DEVICE_CODE_GRANT_TYPE: &str = "urn:ietf:params:oauth:grant-type:device_code";
+
+/// The response to a device authorization request.
+///
+/// The user code and verification uri should be shown to the user
+/// who then signs in on another device by going to the verification
+/// uri and entering the user code. The message returned by Microsoft
+/// includes both and can be shown to the user as is.
+///
+/// # See
+/// [Microsoft Device Code Flow](https://docs.microsoft.com/en-us/azure/active-directory/develop/v2-oauth2-device-code)
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize, AsFile, FromFile)]
+pub struct DeviceCode {
+    device_code: String,
+    user_code: String,
+    #[serde(alias = "verification_url")]
+    verification_uri: String,
+    verification_uri_complete: Option<String>,
+    expires_in: u64,
+    #[serde(default = "default_interval")]
+    interval: u64,
+    message: Option<String>,
+}
+
+// The interval in seconds used when the response does not include one.
+fn default_interval() -> u64 {
+    5
+}
+
+impl DeviceCode {
+    pub fn device_code(&self) -> &str {
+        self.device_code.as_str()
+    }
+
+    pub fn user_code(&self) -> &str {
+        self.user_code.as_str()
+    }
+
+    pub fn verification_uri(&self) -> &str {
+        self.verification_uri.as_str()
+    }
+
+    pub fn verification_uri_complete(&self) -> Option<&str> {
+        self.verification_uri_complete.as_deref()
+    }
+
+    pub fn expires_in(&self) -> u64 {
+        self.expires_in
+    }
+
+    pub fn interval(&self) -> u64 {
+        self.interval
+    }
+
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+}
+
+impl TryFrom<&str> for DeviceCode {
+    type Error = GraphFailure;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Ok(serde_json::from_str(value)?)
+    }
+}
+
+impl fmt::Debug for DeviceCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DeviceCode")
+            .field("device_code", &"[REDACTED]")
+            .field("user_code", &self.user_code)
+            .field("verification_uri", &self.verification_uri)
+            .field("verification_uri_complete", &self.verification_uri_complete)
+            .field("expires_in", &self.expires_in)
+            .field("interval", &self.interval)
+            .field("message", &self.message)
+            .finish()
+    }
+}
+
+/// The error body returned by the token endpoint while polling.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub(crate) struct DeviceCodeError {
+    error: String,
+    error_description: Option<String>,
+}
+
+/// Keeps track of the polling interval and expiration of a device code.
+#[derive(Debug)]
+pub(crate) struct DeviceCodePoller {
+    interval: u64,
+    expires_at: Instant,
+}
+
+impl DeviceCodePoller {
+    pub fn new(device_code: &DeviceCode) -> DeviceCodePoller {
+        DeviceCodePoller {
+            interval: device_code.interval,
+            expires_at: Instant::now() + Duration::from_secs(device_code.expires_in),
+        }
+    }
+
+    /// The time to wait before the next request to the token endpoint.
+    pub fn interval(&self) -> Duration {
+        Duration::from_secs(self.interval)
+    }
+
+    /// Update the poller using the error returned by the token endpoint.
+    /// Returns an error if polling should stop because the user declined
+    /// the request, the device code expired or the error is not one that
+    /// is returned while waiting for the user.
+    pub fn update(&mut self, error: DeviceCodeError) -> OAuthReq<()> {
+        match error.error.as_str() {
+            "authorization_pending" => {},
+            // The interval must be increased by 5 seconds for this and
+            // all later requests.
+            "slow_down" => self.interval += 5,
+            _ => {
+                let msg = match error.error_description {
+                    Some(description) => format!("{}: {}", error.error, description),
+                    None => error.error.clone(),
+                };
+                return OAuthError::grant_error(
+                    GrantType::DeviceCode,
+                    GrantRequest::AccessToken,
+                    msg.as_str(),
+                );
+            },
+        }
+
+        if Instant::now() + self.interval() > self.expires_at {
+            return OAuthError::grant_error(
+                GrantType::DeviceCode,
+                GrantRequest::AccessToken,
+                "expired_token: The device code expired before the user signed in",
+            );
+        }
+        Ok(())
+    }
+}
@@ -1,5 +1,5 @@
 use crate::oauth::wellknown::WellKnown;
-use crate::oauth::{OAuth, OAuthError};
+use crate::oauth::{Authority, AzureCloud, OAuth, OAuthError};
 use from_as::*;
 
 static LOGIN_LIVE_HOST: &str = "https://login.live.com";
@@ -57,6 +57,17 @@ pub enum GraphDiscovery {
     V1,
     V2,
     Tenant(String),
+    /// The authority in a national cloud or an Azure AD B2C authority.
+    Authority(Authority, AzureCloud),
+}
+
+// The endpoints that are in the OpenID Connect metadata of every
+// authority including national clouds and Azure AD B2C.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+struct AuthorityEndpoints {
+    authorization_endpoint: String,
+    token_endpoint: String,
+    end_session_endpoint: String,
 }
 
 impl GraphDiscovery {
@@ -77,6 +88,9 @@ impl GraphDiscovery {
                 "{}/{}/v2.0/{}",
                 MICROSOFT_ONLINE_HOST, &tenant, OPEN_ID_PATH
             ),
+            GraphDiscovery::Authority(authority, cloud) => {
+                authority.open_id_configuration_url(*cloud)
+            },
         }
     }
 
@@ -145,6 +159,16 @@ impl GraphDiscovery {
                     .logout_url(k.end_session_endpoint.as_str());
                 Ok(oauth)
             },
+            GraphDiscovery::Authority(ref authority, cloud) => {
+                oauth.authority(authority, cloud);
+                let k: AuthorityEndpoints = WellKnown::signing_keys(self.url().as_str())?;
+                oauth
+                    .authorize_url(k.authorization_endpoint.as_str())
+                    .access_token_url(k.token_endpoint.as_str())
+                    .refresh_token_url(k.token_endpoint.as_str())
+                    .logout_url(k.end_session_endpoint.as_str());
+                Ok(oauth)
+            },
         }
     }
 
@@ -179,6 +203,17 @@ impl GraphDiscovery {
                     .logout_url(k.end_session_endpoint.as_str());
                 Ok(oauth)
             },
+            GraphDiscovery::Authority(ref authority, cloud) => {
+                oauth.authority(authority, cloud);
+                let k: AuthorityEndpoints =
+                    WellKnown::async_signing_keys(self.url().as_str()).await?;
+                oauth
+                    .authorize_url(k.authorization_endpoint.as_str())
+                    .access_token_url(k.token_endpoint.as_str())
+                    .refresh_token_url(k.token_endpoint.as_str())
+                    .logout_url(k.end_session_endpoint.as_str());
+                Ok(oauth)
+            },
         }
     }
 }
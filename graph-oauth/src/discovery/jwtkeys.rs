@@ -65,6 +65,16 @@ impl JWTKeys {
         self.keys.to_vec()
     }
 
+    /// Get the key with the given key id (kid).
+    pub fn key(&self, kid: &str) -> Option<&Keys> {
+        self.keys.iter().find(|key| key.kid.as_deref() == Some(kid))
+    }
+
+    /// Get the key with the given certificate thumbprint (x5t).
+    pub fn key_by_thumbprint(&self, x5t: &str) -> Option<&Keys> {
+        self.keys.iter().find(|key| key.x5t.as_deref() == Some(x5t))
+    }
+
     pub fn key_map(&mut self) -> Vec<HashMap<String, String>> {
         let mut vec: Vec<HashMap<String, String>> = Vec::new();
         for key in self.keys.iter() {
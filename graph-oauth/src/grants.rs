@@ -20,6 +20,8 @@ pub enum GrantType {
     OpenId,
     ClientCredentials,
     ResourceOwnerPasswordCredentials,
+    DeviceCode,
+    OnBehalfOf,
 }
 
 impl GrantType {
@@ -82,6 +84,8 @@ impl GrantType {
                     OAuthCredential::Scopes,
                     OAuthCredential::GrantType,
                     OAuthCredential::CodeVerifier,
+                    OAuthCredential::ClientAssertion,
+                    OAuthCredential::ClientAssertionType,
                 ],
                 GrantRequest::RefreshToken => vec![
                     OAuthCredential::ClientId,
@@ -89,6 +93,8 @@ impl GrantType {
                     OAuthCredential::RefreshToken,
                     OAuthCredential::GrantType,
                     OAuthCredential::Scopes,
+                    OAuthCredential::ClientAssertion,
+                    OAuthCredential::ClientAssertionType,
                 ],
             },
             GrantType::Implicit => match grant_request {
@@ -167,6 +173,44 @@ impl GrantType {
                     OAuthCredential::ClientAssertion,
                 ],
             },
+            GrantType::DeviceCode => match grant_request {
+                GrantRequest::Authorization => {
+                    vec![OAuthCredential::ClientId, OAuthCredential::Scopes]
+                },
+                GrantRequest::AccessToken => vec![
+                    OAuthCredential::ClientId,
+                    OAuthCredential::GrantType,
+                    OAuthCredential::DeviceCode,
+                ],
+                GrantRequest::RefreshToken => vec![
+                    OAuthCredential::ClientId,
+                    OAuthCredential::RefreshToken,
+                    OAuthCredential::GrantType,
+                    OAuthCredential::Scopes,
+                ],
+            },
+            GrantType::OnBehalfOf => match grant_request {
+                GrantRequest::Authorization => vec![],
+                GrantRequest::AccessToken => vec![
+                    OAuthCredential::ClientId,
+                    OAuthCredential::ClientSecret,
+                    OAuthCredential::ClientAssertion,
+                    OAuthCredential::ClientAssertionType,
+                    OAuthCredential::GrantType,
+                    OAuthCredential::Assertion,
+                    OAuthCredential::Scopes,
+                    OAuthCredential::RequestedTokenUse,
+                ],
+                GrantRequest::RefreshToken => vec![
+                    OAuthCredential::ClientId,
+                    OAuthCredential::ClientSecret,
+                    OAuthCredential::ClientAssertion,
+                    OAuthCredential::ClientAssertionType,
+                    OAuthCredential::RefreshToken,
+                    OAuthCredential::GrantType,
+                    OAuthCredential::Scopes,
+                ],
+            },
         }
     }
 }
@@ -1,10 +1,15 @@
 use crate::auth::OAuthReq;
+use crate::discovery::jwtkeys::JWTKeys;
 use crate::oautherror::OAuthError;
+use chrono::Utc;
 use graph_error::GraphFailure;
+use ring::signature::{RsaPublicKeyComponents, RSA_PKCS1_2048_8192_SHA256};
 use serde_json::Map;
 use serde_json::Value;
 use std::collections::HashMap;
 use std::convert::TryFrom;
+use std::error;
+use std::fmt;
 use std::str::FromStr;
 
 /// Enum for the type of JSON web token (JWT).
@@ -112,6 +117,8 @@ impl FromStr for Algorithm {
 pub struct Header {
     typ: Option<String>,
     alg: Algorithm,
+    kid: Option<String>,
+    x5t: Option<String>,
 }
 
 impl Header {
@@ -122,6 +129,135 @@ impl Header {
     pub fn alg(&self) -> Algorithm {
         self.alg
     }
+
+    pub fn kid(&self) -> Option<String> {
+        self.kid.clone()
+    }
+
+    pub fn x5t(&self) -> Option<String> {
+        self.x5t.clone()
+    }
+}
+
+/// Errors returned when a JSON web token (JWT) fails validation.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ValidationError {
+    /// The JWT could not be parsed.
+    Malformed,
+    /// The JWT is not signed using RS256.
+    UnsupportedAlgorithm(Algorithm),
+    /// The header does not have a kid or x5t to select the signing key.
+    MissingKeyId,
+    /// None of the signing keys match the kid or x5t of the header.
+    KeyNotFound(String),
+    /// The signing key does not have a valid RSA modulus and exponent.
+    InvalidKey(String),
+    /// The signature does not match the signing key.
+    InvalidSignature,
+    /// A claim that is required by the validation is missing.
+    MissingClaim(String),
+    /// The exp claim is in the past.
+    Expired,
+    /// The nbf claim is in the future.
+    NotYetValid,
+    InvalidIssuer(String),
+    InvalidAudience(String),
+    InvalidNonce,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::Malformed => write!(f, "Malformed JWT"),
+            ValidationError::UnsupportedAlgorithm(alg) => {
+                write!(f, "Unsupported algorithm: {:?}", alg)
+            },
+            ValidationError::MissingKeyId => write!(f, "Missing kid"),
+            ValidationError::KeyNotFound(kid) => write!(f, "No signing key found for: {}", kid),
+            ValidationError::InvalidKey(kid) => write!(f, "Invalid signing key: {}", kid),
+            ValidationError::InvalidSignature => write!(f, "Invalid signature"),
+            ValidationError::MissingClaim(claim) => write!(f, "Missing claim: {}", claim),
+            ValidationError::Expired => write!(f, "Expired"),
+            ValidationError::NotYetValid => write!(f, "Not yet valid"),
+            ValidationError::InvalidIssuer(iss) => write!(f, "Invalid issuer: {}", iss),
+            ValidationError::InvalidAudience(aud) => write!(f, "Invalid audience: {}", aud),
+            ValidationError::InvalidNonce => write!(f, "Invalid nonce"),
+        }
+    }
+}
+
+impl error::Error for ValidationError {}
+
+impl From<ValidationError> for GraphFailure {
+    fn from(err: ValidationError) -> Self {
+        OAuthError::invalid(err.to_string().as_str())
+    }
+}
+
+/// The claims that are checked when validating a JSON web token (JWT).
+///
+/// The exp and nbf claims are always checked. The issuer, audience
+/// and nonce are only checked when they are set.
+///
+/// # Example
+/// ```
+/// # use graph_oauth::jwt::Validation;
+/// let mut validation = Validation::new();
+/// validation
+///     .issuer("https://login.microsoftonline.com/{tenantid}/v2.0")
+///     .audience("<CLIENT_ID>")
+///     .nonce("7362CAEA-9CA5")
+///     .leeway(60);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Validation {
+    issuer: Option<String>,
+    audience: Option<String>,
+    nonce: Option<String>,
+    leeway: i64,
+}
+
+impl Validation {
+    pub fn new() -> Validation {
+        Validation::default()
+    }
+
+    /// The expected issuer. For tokens from the common endpoint the
+    /// issuer can include {tenantid} which is replaced by the tid claim.
+    pub fn issuer(&mut self, issuer: &str) -> &mut Validation {
+        self.issuer = Some(issuer.into());
+        self
+    }
+
+    /// The expected audience, usually the client id of the app.
+    pub fn audience(&mut self, audience: &str) -> &mut Validation {
+        self.audience = Some(audience.into());
+        self
+    }
+
+    /// The nonce that was sent in the authorization request for an id token.
+    pub fn nonce(&mut self, nonce: &str) -> &mut Validation {
+        self.nonce = Some(nonce.into());
+        self
+    }
+
+    /// The clock skew in seconds allowed when checking the exp and nbf
+    /// claims. Defaults to 300 seconds.
+    pub fn leeway(&mut self, seconds: i64) -> &mut Validation {
+        self.leeway = seconds;
+        self
+    }
+}
+
+impl Default for Validation {
+    fn default() -> Self {
+        Validation {
+            issuer: None,
+            audience: None,
+            nonce: None,
+            leeway: 300,
+        }
+    }
 }
 
 #[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
@@ -130,6 +266,8 @@ pub struct JsonWebToken {
     header: Option<Header>,
     payload: Option<Vec<Claim>>,
     signature: Option<String>,
+    #[serde(skip)]
+    signing_input: Option<String>,
 }
 
 impl JsonWebToken {
@@ -144,17 +282,145 @@ impl JsonWebToken {
     pub fn signature(&self) -> Option<&String> {
         self.signature.as_ref()
     }
+
+    /// Get a claim by key.
+    pub fn claim(&self, key: &str) -> Option<&Value> {
+        self.payload
+            .as_ref()?
+            .iter()
+            .find(|claim| claim.key.eq(key))
+            .map(|claim| &claim.value)
+    }
+
+    /// Validate the signature and claims of the JWT.
+    ///
+    /// The signature is verified using the key from the JSON web key set
+    /// that matches the kid of the header. Only RS256 is supported which is
+    /// used by the Microsoft identity platform. The keys can be retrieved
+    /// using `JWTKeys::discovery`.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// # use graph_oauth::jwt::{JwtParser, Validation};
+    /// # use graph_oauth::oauth::jwtkeys::JWTKeys;
+    /// let keys = JWTKeys::discovery()?;
+    /// let jwt = JwtParser::parse(id_token.as_str())?;
+    ///
+    /// let mut validation = Validation::new();
+    /// validation.audience("<CLIENT_ID>").nonce("<NONCE>");
+    /// jwt.validate(&keys, &validation)?;
+    /// ```
+    pub fn validate(&self, keys: &JWTKeys, validation: &Validation) -> Result<(), ValidationError> {
+        self.verify_signature(keys)?;
+
+        let now = Utc::now().timestamp();
+        let exp = self
+            .claim("exp")
+            .and_then(|exp| exp.as_i64())
+            .ok_or_else(|| ValidationError::MissingClaim("exp".into()))?;
+        if now - validation.leeway > exp {
+            return Err(ValidationError::Expired);
+        }
+        if let Some(nbf) = self.claim("nbf").and_then(|nbf| nbf.as_i64()) {
+            if now + validation.leeway < nbf {
+                return Err(ValidationError::NotYetValid);
+            }
+        }
+
+        if let Some(issuer) = validation.issuer.as_ref() {
+            let iss = self.string_claim("iss")?;
+            let expected = match self.claim("tid").and_then(|tid| tid.as_str()) {
+                Some(tid) => issuer.replace("{tenantid}", tid),
+                None => issuer.to_string(),
+            };
+            if iss != expected {
+                return Err(ValidationError::InvalidIssuer(iss.to_string()));
+            }
+        }
+
+        if let Some(audience) = validation.audience.as_ref() {
+            let aud = self
+                .claim("aud")
+                .ok_or_else(|| ValidationError::MissingClaim("aud".into()))?;
+            let valid = match aud {
+                Value::String(aud) => aud.eq(audience),
+                Value::Array(aud) => aud.iter().any(|aud| aud.as_str() == Some(audience)),
+                _ => false,
+            };
+            if !valid {
+                return Err(ValidationError::InvalidAudience(aud.to_string()));
+            }
+        }
+
+        if let Some(nonce) = validation.nonce.as_ref() {
+            if self.string_claim("nonce")? != nonce {
+                return Err(ValidationError::InvalidNonce);
+            }
+        }
+        Ok(())
+    }
+
+    fn string_claim(&self, key: &str) -> Result<&str, ValidationError> {
+        self.claim(key)
+            .and_then(|value| value.as_str())
+            .ok_or_else(|| ValidationError::MissingClaim(key.into()))
+    }
+
+    fn verify_signature(&self, keys: &JWTKeys) -> Result<(), ValidationError> {
+        let header = self.header.as_ref().ok_or(ValidationError::Malformed)?;
+        if header.alg != Algorithm::RS256 {
+            return Err(ValidationError::UnsupportedAlgorithm(header.alg));
+        }
+
+        // The x5t is used when the header does not have a kid.
+        let key = match (header.kid.as_ref(), header.x5t.as_ref()) {
+            (Some(kid), _) => keys
+                .key(kid)
+                .ok_or_else(|| ValidationError::KeyNotFound(kid.to_string()))?,
+            (None, Some(x5t)) => keys
+                .key_by_thumbprint(x5t)
+                .ok_or_else(|| ValidationError::KeyNotFound(x5t.to_string()))?,
+            (None, None) => return Err(ValidationError::MissingKeyId),
+        };
+
+        let key_id = key.kid.clone().unwrap_or_default();
+        let decode = |value: &Option<String>| {
+            value
+                .as_ref()
+                .and_then(|value| base64::decode_config(value, base64::URL_SAFE_NO_PAD).ok())
+                .ok_or_else(|| ValidationError::InvalidKey(key_id.clone()))
+        };
+        let public_key = RsaPublicKeyComponents {
+            n: decode(&key.n)?,
+            e: decode(&key.e)?,
+        };
+
+        let signing_input = self
+            .signing_input
+            .as_ref()
+            .ok_or(ValidationError::Malformed)?;
+        let signature = self
+            .signature
+            .as_ref()
+            .and_then(|signature| base64::decode_config(signature, base64::URL_SAFE_NO_PAD).ok())
+            .ok_or(ValidationError::Malformed)?;
+        public_key
+            .verify(
+                &RSA_PKCS1_2048_8192_SHA256,
+                signing_input.as_bytes(),
+                &signature,
+            )
+            .map_err(|_| ValidationError::InvalidSignature)
+    }
 }
 
-/// TODO(#4): JWT Validation - https://github.com/sreeise/rust-onedrive/issues/4
+/// JSON web token (JWT) parsing for RFC 7619
 ///
-/// JSON web token (JWT) verification for RFC 7619
-///
-/// The JWT implementation does not implement full JWT verification.
+/// The parser does not verify the JWT.
 /// The validation here is best effort to follow section 7.2 of RFC 7519 for
 /// JWT validation: https://tools.ietf.org/html/rfc7519#section-7.2
 ///
-/// Callers should not rely on this alone to verify JWTs
+/// Use `JsonWebToken::validate` to verify the signature and claims.
 pub struct JwtParser;
 
 impl JwtParser {
@@ -206,7 +472,7 @@ impl JwtParser {
         let payload = key_vec.get(1);
 
         if let Some(p) = payload {
-            let t = base64::decode(&**p)?;
+            let t = base64::decode_config(&**p, base64::URL_SAFE_NO_PAD)?;
             let v_utf8 = std::str::from_utf8(&t)?;
             let v_owned = v_utf8.to_owned();
 
@@ -234,6 +500,14 @@ impl JwtParser {
         }
         // Step 10.
 
+        // The header and payload are kept for verifying the signature.
+        if jwt_type == JwtType::JWS {
+            if let Some(index) = input.rfind('.') {
+                jwt.signing_input = Some(input[..index].to_string());
+                jwt.signature = Some(input[index + 1..].to_string());
+            }
+        }
+
         jwt.payload = Some(claims);
         Ok(jwt)
     }
@@ -62,6 +62,16 @@
 //! println!("{:#?}", access_token);
 //! ```
 //!
+//! Desktop and command line apps can instead use a loopback listener to
+//! receive the redirect and request the access token in one call:
+//! ```rust,ignore
+//! # use graph_oauth::oauth::{LoopbackListener, OAuth};
+//! # let mut oauth = OAuth::new();
+//! let listener = LoopbackListener::bind(0).unwrap();
+//! let mut request = oauth.build().authorization_code_grant();
+//! let access_token = request.loopback_authorization(listener).unwrap();
+//! ```
+//!
 
 #![feature(vec_remove_item)]
 #![feature(try_trait)]
@@ -73,23 +83,35 @@ extern crate strum_macros;
 
 mod accesstoken;
 mod auth;
+mod authority;
+mod clientcertificate;
+mod devicecode;
 mod discovery;
 mod grants;
 mod idtoken;
 pub mod jwt;
+mod loopback;
 mod oautherror;
+mod tokencache;
 
 pub mod oauth {
     pub use crate::accesstoken::AccessToken;
     pub use crate::auth::GrantSelector;
     pub use crate::auth::OAuth;
     pub use crate::auth::OAuthCredential;
+    pub use crate::authority::Authority;
+    pub use crate::authority::AzureCloud;
+    pub use crate::clientcertificate::pem_to_der;
+    pub use crate::clientcertificate::ClientCertificate;
+    pub use crate::devicecode::DeviceCode;
     pub use crate::discovery::graphdiscovery;
     pub use crate::discovery::jwtkeys;
     pub use crate::discovery::wellknown;
     pub use crate::grants::GrantRequest;
     pub use crate::grants::GrantType;
     pub use crate::idtoken::IdToken;
+    pub use crate::loopback::LoopbackListener;
     pub use crate::oautherror::OAuthError;
     pub use crate::strum::IntoEnumIterator;
+    pub use crate::tokencache::*;
 }
@@ -0,0 +1,271 @@
+use crate::auth::OAuthReq;
+use crate::grants::{GrantRequest, GrantType};
+use crate::oautherror::OAuthError;
+use std::collections::HashMap;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{Ipv4Addr, TcpListener, TcpStream};
+use std::thread;
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt};
+use url::Url;
+
+const SIGN_IN_COMPLETE: &str =
+    "<html><body>Sign in complete. You can close this window.</body></html>";
+const SIGN_IN_FAILED: &str = "<html><body>Sign in failed. You can close this window.</body></html>";
+
+// How long a single connection can take to send its request line.
+const CONNECTION_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A local HTTP listener bound to the loopback interface that receives
+/// the redirect from the authorization endpoint for the authorization
+/// code grant.
+///
+/// The listener is used by the loopback authorization methods of the
+/// authorization code grant which open the authorization url in the
+/// default browser, wait for the redirect and request an access token
+/// using the returned code and PKCE code verifier. The app must have
+/// `http://127.0.0.1` registered as a redirect uri for mobile and
+/// desktop applications. Any port can then be used.
+///
+/// The redirect uri uses 127.0.0.1 instead of localhost because the
+/// listener is bound to 127.0.0.1 and localhost may resolve to ::1.
+///
+/// # See
+/// [Microsoft identity platform redirect URI restrictions](https://docs.microsoft.com/en-us/azure/active-directory/develop/reply-url#localhost-exceptions)
+///
+/// # Example
+/// ```rust,ignore
+/// # use graph_oauth::oauth::{LoopbackListener, OAuth};
+/// let mut oauth = OAuth::new();
+/// oauth
+///     .client_id("<CLIENT_ID>")
+///     .authorize_url("https://login.microsoftonline.com/common/oauth2/v2.0/authorize")
+///     .access_token_url("https://login.microsoftonline.com/common/oauth2/v2.0/token")
+///     .add_scope("Files.Read")
+///     .add_scope("offline_access");
+///
+/// // Bind to any available port.
+/// let listener = LoopbackListener::bind(0)?;
+/// let mut request = oauth.build().authorization_code_grant();
+/// let access_token = request.loopback_authorization(listener)?;
+/// ```
+#[derive(Debug)]
+pub struct LoopbackListener {
+    listener: TcpListener,
+    port: u16,
+    timeout: Duration,
+    open_browser: bool,
+}
+
+impl LoopbackListener {
+    /// Bind the listener to the port on 127.0.0.1. Use port 0 to bind
+    /// to any available port.
+    pub fn bind(port: u16) -> OAuthReq<LoopbackListener> {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port))?;
+        let port = listener.local_addr()?.port();
+        Ok(LoopbackListener {
+            listener,
+            port,
+            timeout: Duration::from_secs(300),
+            open_browser: true,
+        })
+    }
+
+    /// The port the listener is bound to.
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// The redirect uri sent in the authorization and access token requests.
+    pub fn redirect_uri(&self) -> String {
+        format!("http://127.0.0.1:{}", self.port)
+    }
+
+    /// Set how long to wait for the redirect. Defaults to 5 minutes.
+    pub fn timeout(&mut self, timeout: Duration) -> &mut LoopbackListener {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Set whether the authorization url is opened in the default browser.
+    /// Defaults to true.
+    pub fn open_browser(&mut self, value: bool) -> &mut LoopbackListener {
+        self.open_browser = value;
+        self
+    }
+
+    pub(crate) fn opens_browser(&self) -> bool {
+        self.open_browser
+    }
+
+    /// Wait for the redirect and return the authorization code.
+    ///
+    /// Requests that are not the redirect, such as a request by the
+    /// browser for a favicon, and redirects with a state that does not
+    /// match are ignored. Connections that fail or are idle for more than
+    /// 10 seconds are dropped. An error is returned if the redirect has an
+    /// error or the timeout is reached first.
+    pub fn accept(&self, state: &str) -> OAuthReq<String> {
+        // The listener does not block so that the timeout can be checked.
+        self.listener.set_nonblocking(true)?;
+        let deadline = Instant::now() + self.timeout;
+        loop {
+            match self.listener.accept() {
+                Ok((stream, _)) => match handle(stream, state) {
+                    Ok(redirect) => {
+                        if let Some(result) = redirect.into_result() {
+                            return result;
+                        }
+                    },
+                    Err(e) => log::warn!("loopback listener connection failed: {:?}", e),
+                },
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
+                    if Instant::now() > deadline {
+                        return timed_out();
+                    }
+                    thread::sleep(Duration::from_millis(50));
+                },
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+
+    /// Wait for the redirect and return the authorization code.
+    ///
+    /// This is the async version of `accept`.
+    pub async fn async_accept(self, state: &str) -> OAuthReq<String> {
+        self.listener.set_nonblocking(true)?;
+        let mut listener = tokio::net::TcpListener::from_std(self.listener)?;
+        match tokio::time::timeout(self.timeout, async_accept(&mut listener, state)).await {
+            Ok(result) => result,
+            Err(_) => timed_out(),
+        }
+    }
+}
+
+async fn async_accept(listener: &mut tokio::net::TcpListener, state: &str) -> OAuthReq<String> {
+    loop {
+        let (stream, _) = listener.accept().await?;
+        match tokio::time::timeout(CONNECTION_TIMEOUT, async_handle(stream, state)).await {
+            Ok(Ok(redirect)) => {
+                if let Some(result) = redirect.into_result() {
+                    return result;
+                }
+            },
+            Ok(Err(e)) => log::warn!("loopback listener connection failed: {:?}", e),
+            Err(_) => log::warn!("loopback listener connection timed out"),
+        }
+    }
+}
+
+// The request received by the listener.
+enum Redirect {
+    Code(String),
+    Error(String),
+    // The redirect has a state that does not match the authorization request.
+    InvalidState,
+    // Not a redirect such as a request for a favicon.
+    Other,
+}
+
+impl Redirect {
+    // The result of the authorization or None if the listener should
+    // keep waiting for the redirect.
+    fn into_result(self) -> Option<OAuthReq<String>> {
+        match self {
+            Redirect::Code(code) => Some(Ok(code)),
+            Redirect::Error(msg) => Some(OAuthError::grant_error(
+                GrantType::AuthorizationCode,
+                GrantRequest::Authorization,
+                msg.as_str(),
+            )),
+            Redirect::InvalidState => {
+                log::warn!(
+                    "loopback listener ignored a redirect with a state that does not match the authorization request"
+                );
+                None
+            },
+            Redirect::Other => None,
+        }
+    }
+}
+
+fn handle(stream: TcpStream, state: &str) -> OAuthReq<Redirect> {
+    stream.set_nonblocking(false)?;
+    stream.set_read_timeout(Some(CONNECTION_TIMEOUT))?;
+    let mut reader = BufReader::new(stream);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+
+    let redirect = redirect(request_line.as_str(), state);
+    reader.get_mut().write_all(response(&redirect).as_bytes())?;
+    Ok(redirect)
+}
+
+async fn async_handle(stream: tokio::net::TcpStream, state: &str) -> OAuthReq<Redirect> {
+    let mut reader = tokio::io::BufReader::new(stream);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).await?;
+
+    let redirect = redirect(request_line.as_str(), state);
+    reader
+        .get_mut()
+        .write_all(response(&redirect).as_bytes())
+        .await?;
+    Ok(redirect)
+}
+
+// Get the code or error from the request line of the redirect. The
+// state is checked before either is used so that a request with a
+// different state, such as one sent by another local page, can't end
+// the authorization.
+fn redirect(request_line: &str, state: &str) -> Redirect {
+    let path = match request_line.split_whitespace().nth(1) {
+        Some(path) => path,
+        None => return Redirect::Other,
+    };
+    let url = match Url::parse(format!("http://localhost{}", path).as_str()) {
+        Ok(url) => url,
+        Err(_) => return Redirect::Other,
+    };
+    let query: HashMap<String, String> = url.query_pairs().into_owned().collect();
+    if !query.contains_key("code") && !query.contains_key("error") {
+        return Redirect::Other;
+    }
+    if query.get("state").map(|s| s.as_str()) != Some(state) {
+        return Redirect::InvalidState;
+    }
+
+    if let Some(error) = query.get("error") {
+        return match query.get("error_description") {
+            Some(description) => Redirect::Error(format!("{}: {}", error, description)),
+            None => Redirect::Error(error.to_string()),
+        };
+    }
+    match query.get("code") {
+        Some(code) => Redirect::Code(code.to_string()),
+        None => Redirect::Other,
+    }
+}
+
+fn response(redirect: &Redirect) -> String {
+    let (status, body) = match redirect {
+        Redirect::Code(_) => ("200 OK", SIGN_IN_COMPLETE),
+        Redirect::Error(_) | Redirect::InvalidState => ("400 Bad Request", SIGN_IN_FAILED),
+        Redirect::Other => ("404 Not Found", ""),
+    };
+    format!(
+        "HTTP/1.1 {}\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    )
+}
+
+fn timed_out<T>() -> OAuthReq<T> {
+    OAuthError::grant_error(
+        GrantType::AuthorizationCode,
+        GrantRequest::Authorization,
+        "Timed out waiting for the authorization redirect",
+    )
+}
@@ -0,0 +1,394 @@
+use crate::accesstoken::AccessToken;
+use crate::auth::{OAuth, OAuthCredential, OAuthReq};
+use graph_error::GraphFailure;
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM, NONCE_LEN};
+use ring::rand::SecureRandom;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use url::Url;
+
+/// The key that access tokens are stored under in a token cache.
+///
+/// Tokens are cached per client id, tenant, account and set of scopes
+/// so that a token is never returned for a request that it was not
+/// issued for.
+///
+/// # Example
+/// ```
+/// # use graph_oauth::oauth::{OAuth, TokenCacheKey};
+/// let mut oauth = OAuth::new();
+/// oauth
+///     .client_id("client_id")
+///     .access_token_url("https://login.microsoftonline.com/common/oauth2/v2.0/token")
+///     .add_scope("Files.Read");
+///
+/// let key = TokenCacheKey::from(&oauth);
+/// assert_eq!(key.tenant(), Some("common"));
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub struct TokenCacheKey {
+    client_id: String,
+    tenant: Option<String>,
+    account: Option<String>,
+    scopes: BTreeSet<String>,
+}
+
+impl TokenCacheKey {
+    pub fn new<T: ToString, I: IntoIterator<Item = T>>(
+        client_id: &str,
+        tenant: Option<&str>,
+        account: Option<&str>,
+        scopes: I,
+    ) -> TokenCacheKey {
+        TokenCacheKey {
+            client_id: client_id.into(),
+            tenant: tenant.map(|s| s.to_string()),
+            account: account.map(|s| s.to_string()),
+            scopes: scopes.into_iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    pub fn client_id(&self) -> &str {
+        self.client_id.as_str()
+    }
+
+    pub fn tenant(&self) -> Option<&str> {
+        self.tenant.as_deref()
+    }
+
+    pub fn account(&self) -> Option<&str> {
+        self.account.as_deref()
+    }
+
+    pub fn scopes(&self) -> &BTreeSet<String> {
+        &self.scopes
+    }
+
+    pub(crate) fn with_account(mut self, account: Option<String>) -> TokenCacheKey {
+        self.account = account;
+        self
+    }
+
+    // The key used for the token in a JSON file.
+    fn file_key(&self) -> String {
+        let scopes: Vec<&str> = self.scopes.iter().map(|s| s.as_str()).collect();
+        vec![
+            self.client_id.as_str(),
+            self.tenant.as_deref().unwrap_or_default(),
+            self.account.as_deref().unwrap_or_default(),
+            scopes.join(" ").as_str(),
+        ]
+        .join("|")
+    }
+}
+
+/// The tenant is the first path segment of the Microsoft token endpoint,
+/// such as common or the tenant id in
+/// https://login.microsoftonline.com/{tenant}/oauth2/v2.0/token.
+/// The account is the login hint or the username.
+impl From<&OAuth> for TokenCacheKey {
+    fn from(oauth: &OAuth) -> Self {
+        let tenant = oauth
+            .get(OAuthCredential::AccessTokenURL)
+            .and_then(|url| Url::parse(url.as_str()).ok())
+            .and_then(|url| {
+                url.path_segments()?
+                    .find(|s| !s.is_empty())
+                    .map(|s| s.to_string())
+            });
+        let account = oauth
+            .get(OAuthCredential::LoginHint)
+            .or_else(|| oauth.get(OAuthCredential::Username));
+
+        TokenCacheKey {
+            client_id: oauth.get(OAuthCredential::ClientId).unwrap_or_default(),
+            tenant,
+            account,
+            scopes: oauth.get_scopes().clone(),
+        }
+    }
+}
+
+/// Storage for access tokens.
+///
+/// A token cache set on OAuth is checked before a request for an access
+/// token is sent to the token endpoint and a token that has not expired is
+/// returned instead. Access tokens returned by the token endpoint, including
+/// refreshed tokens, are written to the cache.
+///
+/// # Example
+/// ```
+/// # use graph_oauth::oauth::{OAuth, InMemoryTokenCache};
+/// let mut oauth = OAuth::new();
+/// oauth.token_cache(InMemoryTokenCache::new());
+/// ```
+pub trait TokenCache: Send + Sync {
+    fn get(&self, key: &TokenCacheKey) -> OAuthReq<Option<AccessToken>>;
+
+    fn set(&self, key: &TokenCacheKey, access_token: &AccessToken) -> OAuthReq<()>;
+
+    fn remove(&self, key: &TokenCacheKey) -> OAuthReq<()>;
+
+    fn clear(&self) -> OAuthReq<()>;
+}
+
+/// A token cache that only lasts for the life of the process.
+#[derive(Debug, Default)]
+pub struct InMemoryTokenCache {
+    tokens: Mutex<HashMap<TokenCacheKey, AccessToken>>,
+}
+
+impl InMemoryTokenCache {
+    pub fn new() -> InMemoryTokenCache {
+        InMemoryTokenCache::default()
+    }
+
+    fn tokens(&self) -> OAuthReq<std::sync::MutexGuard<HashMap<TokenCacheKey, AccessToken>>> {
+        self.tokens
+            .lock()
+            .map_err(|_| GraphFailure::invalid("token cache lock"))
+    }
+}
+
+impl TokenCache for InMemoryTokenCache {
+    fn get(&self, key: &TokenCacheKey) -> OAuthReq<Option<AccessToken>> {
+        Ok(self.tokens()?.get(key).cloned())
+    }
+
+    fn set(&self, key: &TokenCacheKey, access_token: &AccessToken) -> OAuthReq<()> {
+        self.tokens()?.insert(key.clone(), access_token.clone());
+        Ok(())
+    }
+
+    fn remove(&self, key: &TokenCacheKey) -> OAuthReq<()> {
+        self.tokens()?.remove(key);
+        Ok(())
+    }
+
+    fn clear(&self) -> OAuthReq<()> {
+        self.tokens()?.clear();
+        Ok(())
+    }
+}
+
+/// A token cache that stores access tokens in a JSON file.
+///
+/// The file can optionally be encrypted using AES-256-GCM with a 32 byte
+/// key provided by the caller. The key should be stored somewhere other
+/// than the file system such as the platform keychain.
+///
+/// # Example
+/// ```rust,ignore
+/// # use graph_oauth::oauth::{OAuth, FileTokenCache};
+/// let mut oauth = OAuth::new();
+/// oauth.token_cache(FileTokenCache::new("./tokens.json"));
+///
+/// // Encrypted
+/// let key: [u8; 32] = load_key();
+/// oauth.token_cache(FileTokenCache::encrypted("./tokens.json", &key)?);
+/// ```
+pub struct FileTokenCache {
+    path: PathBuf,
+    key: Option<LessSafeKey>,
+    lock: Mutex<()>,
+}
+
+impl FileTokenCache {
+    pub fn new<P: AsRef<Path>>(path: P) -> FileTokenCache {
+        FileTokenCache {
+            path: path.as_ref().to_path_buf(),
+            key: None,
+            lock: Mutex::new(()),
+        }
+    }
+
+    /// Create a file token cache that is encrypted with the given 32 byte key.
+    pub fn encrypted<P: AsRef<Path>>(path: P, key: &[u8]) -> OAuthReq<FileTokenCache> {
+        let key = UnboundKey::new(&AES_256_GCM, key)
+            .map_err(|_| GraphFailure::invalid("token cache key. The key must be 32 bytes"))?;
+        Ok(FileTokenCache {
+            path: path.as_ref().to_path_buf(),
+            key: Some(LessSafeKey::new(key)),
+            lock: Mutex::new(()),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        self.path.as_path()
+    }
+
+    pub fn is_encrypted(&self) -> bool {
+        self.key.is_some()
+    }
+
+    fn read(&self) -> OAuthReq<BTreeMap<String, AccessToken>> {
+        let content = match fs::read(self.path.as_path()) {
+            Ok(content) => content,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(BTreeMap::new()),
+            Err(err) => return Err(GraphFailure::from(err)),
+        };
+
+        match self.key.as_ref() {
+            Some(key) => {
+                let mut content = base64::decode(&content)?;
+                if content.len() < NONCE_LEN {
+                    return Err(GraphFailure::CryptoError);
+                }
+                let mut in_out = content.split_off(NONCE_LEN);
+                let nonce = Nonce::try_assume_unique_for_key(&content)?;
+                let plain_text = key.open_in_place(nonce, Aad::empty(), &mut in_out)?;
+                Ok(serde_json::from_slice(plain_text)?)
+            },
+            None => Ok(serde_json::from_slice(&content)?),
+        }
+    }
+
+    fn write(&self, tokens: &BTreeMap<String, AccessToken>) -> OAuthReq<()> {
+        let content = serde_json::to_vec(tokens)?;
+        match self.key.as_ref() {
+            Some(key) => {
+                let mut nonce = [0; NONCE_LEN];
+                ring::rand::SystemRandom::new().fill(&mut nonce)?;
+                let mut in_out = content;
+                key.seal_in_place_append_tag(
+                    Nonce::assume_unique_for_key(nonce),
+                    Aad::empty(),
+                    &mut in_out,
+                )?;
+                let mut sealed = nonce.to_vec();
+                sealed.extend(in_out);
+                self.write_file(base64::encode(&sealed).as_bytes())
+            },
+            None => self.write_file(&content),
+        }
+    }
+
+    // Tokens are written to a temporary file that only the current user
+    // can read and then renamed over the cache so that the cache is never
+    // left partly written or readable by other users.
+    fn write_file(&self, content: &[u8]) -> OAuthReq<()> {
+        let mut temp_path = self.path.clone().into_os_string();
+        temp_path.push(".tmp");
+        if let Err(err) = fs::remove_file(&temp_path) {
+            if err.kind() != ErrorKind::NotFound {
+                return Err(GraphFailure::from(err));
+            }
+        }
+
+        let mut options = OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+
+        let mut file = options.open(&temp_path)?;
+        file.write_all(content)?;
+        file.sync_all()?;
+        drop(file);
+        fs::rename(&temp_path, self.path.as_path())?;
+        Ok(())
+    }
+
+    fn update<F>(&self, f: F) -> OAuthReq<()>
+    where
+        F: FnOnce(&mut BTreeMap<String, AccessToken>),
+    {
+        let _lock = self
+            .lock
+            .lock()
+            .map_err(|_| GraphFailure::invalid("token cache lock"))?;
+        let mut tokens = self.read()?;
+        f(&mut tokens);
+        self.write(&tokens)
+    }
+}
+
+impl TokenCache for FileTokenCache {
+    fn get(&self, key: &TokenCacheKey) -> OAuthReq<Option<AccessToken>> {
+        let _lock = self
+            .lock
+            .lock()
+            .map_err(|_| GraphFailure::invalid("token cache lock"))?;
+        Ok(self.read()?.remove(&key.file_key()))
+    }
+
+    fn set(&self, key: &TokenCacheKey, access_token: &AccessToken) -> OAuthReq<()> {
+        self.update(|tokens| {
+            tokens.insert(key.file_key(), access_token.clone());
+        })
+    }
+
+    fn remove(&self, key: &TokenCacheKey) -> OAuthReq<()> {
+        self.update(|tokens| {
+            tokens.remove(&key.file_key());
+        })
+    }
+
+    fn clear(&self) -> OAuthReq<()> {
+        self.update(|tokens| tokens.clear())
+    }
+}
+
+impl fmt::Debug for FileTokenCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FileTokenCache")
+            .field("path", &self.path)
+            .field("encrypted", &self.is_encrypted())
+            .finish()
+    }
+}
+
+/// The token cache stored in OAuth and in access token requests. The
+/// cache is not serialized and does not affect equality so that OAuth
+/// can still be written to and read from files.
+#[derive(Clone, Default)]
+pub(crate) struct TokenCacheHandle(Option<Arc<dyn TokenCache>>);
+
+impl TokenCacheHandle {
+    pub fn new(token_cache: Arc<dyn TokenCache>) -> TokenCacheHandle {
+        TokenCacheHandle(Some(token_cache))
+    }
+
+    pub fn get(&self) -> Option<&Arc<dyn TokenCache>> {
+        self.0.as_ref()
+    }
+
+    // An access token from the cache that has not expired.
+    pub fn cached(&self, key: &TokenCacheKey, read: bool) -> OAuthReq<Option<AccessToken>> {
+        match self.0.as_ref() {
+            Some(token_cache) if read => Ok(token_cache
+                .get(key)?
+                .filter(|access_token| !access_token.is_expired())),
+            _ => Ok(None),
+        }
+    }
+
+    pub fn store(&self, key: &TokenCacheKey, access_token: &AccessToken) -> OAuthReq<()> {
+        if let Some(token_cache) = self.0.as_ref() {
+            token_cache.set(key, access_token)?;
+        }
+        Ok(())
+    }
+}
+
+impl PartialEq for TokenCacheHandle {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for TokenCacheHandle {}
+
+impl fmt::Debug for TokenCacheHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TokenCacheHandle")
+            .field(&self.0.is_some())
+            .finish()
+    }
+}
@@ -1,5 +1,6 @@
 use crate::client::Graph;
 use crate::http::{GraphResponse, IntoResponse};
+use crate::models::Attachment;
 use crate::types::collection::Collection;
 use crate::types::content::Content;
 use reqwest::Method;
@@ -10,7 +11,7 @@ impl<'a, Client> AttachmentRequest<'a, Client>
 where
     Client: crate::http::RequestClient,
 {
-    get!( | get, serde_json::Value => "attachments/{{id}}" );
+    get!( | get, Attachment => "attachments/{{id}}" );
     get!( | content, GraphResponse<Content> => "attachments/{{id}}/$value" );
     delete!( | delete, GraphResponse<Content> => "attachments/{{id}}" );
 
@@ -45,10 +46,10 @@ impl<'a, Client> CalendarAttachmentRequest<'a, Client>
 where
     Client: crate::http::RequestClient,
 {
-    get!( || get_default, serde_json::Value => "events/{{id}}/attachments/{{id}}" );
+    get!( || get_default, Attachment => "events/{{id}}/attachments/{{id}}" );
     get!( || default_content, GraphResponse<Content> => "events/{{id}}/attachments/{{id}}/$value" );
     delete!( || delete_default, GraphResponse<Content> => "events/{{id}}/attachments/{{id}}" );
-    get!( ||| get, serde_json::Value => "calendar/{{id}}/events/{{id2}}/attachments/{{id3}}" );
+    get!( ||| get, Attachment => "calendar/{{id}}/events/{{id2}}/attachments/{{id3}}" );
     get!( ||| content, GraphResponse<Content> => "calendar/{{id}}/events/{{id2}}/attachments/{{id3}}/$value" );
     delete!( ||| delete, GraphResponse<Content> => "calendar/{{id}}/events/{{id2}}/attachments/{{id3}}" );
 }
@@ -59,10 +60,10 @@ impl<'a, Client> CalendarGroupAttachmentRequest<'a, Client>
 where
     Client: crate::http::RequestClient,
 {
-    get!( ||| get_default, serde_json::Value => "calendargroup/calendars/{{id}}/events/{{id2}}/attachments/{{id3}}" );
+    get!( ||| get_default, Attachment => "calendargroup/calendars/{{id}}/events/{{id2}}/attachments/{{id3}}" );
     get!( ||| default_content, GraphResponse<Content> => "calendargroup/calendars/{{id}}/events/{{id2}}/attachments/{{id3}}/$value" );
     delete!( ||| delete_default, GraphResponse<Content> => "calendargroup/calendars/{{id}}/events/{{id2}}/attachments/{{id3}}" );
-    get!( |||| get, serde_json::Value => "calendargroups/{{id}}/calendars/{{id2}}/events/{{id3}}/attachments/{{id4}}" );
+    get!( |||| get, Attachment => "calendargroups/{{id}}/calendars/{{id2}}/events/{{id3}}/attachments/{{id4}}" );
     get!( |||| content, GraphResponse<Content> => "calendargroups/{{id}}/calendars/{{id2}}/events/{{id3}}/attachments/{{id4}}/$value" );
     delete!( |||| delete, GraphResponse<Content> => "calendargroups/{{id}}/calendars/{{id2}}/events/{{id3}}/attachments/{{id4}}" );
 }
@@ -73,8 +74,8 @@ impl<'a, Client> MailMessageAttachmentRequest<'a, Client>
 where
     Client: crate::http::RequestClient,
 {
-    get!( || get, serde_json::Value => "messages/{{id}}/attachments/{{id2}}" );
-    post!( [ | add, serde_json::Value => "messages/{{id}}/attachments" ] );
+    get!( || get, Attachment => "messages/{{id}}/attachments/{{id2}}" );
+    post!( [ | add, Attachment => "messages/{{id}}/attachments" ] );
     get!( || content, GraphResponse<Content> => "messages/{{id}}/attachments/{{id2}}/$value" );
     delete!( || delete, GraphResponse<Content> => "messages/{{id}}/attachments/{{id2}}" );
 
@@ -89,9 +90,9 @@ impl<'a, Client> MailFolderMessageAttachmentRequest<'a, Client>
 where
     Client: crate::http::RequestClient,
 {
-    get!( ||| get, serde_json::Value => "mailFolders/{{id}}/messages/{{id2}}/attachments/{{id3}}" );
+    get!( ||| get, Attachment => "mailFolders/{{id}}/messages/{{id2}}/attachments/{{id3}}" );
     get!( ||| content, GraphResponse<Content> => "mailFolders/{{id}}/messages/{{id2}}/attachments/{{id3}}/$value" );
-    post!( [ || add, serde_json::Value => "mailFolders/{{id}}/messages/{{id2}}/attachments" ] );
+    post!( [ || add, Attachment => "mailFolders/{{id}}/messages/{{id2}}/attachments" ] );
     delete!( ||| delete, GraphResponse<Content> => "mailFolders/{{id}}/messages/{{id2}}/attachments/{{id3}}" );
 
     fn render_child_folder_path<S: AsRef<str>>(
@@ -138,7 +139,7 @@ where
         child_folders: &[&str],
         message_id: S,
         attachment_id: S,
-    ) -> IntoResponse<'a, serde_json::Value, Client> {
+    ) -> IntoResponse<'a, Attachment, Client> {
         self.client.request().set_method(Method::GET);
         self.render_child_folder_path(
             mail_folder_id,
@@ -193,8 +194,8 @@ impl<'a, Client> ThreadPostAttachmentRequest<'a, Client>
 where
     Client: crate::http::RequestClient,
 {
-    get!( || list, Collection<serde_json::Value> => "threads/{{id}}/posts/{{id2}}/attachments" );
-    get!( ||| get, serde_json::Value => "threads/{{id}}/posts/{{id2}}/attachments/{{id3}}" );
+    get!( || list, Collection<Attachment> => "threads/{{id}}/posts/{{id2}}/attachments" );
+    get!( ||| get, Attachment => "threads/{{id}}/posts/{{id2}}/attachments/{{id3}}" );
     get!( ||| content, GraphResponse<Content> => "threads/{{id}}/posts/{{id2}}/attachments/{{id3}}/$value" );
     delete!( ||| delete, GraphResponse<Content> => "threads/{{id}}/posts/{{id2}}/attachments/{{id3}}" );
 }
@@ -205,8 +206,8 @@ impl<'a, Client> ThreadConvoPostAttachmentRequest<'a, Client>
 where
     Client: crate::http::RequestClient,
 {
-    get!( ||| list, Collection<serde_json::Value> => "conversations/{{id}}/threads/{{id2}}/posts/{{id3}}/attachments" );
-    get!( |||| get, serde_json::Value => "conversations/{{id}}/threads/{{id2}}/posts/{{id3}}/attachments/{{id4}}" );
+    get!( ||| list, Collection<Attachment> => "conversations/{{id}}/threads/{{id2}}/posts/{{id3}}/attachments" );
+    get!( |||| get, Attachment => "conversations/{{id}}/threads/{{id2}}/posts/{{id3}}/attachments/{{id4}}" );
     get!( |||| content, GraphResponse<Content> => "conversations/{{id}}/threads/{{id2}}/posts/{{id3}}/attachments/{{id4}}/$value" );
     delete!( |||| delete, GraphResponse<Content> => "conversations/{{id}}/threads/{{id2}}/posts/{{id3}}/attachments/{{id4}}" );
 }
@@ -1,7 +1,8 @@
 use crate::attachments::{CalendarAttachmentRequest, CalendarGroupAttachmentRequest};
 use crate::client::Graph;
 use crate::http::{GraphResponse, IntoResponse};
-use crate::types::{collection::Collection, content::Content};
+use crate::models::{Calendar, CalendarGroup, Event};
+use crate::types::{collection::Collection, content::Content, delta::DeltaRequest};
 use reqwest::Method;
 
 register_client!(CalendarRequest,);
@@ -10,14 +11,14 @@ impl<'a, Client> CalendarRequest<'a, Client>
 where
     Client: crate::http::RequestClient,
 {
-    get!( list, Collection<serde_json::Value> => "calendars" );
-    get!( get_default, serde_json::Value => "calendar" );
-    get!( | get, serde_json::Value => "calendars/{{id}}" );
-    get!( list_events, Collection<serde_json::Value> => "calendar/events" );
-    patch!( [ update_default, serde_json::Value => "calendar" ] );
-    patch!( [ | update, serde_json::Value => "calendars/{{id}}" ] );
-    post!( [ create, serde_json::Value => "calendars" ] );
-    post!( [ create_event, serde_json::Value => "calendar/events" ] );
+    get!( list, Collection<Calendar> => "calendars" );
+    get!( get_default, Calendar => "calendar" );
+    get!( | get, Calendar => "calendars/{{id}}" );
+    get!( list_events, Collection<Event> => "calendar/events" );
+    patch!( [ update_default, Calendar => "calendar" ] );
+    patch!( [ | update, Calendar => "calendars/{{id}}" ] );
+    post!( [ create, Calendar => "calendars" ] );
+    post!( [ create_event, Event => "calendar/events" ] );
     delete!( | delete, GraphResponse<Content> => "calendars/{{id}}" );
 
     pub fn attachments(&'a self) -> CalendarAttachmentRequest<'a, Client> {
@@ -43,7 +44,7 @@ where
         &self,
         start_date_time: &str,
         end_date_time: &str,
-    ) -> IntoResponse<'a, Collection<serde_json::Value>, Client> {
+    ) -> IntoResponse<'a, Collection<Event>, Client> {
         let request = self.client.request();
         request.set_method(Method::GET);
         request.url_mut(|url| {
@@ -59,7 +60,7 @@ where
         id: &str,
         start_date_time: &str,
         end_date_time: &str,
-    ) -> IntoResponse<'a, Collection<serde_json::Value>, Client> {
+    ) -> IntoResponse<'a, Collection<Event>, Client> {
         let request = self.client.request();
         request.set_method(Method::GET);
         request.url_mut(|url| {
@@ -75,7 +76,7 @@ where
         calendar_id: &str,
         start_date_time: &str,
         end_date_time: &str,
-    ) -> IntoResponse<'a, Collection<serde_json::Value>, Client> {
+    ) -> IntoResponse<'a, Collection<Event>, Client> {
         let request = self.client.request();
         request.set_method(Method::GET);
         request.url_mut(|url| {
@@ -92,7 +93,7 @@ where
         calendar_id: &str,
         start_date_time: &str,
         end_date_time: &str,
-    ) -> IntoResponse<'a, Collection<serde_json::Value>, Client> {
+    ) -> IntoResponse<'a, Collection<Event>, Client> {
         let request = self.client.request();
         request.set_method(Method::GET);
         request.url_mut(|url| {
@@ -108,6 +109,23 @@ where
         });
         IntoResponse::new(self.client)
     }
+
+    /// The events added, updated or removed in the calendar view of
+    /// the default calendar between the start and end date time.
+    pub fn delta(
+        &self,
+        start_date_time: &str,
+        end_date_time: &str,
+    ) -> IntoResponse<'a, DeltaRequest<Collection<Event>>, Client> {
+        let request = self.client.request();
+        request.set_method(Method::GET);
+        request.url_mut(|url| {
+            url.extend_path(&["calendarView", "delta"]);
+            url.append_query_pair("startDateTime", start_date_time);
+            url.append_query_pair("endDateTime", end_date_time);
+        });
+        IntoResponse::new(self.client)
+    }
 }
 
 register_client!(CalendarGroupRequest,);
@@ -116,22 +134,22 @@ impl<'a, Client> CalendarGroupRequest<'a, Client>
 where
     Client: crate::http::RequestClient,
 {
-    get!( list, Collection<serde_json::Value> => "calendarGroups" );
-    get!( list_default_calendars, Collection<serde_json::Value> => "calendarGroup/calendars" );
-    get!( | get, serde_json::Value => "calendarGroups/{{id}}" );
-    get!( | list_calendars, Collection<serde_json::Value> => "calendarGroups/{{id}}/calendars" );
-    get!( || list_events, Collection<serde_json::Value> => "calendarGroups/{{id}}/calendars/{{id2}}/events" );
-    get!( | list_default_events, Collection<serde_json::Value> => "calendarGroup/calendars/{{id}}/events" );
-    post!( [ || create_event, serde_json::Value => "calendarGroups/{{id}}/calendars/{{id2}}/events" ] );
-    post!( [ | create_default_event, serde_json::Value => "calendarGroup/calendars/{{id}}/events" ] );
-    post!( [ create, serde_json::Value => "calendarGroups" ] );
+    get!( list, Collection<CalendarGroup> => "calendarGroups" );
+    get!( list_default_calendars, Collection<Calendar> => "calendarGroup/calendars" );
+    get!( | get, CalendarGroup => "calendarGroups/{{id}}" );
+    get!( | list_calendars, Collection<Calendar> => "calendarGroups/{{id}}/calendars" );
+    get!( || list_events, Collection<Event> => "calendarGroups/{{id}}/calendars/{{id2}}/events" );
+    get!( | list_default_events, Collection<Event> => "calendarGroup/calendars/{{id}}/events" );
+    post!( [ || create_event, Event => "calendarGroups/{{id}}/calendars/{{id2}}/events" ] );
+    post!( [ | create_default_event, Event => "calendarGroup/calendars/{{id}}/events" ] );
+    post!( [ create, CalendarGroup => "calendarGroups" ] );
     post!([
         create_default_calendar,
-        serde_json::Value =>
+        Calendar =>
         "calendarGroups/calendars"
     ]);
-    post!( [ | create_calendar, serde_json::Value => "calendarGroups/{{id}}/calendars" ] );
-    patch!( [ | update, serde_json::Value => "calendarGroups/{{id}}" ] );
+    post!( [ | create_calendar, Calendar => "calendarGroups/{{id}}/calendars" ] );
+    patch!( [ | update, CalendarGroup => "calendarGroups/{{id}}" ] );
     delete!( | delete, GraphResponse<Content> => "calendarGroups/{{id}}" );
 
     pub fn attachments(&'a self) -> CalendarGroupAttachmentRequest<'a, Client> {
@@ -2,30 +2,40 @@ use crate::activities::ActivitiesRequest;
 use crate::attachments::AttachmentRequest;
 use crate::calendar::CalendarRequest;
 use crate::contacts::ContactsRequest;
-use crate::drive::DriveRequest;
+use crate::directory::{DirectoryObjectsRequest, DirectoryRolesRequest};
+use crate::drive::{DriveRequest, SharesRequest};
 use crate::education::{EducationMeRequest, EducationRequest, EducationUsersRequest};
 use crate::groups::{
     GroupConversationPostRequest, GroupConversationRequest, GroupThreadPostRequest,
 };
 use crate::http::{
-    AsyncHttpClient, BlockingHttpClient, GraphResponse, IntoResponse, RequestClient,
+    AsyncHttpClient, BatchRequest, BlockingHttpClient, GraphResponse, IntoResponse, Middleware,
+    OAuthTokenProvider, RequestClient, RetryPolicy, TokenProvider,
 };
 use crate::mail::MailRequest;
+use crate::models::{
+    Chat, DirectoryObject, DirectoryObjectReference, Drive, Event, Group, PlannerPlan, PlannerTask,
+    Team, User,
+};
 use crate::onenote::OnenoteRequest;
 use crate::planner::PlannerRequest;
+use crate::subscriptions::SubscriptionRequest;
+use crate::teams::{ChatsRequest, TeamsRequest};
+use crate::todo::TodoRequest;
 use crate::types::{
     boolresponse::BoolResponse, collection::Collection, content::Content, delta::DeltaRequest,
 };
 use crate::url::GraphUrl;
-use crate::{GRAPH_URL, GRAPH_URL_BETA};
-use graph_error::GraphFailure;
-use graph_oauth::oauth::{AccessToken, OAuth};
+use crate::GRAPH_URL;
+use graph_error::{GraphFailure, GraphResult};
+use graph_oauth::oauth::{AccessToken, AzureCloud, OAuth};
 use handlebars::*;
 use reqwest::header::{HeaderValue, ACCEPT};
 use reqwest::Method;
 use std::convert::TryFrom;
 use std::fmt::Debug;
 use std::str::FromStr;
+use std::sync::Arc;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum Ident {
@@ -63,25 +73,100 @@ where
     Client: crate::http::RequestClient,
 {
     pub fn v1(&'a self) -> Identify<'a, Client> {
-        self.request.set_url(GraphUrl::from_str(GRAPH_URL).unwrap());
+        self.request.set_url(self.version_url("v1.0"));
         Identify { client: &self }
     }
 
     /// Use the Graph beta API
     pub fn beta(&'a self) -> Identify<'a, Client> {
-        self.request
-            .set_url(GraphUrl::from_str(GRAPH_URL_BETA).unwrap());
+        self.request.set_url(self.version_url("beta"));
         Identify { client: &self }
     }
 
     /// Check if the current host is v1.0.
     pub fn is_v1(&self) -> bool {
-        self.request.url().as_str().starts_with(GRAPH_URL)
+        self.request
+            .url()
+            .as_str()
+            .starts_with(self.version_url("v1.0").as_str())
     }
 
     /// Check if the current host is beta.
     pub fn is_beta(&self) -> bool {
-        self.request.url().as_str().starts_with(GRAPH_URL_BETA)
+        self.request
+            .url()
+            .as_str()
+            .starts_with(self.version_url("beta").as_str())
+    }
+
+    /// Set the Azure cloud that requests are sent to. The v1 and
+    /// beta APIs use the Microsoft Graph host of the cloud.
+    pub fn set_azure_cloud(&self, azure_cloud: AzureCloud) {
+        let is_beta = self.is_beta();
+        self.request.set_azure_cloud(azure_cloud);
+        if is_beta {
+            self.request.set_url(self.version_url("beta"));
+        } else {
+            self.request.set_url(self.version_url("v1.0"));
+        }
+    }
+
+    /// The Azure cloud that requests are sent to.
+    pub fn azure_cloud(&self) -> AzureCloud {
+        self.request.azure_cloud()
+    }
+
+    /// Send requests to a custom endpoint, such as a proxy, in place of
+    /// the Microsoft Graph host of the Azure cloud. The v1 and beta APIs
+    /// use the custom endpoint until it is cleared.
+    ///
+    /// # Example
+    /// ```
+    /// # use graph_rs::client::Graph;
+    /// let client = Graph::new("ACCESS_TOKEN");
+    /// client.set_custom_endpoint("http://127.0.0.1:8080").unwrap();
+    /// assert!(client.is_v1());
+    /// client.url_ref(|url| assert_eq!(url.as_str(), "http://127.0.0.1:8080/v1.0"));
+    /// ```
+    pub fn set_custom_endpoint(&self, endpoint: &str) -> GraphResult<()> {
+        GraphUrl::parse(endpoint)?;
+        let is_beta = self.is_beta();
+        self.request
+            .set_custom_endpoint(Some(endpoint.trim_end_matches('/').to_string()));
+        if is_beta {
+            self.request.set_url(self.version_url("beta"));
+        } else {
+            self.request.set_url(self.version_url("v1.0"));
+        }
+        Ok(())
+    }
+
+    /// Send requests to the Microsoft Graph host of the Azure cloud again.
+    pub fn clear_custom_endpoint(&self) {
+        let is_beta = self.is_beta();
+        self.request.set_custom_endpoint(None);
+        if is_beta {
+            self.request.set_url(self.version_url("beta"));
+        } else {
+            self.request.set_url(self.version_url("v1.0"));
+        }
+    }
+
+    // The v1.0 or beta url of the host that is currently used.
+    pub(crate) fn base_url(&self) -> GraphUrl {
+        if self.is_beta() {
+            self.version_url("beta")
+        } else {
+            self.version_url("v1.0")
+        }
+    }
+
+    fn version_url(&self, version: &str) -> GraphUrl {
+        let host = self
+            .request
+            .custom_endpoint()
+            .unwrap_or_else(|| self.request.azure_cloud().graph_url().to_string());
+        GraphUrl::from_str(format!("{}/{}", host, version).as_str()).unwrap()
     }
 
     pub fn ident(&self) -> Ident {
@@ -93,6 +178,42 @@ where
         self.request.set_token(token);
     }
 
+    /// Set the provider that is asked for the bearer token before each
+    /// request. The provider is asked to refresh the token when a request
+    /// fails with a 401 and an invalid_token error and the request is
+    /// sent again once with the new token.
+    pub fn set_token_provider<T: TokenProvider + 'static>(&self, token_provider: T) {
+        self.request
+            .set_token_provider(Some(Arc::new(token_provider)));
+    }
+
+    /// Remove the token provider. Requests use the last bearer
+    /// token given by the provider or set with set_token.
+    pub fn clear_token_provider(&self) {
+        self.request.set_token_provider(None);
+    }
+
+    /// Set the policy used to retry throttled (429) requests and
+    /// requests that failed with a 503 or 504 status code.
+    pub fn set_retry_policy(&self, retry_policy: RetryPolicy) {
+        self.request.set_retry_policy(retry_policy);
+    }
+
+    /// Add a middleware to the end of the pipeline that requests
+    /// are sent through. Middleware runs in the order it was added
+    /// before a request is sent and in reverse order after the
+    /// response is received.
+    pub fn add_middleware<M: Middleware + 'static>(&self, middleware: M) {
+        let mut pipeline = self.request.pipeline();
+        pipeline.push(middleware);
+        self.request.set_pipeline(pipeline);
+    }
+
+    /// Remove all middleware from the pipeline.
+    pub fn clear_middleware(&self) {
+        self.request.set_pipeline(Default::default());
+    }
+
     pub(crate) fn request(&self) -> &Client {
         &self.request
     }
@@ -165,7 +286,20 @@ impl TryFrom<&OAuth> for GraphBlocking {
 
     fn try_from(oauth: &OAuth) -> Result<Self, Self::Error> {
         let access_token = oauth.get_access_token()?;
-        Ok(Graph::from(&access_token))
+        let client = Graph::from(&access_token);
+        client.set_azure_cloud(oauth.get_azure_cloud());
+        Ok(client)
+    }
+}
+
+impl From<OAuthTokenProvider> for GraphBlocking {
+    fn from(token_provider: OAuthTokenProvider) -> Self {
+        let client = Graph::new("");
+        if let Ok(oauth) = token_provider.oauth() {
+            client.set_azure_cloud(oauth.get_azure_cloud());
+        }
+        client.set_token_provider(token_provider);
+        client
     }
 }
 
@@ -224,7 +358,20 @@ impl TryFrom<&OAuth> for GraphAsync {
 
     fn try_from(oauth: &OAuth) -> Result<Self, Self::Error> {
         let access_token = oauth.get_access_token()?;
-        Ok(Graph::from(&access_token))
+        let client = Graph::from(&access_token);
+        client.set_azure_cloud(oauth.get_azure_cloud());
+        Ok(client)
+    }
+}
+
+impl From<OAuthTokenProvider> for GraphAsync {
+    fn from(token_provider: OAuthTokenProvider) -> Self {
+        let client = Graph::new_async("");
+        if let Ok(oauth) = token_provider.oauth() {
+            client.set_azure_cloud(oauth.get_azure_cloud());
+        }
+        client.set_token_provider(token_provider);
+        client
     }
 }
 
@@ -283,6 +430,39 @@ where
         EducationRequest::new(self.client)
     }
 
+    /// Select the subscriptions endpoint to create and manage
+    /// change notification subscriptions.
+    pub fn subscriptions(&self) -> SubscriptionRequest<'a, Client> {
+        SubscriptionRequest::new(self.client)
+    }
+
+    /// Select the teams endpoint for teams and their channels,
+    /// messages, tabs, installed apps and members.
+    pub fn teams(&self) -> TeamsRequest<'a, Client> {
+        TeamsRequest::new(self.client)
+    }
+
+    /// Select the chats endpoint.
+    pub fn chats(&self) -> ChatsRequest<'a, Client> {
+        ChatsRequest::new(self.client)
+    }
+
+    /// Select the shares endpoint to access shared items using a share
+    /// id or a sharing url encoded with `drive::encode_sharing_url`.
+    pub fn shares(&self) -> SharesRequest<'a, Client> {
+        SharesRequest::new(self.client)
+    }
+
+    /// Select the directory roles endpoint.
+    pub fn directory_roles(&self) -> DirectoryRolesRequest<'a, Client> {
+        DirectoryRolesRequest::new(self.client)
+    }
+
+    /// Select the directory objects endpoint.
+    pub fn directory_objects(&self) -> DirectoryObjectsRequest<'a, Client> {
+        DirectoryObjectsRequest::new(self.client)
+    }
+
     pub fn batch<B: serde::Serialize>(
         &self,
         batch: &B,
@@ -299,6 +479,12 @@ where
         render_path!(self.client, "$batch", &serde_json::json!({}));
         IntoResponse::new(self.client)
     }
+
+    /// Combine requests from the request builders into a JSON batch.
+    /// See [BatchRequest](../http/struct.BatchRequest.html).
+    pub fn batch_request(&self) -> BatchRequest<'a, Client> {
+        BatchRequest::new(self.client, self.client.request().url())
+    }
 }
 
 register_ident_client!(IdentMe,);
@@ -311,10 +497,12 @@ impl<'a, Client> IdentMe<'a, Client>
 where
     Client: crate::http::RequestClient,
 {
-    get!( get, serde_json::Value => "me" );
-    get!( list_events, Collection<serde_json::Value> => "me/events" );
+    get!( get, User => "me" );
+    get!( list_events, Collection<Event> => "me/events" );
     get!( settings, serde_json::Value => "me/settings" );
-    get!(list_planner_tasks, Collection<serde_json::Value> => "me/planner/tasks");
+    get!(list_planner_tasks, Collection<PlannerTask> => "me/planner/tasks");
+    get!( list_joined_teams, Collection<Team> => "me/joinedTeams" );
+    get!( list_chats, Collection<Chat> => "me/chats" );
     patch!( [ update_settings, serde_json::Value => "me/settings" ] );
 
     pub fn activities(&'a self) -> ActivitiesRequest<'a, Client> {
@@ -325,13 +513,18 @@ where
     pub fn education(&self) -> EducationMeRequest<'a, Client> {
         EducationMeRequest::new(&self.client)
     }
+
+    pub fn todo(&'a self) -> TodoRequest<'a, Client> {
+        self.set_path();
+        TodoRequest::new(self.client)
+    }
 }
 
 impl<'a, Client> IdentDrives<'a, Client>
 where
     Client: crate::http::RequestClient,
 {
-    get!( get, serde_json::Value => "drive/{{RID}}" );
+    get!( get, Drive => "drive/{{RID}}" );
 }
 
 impl<'a, Client> IdentSites<'a, Client>
@@ -412,20 +605,20 @@ impl<'a, Client> IdentGroups<'a, Client>
 where
     Client: crate::http::RequestClient,
 {
-    get!( list, Collection<serde_json::Value> => "groups" );
-    get!( get, serde_json::Value => "groups/{{RID}}" );
-    get!( delta, DeltaRequest<Collection<serde_json::Value>> => "groups/delta" );
-    get!( list_events, Collection<serde_json::Value> => "groups/{{RID}}/events" );
+    get!( list, Collection<Group> => "groups" );
+    get!( get, Group => "groups/{{RID}}" );
+    get!( delta, DeltaRequest<Collection<Group>> => "groups/delta" );
+    get!( list_events, Collection<Event> => "groups/{{RID}}/events" );
     get!( list_lifecycle_policies, Collection<serde_json::Value> => "groups/{{RID}}/groupLifecyclePolicies" );
-    get!( list_member_of, Collection<serde_json::Value> => "groups/{{RID}}/memberOf" );
-    get!( list_transitive_member_of, Collection<serde_json::Value> => "groups/{{RID}}/transitiveMemberOf" );
-    get!( list_members, Collection<serde_json::Value> => "groups/{{RID}}/members"  );
-    get!( list_transitive_members, Collection<serde_json::Value> => "groups/{{RID}}/transitiveMembers" );
-    get!( list_owners, Collection<serde_json::Value> => "groups/{{RID}}/owners" );
+    get!( list_member_of, Collection<DirectoryObject> => "groups/{{RID}}/memberOf" );
+    get!( list_transitive_member_of, Collection<DirectoryObject> => "groups/{{RID}}/transitiveMemberOf" );
+    get!( list_members, Collection<DirectoryObject> => "groups/{{RID}}/members"  );
+    get!( list_transitive_members, Collection<DirectoryObject> => "groups/{{RID}}/transitiveMembers" );
+    get!( list_owners, Collection<DirectoryObject> => "groups/{{RID}}/owners" );
     get!( list_photos, Collection<serde_json::Value> => "groups/{{RID}}/photos" );
     get!( root_site, Collection<serde_json::Value> => "groups/{{RID}}/sites/root" );
-    get!( list_planner_plans, Collection<serde_json::Value> => "groups/{{RID}}/planner/plans" );
-    post!( [ create, serde_json::Value => "groups" ] );
+    get!( list_planner_plans, Collection<PlannerPlan> => "groups/{{RID}}/planner/plans" );
+    post!( [ create, Group => "groups" ] );
     post!( add_favorite, GraphResponse<Content> => "groups/{{RID}}/addFavorite" );
     post!( [ add_member, GraphResponse<Content> => "groups/{{RID}}/members/$ref" ] );
     post!( [ add_owner, GraphResponse<Content> => "groups/{{RID}}/owners/$ref" ] );
@@ -443,6 +636,24 @@ where
     delete!( | remove_member, GraphResponse<Content> => "groups/{{RID}}/members/{{id}}/$ref" );
     delete!( | remove_owner, GraphResponse<Content> => "groups/{{RID}}/owners/{{id}}/$ref" );
 
+    /// Add a member to the group using the id of the directory object.
+    pub fn add_member_by_id<S: AsRef<str>>(
+        &'a self,
+        id: S,
+    ) -> IntoResponse<'a, GraphResponse<Content>, Client> {
+        let reference = DirectoryObjectReference::new(self.client.base_url().as_str(), id.as_ref());
+        self.add_member(&reference)
+    }
+
+    /// Add an owner to the group using the id of the user.
+    pub fn add_owner_by_id<S: AsRef<str>>(
+        &'a self,
+        id: S,
+    ) -> IntoResponse<'a, GraphResponse<Content>, Client> {
+        let reference = DirectoryObjectReference::new(self.client.base_url().as_str(), id.as_ref());
+        self.add_owner(&reference)
+    }
+
     pub fn conversations(&self) -> GroupConversationRequest<'a, Client> {
         GroupConversationRequest::new(self.client)
     }
@@ -460,14 +671,23 @@ impl<'a, Client> IdentUsers<'a, Client>
 where
     Client: crate::http::RequestClient,
 {
-    get!( get, serde_json::Value => "users/{{RID}}" );
+    get!( get, User => "users/{{RID}}" );
     get!( settings, serde_json::Value => "users/{{RID}}/settings" );
-    get!( list, Collection<serde_json::Value> => "users" );
-    get!( list_events, Collection<serde_json::Value> => "users/{{RID}}/events" );
-    get!( delta, DeltaRequest<Collection<serde_json::Value>> => "users" );
+    get!( list, Collection<User> => "users" );
+    get!( list_events, Collection<Event> => "users/{{RID}}/events" );
+    get!( delta, DeltaRequest<Collection<User>> => "users" );
     get!( | list_joined_group_photos, Collection<serde_json::Value> => "users/{{RID}}/joinedGroups/{{id}}/photos" );
-    get!( list_planner_tasks, Collection<serde_json::Value> => "users/{{RID}}/planner/tasks");
-    post!( [ create, serde_json::Value => "users" ] );
+    get!( list_planner_tasks, Collection<PlannerTask> => "users/{{RID}}/planner/tasks");
+    get!( list_member_of, Collection<DirectoryObject> => "users/{{RID}}/memberOf" );
+    get!( list_transitive_member_of, Collection<DirectoryObject> => "users/{{RID}}/transitiveMemberOf" );
+    get!( list_owned_objects, Collection<DirectoryObject> => "users/{{RID}}/ownedObjects" );
+    post!( [ check_member_groups, Collection<String> => "users/{{RID}}/checkMemberGroups" ] );
+    post!( [ check_member_objects, Collection<String> => "users/{{RID}}/checkMemberObjects" ] );
+    post!( [ member_groups, Collection<String> => "users/{{RID}}/getMemberGroups" ] );
+    post!( [ member_objects, Collection<String> => "users/{{RID}}/getMemberObjects" ] );
+    get!( list_joined_teams, Collection<Team> => "users/{{RID}}/joinedTeams" );
+    get!( list_chats, Collection<Chat> => "users/{{RID}}/chats" );
+    post!( [ create, User => "users" ] );
     patch!( [ update, GraphResponse<Content> => "users/{{RID}}" ] );
     patch!( [ update_settings, serde_json::Value => "users/{{RID}}/settings" ] );
     delete!( delete, GraphResponse<Content> => "users/{{RID}}" );
@@ -480,6 +700,11 @@ where
     pub fn education(&self) -> EducationUsersRequest<'a, Client> {
         EducationUsersRequest::new(self.client)
     }
+
+    pub fn todo(&'a self) -> TodoRequest<'a, Client> {
+        self.set_path();
+        TodoRequest::new(self.client)
+    }
 }
 
 register_ident_client!(
@@ -1,5 +1,6 @@
 use crate::client::Graph;
 use crate::http::{GraphResponse, IntoResponse};
+use crate::models::{Contact, ContactFolder};
 use crate::types::{collection::Collection, content::Content, delta::DeltaRequest};
 use handlebars::*;
 use reqwest::Method;
@@ -14,11 +15,11 @@ impl<'a, Client> ContactsRequest<'a, Client>
 where
     Client: crate::http::RequestClient,
 {
-    get!( delta, DeltaRequest<Collection<serde_json::Value>> => "{{ct}}/delta" );
-    get!( list, Collection<serde_json::Value> => "{{ct}}" );
-    get!( | get, serde_json::Value => "{{ct}}/{{id}}" );
-    post!( [ create, serde_json::Value => "{{ct}}" ] );
-    patch!( [ | update, serde_json::Value => "{{ct}}/{{id}}" ] );
+    get!( delta, DeltaRequest<Collection<Contact>> => "{{ct}}/delta" );
+    get!( list, Collection<Contact> => "{{ct}}" );
+    get!( | get, Contact => "{{ct}}/{{id}}" );
+    post!( [ create, Contact => "{{ct}}" ] );
+    patch!( [ | update, Contact => "{{ct}}/{{id}}" ] );
     delete!( | delete, GraphResponse<Content> => "{{ct}}/{{id}}" );
 
     pub fn contacts_folder(&'a self) -> ContactsFolderRequest<'a, Client> {
@@ -32,11 +33,11 @@ impl<'a, Client> ContactsFolderRequest<'a, Client>
 where
     Client: crate::http::RequestClient,
 {
-    get!( delta, DeltaRequest<Collection<serde_json::Value>> => "{{cf}}/delta" );
-    get!( | get, serde_json::Value => "{{cf}}/{{id}}" );
-    get!( | list_child_folders, Collection<serde_json::Value> => "{{cf}}/{{id}}/childFolders" );
-    post!( [ | create_child_folder, serde_json::Value => "{{cf}}/{{id}}/childFolders" ] );
-    patch!( [ | update, serde_json::Value => "{{cf}}/{{id}}" ] );
+    get!( delta, DeltaRequest<Collection<ContactFolder>> => "{{cf}}/delta" );
+    get!( | get, ContactFolder => "{{cf}}/{{id}}" );
+    get!( | list_child_folders, Collection<ContactFolder> => "{{cf}}/{{id}}/childFolders" );
+    post!( [ | create_child_folder, ContactFolder => "{{cf}}/{{id}}/childFolders" ] );
+    patch!( [ | update, ContactFolder => "{{cf}}/{{id}}" ] );
     delete!( | delete, GraphResponse<Content> => "{{cf}}/{{id}}" );
 
     pub fn contacts(&'a self) -> ContactsFolderContactsRequest<'a, Client> {
@@ -50,8 +51,8 @@ impl<'a, Client> ContactsFolderContactsRequest<'a, Client>
 where
     Client: crate::http::RequestClient,
 {
-    get!( | delta, DeltaRequest<Collection<serde_json::Value>> => "{{cf}}/{{id}}/{{ct}}/delta" );
-    get!( | list, Collection<serde_json::Value> => "{{cf}}/{{id}}/{{ct}}" );
-    post!( [ | create, serde_json::Value => "{{cf}}/{{id}}" ] );
+    get!( | delta, DeltaRequest<Collection<Contact>> => "{{cf}}/{{id}}/{{ct}}/delta" );
+    get!( | list, Collection<Contact> => "{{cf}}/{{id}}/{{ct}}" );
+    post!( [ | create, Contact => "{{cf}}/{{id}}" ] );
     delete!( || delete, GraphResponse<Content> => "{{cf}}/{{id}}/{{ct}}/{{id2}}" );
 }
@@ -0,0 +1,69 @@
+use crate::client::Graph;
+use crate::http::{GraphResponse, IntoResponse};
+use crate::models::{DirectoryObject, DirectoryObjectReference, DirectoryRole};
+use crate::types::{collection::Collection, content::Content};
+use reqwest::Method;
+
+register_client!(DirectoryRolesRequest,);
+register_client!(DirectoryObjectsRequest,);
+
+impl<'a, Client> DirectoryRolesRequest<'a, Client>
+where
+    Client: crate::http::RequestClient,
+{
+    get!( list_roles, Collection<DirectoryRole> => "directoryRoles" );
+    get!( | get_role, DirectoryRole => "directoryRoles/{{id}}" );
+    post!( [ activate_role, DirectoryRole => "directoryRoles" ] );
+    get!( list_role_templates, Collection<serde_json::Value> => "directoryRoleTemplates" );
+    get!( | get_role_template, serde_json::Value => "directoryRoleTemplates/{{id}}" );
+    get!( | list_members, Collection<DirectoryObject> => "directoryRoles/{{id}}/members" );
+    post!( [| add_member, GraphResponse<Content> => "directoryRoles/{{id}}/members/$ref" ] );
+    delete!( || remove_member, GraphResponse<Content> => "directoryRoles/{{id}}/members/{{id2}}/$ref" );
+
+    /// Add a user or service principal to a directory role using
+    /// the id of the directory object.
+    pub fn add_member_by_id<S: AsRef<str>>(
+        &'a self,
+        id: S,
+        member_id: S,
+    ) -> IntoResponse<'a, GraphResponse<Content>, Client> {
+        let reference =
+            DirectoryObjectReference::new(self.client.base_url().as_str(), member_id.as_ref());
+        self.add_member(id, &reference)
+    }
+}
+
+impl<'a, Client> DirectoryObjectsRequest<'a, Client>
+where
+    Client: crate::http::RequestClient,
+{
+    get!( | get_object, DirectoryObject => "directoryObjects/{{id}}" );
+    delete!( | delete_object, GraphResponse<Content> => "directoryObjects/{{id}}" );
+    post!( [| check_member_groups, Collection<String> => "directoryObjects/{{id}}/checkMemberGroups" ] );
+    post!( [| member_groups, Collection<String> => "directoryObjects/{{id}}/getMemberGroups" ] );
+    post!( [| member_objects, Collection<String> => "directoryObjects/{{id}}/getMemberObjects" ] );
+    post!( [ validate_properties, GraphResponse<Content> => "directoryObjects/validateProperties" ] );
+
+    /// Get the directory objects with the given ids. The types limit the
+    /// objects returned to resource types such as user, group or device.
+    /// All types are searched when types is empty.
+    pub fn get_by_ids<S: AsRef<str>>(
+        &'a self,
+        ids: &[S],
+        types: &[S],
+    ) -> IntoResponse<'a, Collection<DirectoryObject>, Client> {
+        let ids: Vec<&str> = ids.iter().map(|id| id.as_ref()).collect();
+        let types: Vec<&str> = types.iter().map(|t| t.as_ref()).collect();
+        let body = if types.is_empty() {
+            serde_json::json!({ "ids": ids })
+        } else {
+            serde_json::json!({ "ids": ids, "types": types })
+        };
+
+        let client = self.client.request();
+        client.set_method(Method::POST);
+        client.set_body(body.to_string());
+        render_path!(self.client, "directoryObjects/getByIds");
+        IntoResponse::new(self.client)
+    }
+}
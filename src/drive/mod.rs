@@ -8,6 +8,31 @@
 //!     .root_children()
 //!     .send();
 //! ```
+//!
+//! Items can be addressed by id or by a path from the root of the drive
+//! such as `:/Documents/file.txt:` or `/Documents/file.txt`. Paths should
+//! not be percent encoded.
+//!
+//! # Example
+//! ```rust,ignore
+//! # use graph_rs::prelude::*;
+//! # let client = Graph::new("");
+//! let result = client.v1()
+//!     .me()
+//!     .drive()
+//!     .list_children("/Documents/Annual Reports")
+//!     .send();
+//!
+//! // Search a folder and request the next pages of the results.
+//! for item in client.v1()
+//!     .me()
+//!     .drive()
+//!     .search_folder("/Documents", "budget")
+//!     .paged()
+//! {
+//!     println!("{:#?}", item?.name());
+//! }
+//! ```
 
 mod request;
 
@@ -1,9 +1,14 @@
 use crate::client::*;
 use crate::http::{
     AsyncDownload, AsyncHttpClient, BlockingDownload, BlockingHttpClient, GraphRequestType,
-    GraphResponse, IntoResponse, RequestAttribute, RequestClient, UploadSessionClient,
+    GraphResponse, IntoResponse, RequestAttribute, RequestClient, UploadContent,
+    UploadSessionClient,
+};
+use crate::models::{
+    Drive, DriveItem, DriveItemVersion, Permission, SharedDriveItem, Thumbnail, ThumbnailSet,
 };
 use crate::types::collection::Collection;
+use crate::types::upload::UploadRequest;
 use crate::types::{content::Content, delta::DeltaRequest};
 use graph_error::{GraphFailure, GraphRsError};
 use handlebars::*;
@@ -12,26 +17,58 @@ use reqwest::Method;
 use serde_json::json;
 use std::path::Path;
 
-fn template(s: &str, last: &str) -> String {
-    if s.starts_with(':') {
-        vec!["{{drive_root_path}}{{id}}/", last].join("")
-    } else {
-        vec!["{{drive_item}}/{{id}}/", last].join("")
+// Items are addressed by id or by a path from the root of the drive
+// such as :/Documents/file.txt: or /Documents/file.txt.
+fn is_path(id: &str) -> bool {
+    id.starts_with(':') || id.starts_with('/')
+}
+
+// The path of an item relative to the drive. Paths are added to the url
+// as root:/Documents/file.txt: where each segment is percent encoded when
+// it is added to the url, so paths should not be encoded by the caller.
+fn item_path(id: &str) -> (&'static str, Vec<String>) {
+    if !is_path(id) {
+        return ("{{drive_item}}", vec![id.to_string()]);
     }
+
+    let path = id.trim_matches(':').trim_matches('/');
+    if path.is_empty() {
+        return ("{{drive_root_path}}", Vec::new());
+    }
+    ("{{drive_root_path}}:", colon_path(path))
 }
 
-fn encode(s: &str) -> String {
-    if s.starts_with(':') {
-        url::percent_encoding::percent_encode(
-            s.as_bytes(),
-            url::percent_encoding::DEFAULT_ENCODE_SET,
-        )
-        .collect::<String>()
+// The path of an item named file_name in the folder with the id.
+fn child_path(id: &str, file_name: &str) -> (&'static str, Vec<String>) {
+    if is_path(id) {
+        let parent = id.trim_matches(':').trim_matches('/');
+        item_path(&format!("/{}/{}", parent, file_name))
     } else {
-        s.to_string()
+        let mut segments = vec![format!("{}:", id)];
+        segments.extend(colon_path(file_name));
+        ("{{drive_item}}", segments)
     }
 }
 
+// The search function of a drive or folder. Single quotes in the
+// query are escaped by doubling them.
+fn search_function(q: &str) -> String {
+    format!("search(q='{}')", q.replace('\'', "''"))
+}
+
+// The segments of a path where the last segment ends with a colon.
+fn colon_path(path: &str) -> Vec<String> {
+    let mut segments: Vec<String> = path
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_string())
+        .collect();
+    if let Some(last) = segments.last_mut() {
+        last.push(':');
+    }
+    segments
+}
+
 register_client!(
     DriveRequest,
     drive_item => "drive/items", "items", Ident::Drives,
@@ -43,59 +80,99 @@ impl<'a, Client> DriveRequest<'a, Client>
 where
     Client: crate::http::RequestClient,
 {
-    get!( drive, serde_json::Value => "{{drive_root}}" );
-    get!( root, serde_json::Value => "{{drive_root}}/root" );
-    get!( recent, Collection<serde_json::Value> => "{{drive_root}}/recent" );
-    get!( delta, DeltaRequest<Collection<serde_json::Value>> => "{{drive_root}}/root/delta" );
-    get!( root_children, Collection<serde_json::Value> => "{{drive_root}}/root/children" );
+    get!( drive, Drive => "{{drive_root}}" );
+    get!( root, DriveItem => "{{drive_root}}/root" );
+    get!( recent, Collection<DriveItem> => "{{drive_root}}/recent" );
+    get!( delta, DeltaRequest<Collection<DriveItem>> => "{{drive_root}}/root/delta" );
+    get!( root_children, Collection<DriveItem> => "{{drive_root}}/root/children" );
     get!( drive_activity, Collection<serde_json::Value> => "{{drive_root}}/activities" );
-    get!( thumbnails, Collection<serde_json::Value> => "{{drive_item}}/thumbnails" );
-    get!( shared_with_me, Collection<serde_json::Value> => "{{drive_root}}/sharedWithMe" );
-    get!( special_documents, serde_json::Value => "{{drive_root}}/special/documents" );
-    get!( special_documents_children, Collection<serde_json::Value> => "{{drive_root}}/special/documents/children" );
-    get!( special_photos, serde_json::Value => "{{drive_root}}/special/photos" );
-    get!( special_photos_children, Collection<serde_json::Value> => "{{drive_root}}/special/photos/children" );
-    get!( special_camera_roll, serde_json::Value => "{{drive_root}}/special/cameraroll" );
-    get!( special_camera_roll_children, Collection<serde_json::Value> => "{{drive_root}}/special/cameraroll/children" );
-    get!( special_app_root, serde_json::Value => "{{drive_root}}/special/approot" );
-    get!( special_app_root_children, Collection<serde_json::Value> => "{{drive_root}}/special/approot/children" );
-    get!( special_music, serde_json::Value => "{{drive_root}}/special/music" );
-    get!( special_music_children, Collection<serde_json::Value> => "{{drive_root}}/special/music/children" );
-    get!( | special_folder, serde_json::Value => "{{drive_root}}/special/{{id}}" );
+    get!( thumbnails, Collection<ThumbnailSet> => "{{drive_item}}/thumbnails" );
+    get!( shared_with_me, Collection<DriveItem> => "{{drive_root}}/sharedWithMe" );
+    get!( special_documents, DriveItem => "{{drive_root}}/special/documents" );
+    get!( special_documents_children, Collection<DriveItem> => "{{drive_root}}/special/documents/children" );
+    get!( special_photos, DriveItem => "{{drive_root}}/special/photos" );
+    get!( special_photos_children, Collection<DriveItem> => "{{drive_root}}/special/photos/children" );
+    get!( special_camera_roll, DriveItem => "{{drive_root}}/special/cameraroll" );
+    get!( special_camera_roll_children, Collection<DriveItem> => "{{drive_root}}/special/cameraroll/children" );
+    get!( special_app_root, DriveItem => "{{drive_root}}/special/approot" );
+    get!( special_app_root_children, Collection<DriveItem> => "{{drive_root}}/special/approot/children" );
+    get!( special_music, DriveItem => "{{drive_root}}/special/music" );
+    get!( special_music_children, Collection<DriveItem> => "{{drive_root}}/special/music/children" );
+    get!( | special_folder, DriveItem => "{{drive_root}}/special/{{id}}" );
 
-    pub fn list_children<S: AsRef<str>>(
-        &'a self,
-        id: S,
-    ) -> IntoResponse<'a, Collection<serde_json::Value>, Client> {
+    // Adds the path of the item with the id and the last segments to the url.
+    fn render_item_path(&self, id: &str, last: &[&str]) {
+        let (template, mut segments) = item_path(id);
+        segments.extend(last.iter().map(|s| s.to_string()));
+        render_path!(
+            self.client,
+            template,
+            &json!({}),
+            segments.iter().map(|s| s.as_str())
+        );
+    }
+
+    // Adds the path of the item named file_name in the folder with
+    // the id and the last segments to the url.
+    fn render_child_path(&self, id: &str, file_name: &str, last: &[&str]) {
+        let (template, mut segments) = child_path(id, file_name);
+        segments.extend(last.iter().map(|s| s.to_string()));
+        render_path!(
+            self.client,
+            template,
+            &json!({}),
+            segments.iter().map(|s| s.as_str())
+        );
+    }
+
+    /// Search the drive for items that match the query. The query is
+    /// matched against the file name, metadata and content of items.
+    ///
+    /// The response is a collection that can be paged using `paged()`.
+    pub fn search(&'a self, q: &str) -> IntoResponse<'a, Collection<DriveItem>, Client> {
+        let search = search_function(q);
         self.client.request().set_method(Method::GET);
         render_path!(
             self.client,
-            &template(id.as_ref(), "children"),
-            &json!({ "id": encode(id.as_ref()) })
+            "{{drive_root}}/root",
+            &json!({}),
+            vec![search.as_str()]
         );
         IntoResponse::new(self.client)
     }
 
+    /// Search the folder with the id or path for items that match the query.
+    pub fn search_folder<S: AsRef<str>>(
+        &'a self,
+        id: S,
+        q: &str,
+    ) -> IntoResponse<'a, Collection<DriveItem>, Client> {
+        self.client.request().set_method(Method::GET);
+        self.render_item_path(id.as_ref(), &[search_function(q).as_str()]);
+        IntoResponse::new(self.client)
+    }
+
+    pub fn list_children<S: AsRef<str>>(
+        &'a self,
+        id: S,
+    ) -> IntoResponse<'a, Collection<DriveItem>, Client> {
+        self.client.request().set_method(Method::GET);
+        self.render_item_path(id.as_ref(), &["children"]);
+        IntoResponse::new(self.client)
+    }
+
     pub fn item_activity<S: AsRef<str>>(
         &'a self,
         id: S,
     ) -> IntoResponse<'a, Collection<serde_json::Value>, Client> {
         self.client.request().set_method(Method::GET);
-        render_path!(
-            self.client,
-            &template(id.as_ref(), "activities"),
-            &json!({ "id": encode(id.as_ref()) })
-        );
+        self.render_item_path(id.as_ref(), &["activities"]);
         IntoResponse::new(self.client)
     }
 
-    pub fn get_item<S: AsRef<str>>(&'a self, id: S) -> IntoResponse<'a, serde_json::Value, Client> {
+    pub fn get_item<S: AsRef<str>>(&'a self, id: S) -> IntoResponse<'a, DriveItem, Client> {
         self.client.request().set_method(Method::GET);
-        render_path!(
-            self.client,
-            template(id.as_ref(), "").as_str(),
-            &json!({ "id": encode(id.as_ref()) })
-        );
+        self.render_item_path(id.as_ref(), &[]);
         IntoResponse::new(self.client)
     }
 
@@ -103,7 +180,7 @@ where
         &'a self,
         id: S,
         body: &B,
-    ) -> IntoResponse<'a, serde_json::Value, Client> {
+    ) -> IntoResponse<'a, DriveItem, Client> {
         let body = serde_json::to_string(body);
         if let Ok(body) = body {
             let client = self.client.request();
@@ -112,11 +189,7 @@ where
         } else if let Err(e) = body {
             return IntoResponse::new_error(self.client, GraphFailure::from(e));
         }
-        render_path!(
-            self.client,
-            template(id.as_ref(), "").as_str(),
-            &json!({"id": encode(id.as_ref()) })
-        );
+        self.render_item_path(id.as_ref(), &[]);
         IntoResponse::new(self.client)
     }
 
@@ -125,11 +198,7 @@ where
         id: S,
     ) -> IntoResponse<'a, GraphResponse<Content>, Client> {
         self.client.request().set_method(Method::DELETE);
-        render_path!(
-            self.client,
-            template(id.as_ref(), "").as_str(),
-            &json!({"id": encode(id.as_ref()) })
-        );
+        self.render_item_path(id.as_ref(), &[]);
         IntoResponse::new(self.client)
     }
 
@@ -137,7 +206,7 @@ where
         &'a self,
         id: S,
         body: &B,
-    ) -> IntoResponse<'a, serde_json::Value, Client> {
+    ) -> IntoResponse<'a, DriveItem, Client> {
         let body = serde_json::to_string(body);
         if let Ok(body) = body {
             let client = self.client.request();
@@ -150,11 +219,7 @@ where
         if id.as_ref().is_empty() {
             render_path!(self.client, "{{drive_root_path}}/children", &json!({}));
         } else {
-            render_path!(
-                self.client,
-                template(id.as_ref(), "children").as_str(),
-                &json!({ "id": encode(id.as_ref()) })
-            );
+            self.render_item_path(id.as_ref(), &["children"]);
         }
         IntoResponse::new(self.client)
     }
@@ -172,24 +237,16 @@ where
         } else if let Err(e) = body {
             return IntoResponse::new_error(self.client, GraphFailure::from(e));
         }
-        render_path!(
-            self.client,
-            template(id.as_ref(), "copy").as_str(),
-            &json!({"id": encode(id.as_ref()) })
-        );
+        self.render_item_path(id.as_ref(), &["copy"]);
         IntoResponse::new(self.client)
     }
 
     pub fn list_versions<S: AsRef<str>>(
         &self,
         id: S,
-    ) -> IntoResponse<'a, Collection<serde_json::Value>, Client> {
+    ) -> IntoResponse<'a, Collection<DriveItemVersion>, Client> {
         self.client.request().set_method(Method::GET);
-        render_path!(
-            self.client,
-            template(id.as_ref(), "versions").as_str(),
-            &json!({ "id": encode(id.as_ref()) })
-        );
+        self.render_item_path(id.as_ref(), &["versions"]);
         IntoResponse::new(self.client)
     }
 
@@ -198,17 +255,9 @@ where
         id: S,
         thumb_id: &str,
         size: &str,
-    ) -> IntoResponse<'a, serde_json::Value, Client> {
+    ) -> IntoResponse<'a, Thumbnail, Client> {
         self.client.request().set_method(Method::GET);
-        render_path!(
-            self.client,
-            template(id.as_ref(), "thumbnails/{{thumb_id}}/{{size}}").as_str(),
-            &json!({
-               "id": encode(id.as_ref()),
-               "thumb_id": thumb_id,
-               "size": size
-            })
-        );
+        self.render_item_path(id.as_ref(), &["thumbnails", thumb_id, size]);
         IntoResponse::new(self.client)
     }
 
@@ -219,15 +268,7 @@ where
         size: &str,
     ) -> IntoResponse<'a, Vec<u8>, Client> {
         self.client.request().set_method(Method::GET);
-        render_path!(
-            self.client,
-            template(id.as_ref(), "thumbnails/{{thumb_id}}/{{size}}/content").as_str(),
-            &json!({
-               "id": encode(id.as_ref()),
-               "thumb_id": thumb_id,
-               "size": size
-            })
-        );
+        self.render_item_path(id.as_ref(), &["thumbnails", thumb_id, size, "content"]);
         IntoResponse::new(self.client)
     }
 
@@ -235,7 +276,7 @@ where
         &'a self,
         id: S,
         file: P,
-    ) -> IntoResponse<'a, serde_json::Value, Client> {
+    ) -> IntoResponse<'a, DriveItem, Client> {
         if let Err(err) = self
             .client
             .request()
@@ -245,11 +286,7 @@ where
         }
 
         self.client.request().set_method(Method::PUT);
-        render_path!(
-            self.client,
-            template(id.as_ref(), "content").as_str(),
-            &json!({"id": encode(id.as_ref()) })
-        );
+        self.render_item_path(id.as_ref(), &["content"]);
         IntoResponse::new(self.client)
     }
 
@@ -257,8 +294,8 @@ where
         &'a self,
         id: S,
         file: P,
-    ) -> IntoResponse<'a, serde_json::Value, Client> {
-        if id.as_ref().starts_with(':') {
+    ) -> IntoResponse<'a, DriveItem, Client> {
+        if is_path(id.as_ref()) {
             if let Err(err) = self
                 .client
                 .request()
@@ -268,11 +305,7 @@ where
             }
 
             self.client.request().set_method(Method::PUT);
-            render_path!(
-                self.client,
-                template(id.as_ref(), "content").as_str(),
-                &json!({"id": encode(id.as_ref()) })
-            );
+            self.render_item_path(id.as_ref(), &["content"]);
         } else {
             let name = file.as_ref().file_name();
             if name.is_none() {
@@ -285,14 +318,7 @@ where
                     GraphFailure::internal(GraphRsError::FileNameInvalidUTF8),
                 );
             }
-            render_path!(
-                self.client,
-                "{{drive_item}}/{{id}}:/{{file_name}}:/content",
-                &json!({
-                    "id": id.as_ref(),
-                    "file_name": name.unwrap(),
-                })
-            );
+            self.render_child_path(id.as_ref(), name.unwrap(), &["content"]);
 
             if let Err(e) = self
                 .client
@@ -306,19 +332,47 @@ where
         IntoResponse::new(self.client)
     }
 
+    /// Upload the content, such as bytes in memory or a reader, to the
+    /// item. Content smaller than 4 MiB is uploaded using a single PUT
+    /// request and larger content is uploaded using an upload session.
+    pub fn upload_replace_content<S: AsRef<str>, C: Into<UploadContent>>(
+        &'a self,
+        id: S,
+        content: C,
+    ) -> IntoResponse<'a, UploadRequest<DriveItem>, Client> {
+        self.client
+            .request()
+            .set_upload_session_content(content.into());
+        self.render_item_path(id.as_ref(), &[]);
+        IntoResponse::new(self.client)
+    }
+
+    /// Upload the content, such as bytes in memory or a reader, to a new
+    /// file named file_name in the folder with the id. Content smaller than
+    /// 4 MiB is uploaded using a single PUT request and larger content is
+    /// uploaded using an upload session.
+    pub fn upload_new_content<S: AsRef<str>, C: Into<UploadContent>>(
+        &'a self,
+        id: S,
+        file_name: &str,
+        content: C,
+    ) -> IntoResponse<'a, UploadRequest<DriveItem>, Client> {
+        self.client
+            .request()
+            .set_upload_session_content(content.into());
+        self.render_child_path(id.as_ref(), file_name, &[]);
+        IntoResponse::new(self.client)
+    }
+
     pub fn restore_version<S: AsRef<str>>(
         &'a self,
         id: S,
         version_id: S,
     ) -> IntoResponse<'a, GraphResponse<Content>, Client> {
         self.client.request().set_method(Method::POST);
-        render_path!(
-            self.client,
-            template(id.as_ref(), "versions/{{version_id}}/restoreVersion").as_str(),
-            &json!({
-                "id": encode(id.as_ref()),
-                "version_id": version_id.as_ref(),
-            })
+        self.render_item_path(
+            id.as_ref(),
+            &["versions", version_id.as_ref(), "restoreVersion"],
         );
         IntoResponse::new(self.client)
     }
@@ -338,11 +392,28 @@ where
         } else if let Err(e) = body {
             return IntoResponse::new_error(self.client, GraphFailure::from(e));
         }
-        render_path!(
-            self.client,
-            template(id.as_ref(), "createUploadSession").as_str(),
-            &json!({ "id": encode(id.as_ref()) })
-        );
+        self.render_item_path(id.as_ref(), &["createUploadSession"]);
+        IntoResponse::new(self.client)
+    }
+
+    /// Create an upload session that uploads the content, such as bytes
+    /// in memory or a reader, instead of a file.
+    pub fn upload_session_content<S: AsRef<str>, C: Into<UploadContent>, B: serde::Serialize>(
+        &'a self,
+        id: S,
+        content: C,
+        body: &B,
+    ) -> IntoResponse<'a, UploadSessionClient<Client>, Client> {
+        let body = serde_json::to_string(body);
+        if let Ok(body) = body {
+            let client = self.client.request();
+            client.set_method(Method::POST);
+            client.set_upload_session_content(content.into());
+            client.set_body(body);
+        } else if let Err(e) = body {
+            return IntoResponse::new_error(self.client, GraphFailure::from(e));
+        }
+        self.render_item_path(id.as_ref(), &["createUploadSession"]);
         IntoResponse::new(self.client)
     }
 
@@ -365,11 +436,7 @@ where
             client.set_method(Method::POST);
             client.header(CONTENT_LENGTH, HeaderValue::from(0));
         }
-        render_path!(
-            self.client,
-            template(id.as_ref(), "preview").as_str(),
-            &json!({ "id": encode(id.as_ref()) })
-        );
+        self.render_item_path(id.as_ref(), &["preview"]);
         IntoResponse::new(self.client)
     }
 
@@ -377,11 +444,7 @@ where
         &'a self,
         id: S,
     ) -> IntoResponse<'a, GraphResponse<Content>, Client> {
-        render_path!(
-            self.client,
-            template(id.as_ref(), "content").as_str(),
-            &json!({ "id": encode(id.as_ref()) })
-        );
+        self.render_item_path(id.as_ref(), &["content"]);
         self.client.request().set_method(Method::GET);
         IntoResponse::new(self.client)
     }
@@ -390,11 +453,7 @@ where
         &'a self,
         id: S,
     ) -> IntoResponse<'a, GraphResponse<Content>, Client> {
-        render_path!(
-            self.client,
-            template(id.as_ref(), "checkout").as_str(),
-            &json!({ "id": encode(id.as_ref()) })
-        );
+        self.render_item_path(id.as_ref(), &["checkout"]);
         let client = self.client.request();
         client.set_method(Method::POST);
         client.header(CONTENT_LENGTH, HeaderValue::from(0));
@@ -406,11 +465,7 @@ where
         id: S,
         body: &B,
     ) -> IntoResponse<'a, GraphResponse<Content>, Client> {
-        render_path!(
-            self.client,
-            template(id.as_ref(), "checkin").as_str(),
-            &json!({ "id": encode(id.as_ref()) })
-        );
+        self.render_item_path(id.as_ref(), &["checkin"]);
 
         let body = serde_json::to_string(body);
         if let Ok(body) = body {
@@ -427,7 +482,7 @@ where
         &'a self,
         id: S,
         body: &B,
-    ) -> IntoResponse<'a, serde_json::Value, Client> {
+    ) -> IntoResponse<'a, DriveItem, Client> {
         let body = serde_json::to_string(body);
         if let Ok(body) = body {
             let client = self.client.request();
@@ -436,11 +491,94 @@ where
         } else if let Err(e) = body {
             return IntoResponse::new_error(self.client, GraphFailure::from(e));
         }
-        render_path!(
-            self.client,
-            template(id.as_ref(), "").as_str(),
-            &json!({ "id": id.as_ref() })
-        );
+        self.render_item_path(id.as_ref(), &[]);
+        IntoResponse::new(self.client)
+    }
+
+    /// Create a sharing link for the item. The body sets the type of the
+    /// link such as view or edit and the scope such as anonymous or
+    /// organization.
+    pub fn create_link<S: AsRef<str>, B: serde::Serialize>(
+        &'a self,
+        id: S,
+        body: &B,
+    ) -> IntoResponse<'a, Permission, Client> {
+        let body = serde_json::to_string(body);
+        if let Ok(body) = body {
+            let client = self.client.request();
+            client.set_method(Method::POST);
+            client.set_body(body);
+        } else if let Err(e) = body {
+            return IntoResponse::new_error(self.client, GraphFailure::from(e));
+        }
+        self.render_item_path(id.as_ref(), &["createLink"]);
+        IntoResponse::new(self.client)
+    }
+
+    /// Send a sharing invitation for the item to the recipients
+    /// in the body.
+    pub fn invite<S: AsRef<str>, B: serde::Serialize>(
+        &'a self,
+        id: S,
+        body: &B,
+    ) -> IntoResponse<'a, Collection<Permission>, Client> {
+        let body = serde_json::to_string(body);
+        if let Ok(body) = body {
+            let client = self.client.request();
+            client.set_method(Method::POST);
+            client.set_body(body);
+        } else if let Err(e) = body {
+            return IntoResponse::new_error(self.client, GraphFailure::from(e));
+        }
+        self.render_item_path(id.as_ref(), &["invite"]);
+        IntoResponse::new(self.client)
+    }
+
+    pub fn list_permissions<S: AsRef<str>>(
+        &'a self,
+        id: S,
+    ) -> IntoResponse<'a, Collection<Permission>, Client> {
+        self.client.request().set_method(Method::GET);
+        self.render_item_path(id.as_ref(), &["permissions"]);
+        IntoResponse::new(self.client)
+    }
+
+    pub fn get_permission<S: AsRef<str>>(
+        &'a self,
+        id: S,
+        permission_id: S,
+    ) -> IntoResponse<'a, Permission, Client> {
+        self.client.request().set_method(Method::GET);
+        self.render_item_path(id.as_ref(), &["permissions", permission_id.as_ref()]);
+        IntoResponse::new(self.client)
+    }
+
+    /// Update the roles of a permission.
+    pub fn update_permission<S: AsRef<str>, B: serde::Serialize>(
+        &'a self,
+        id: S,
+        permission_id: S,
+        body: &B,
+    ) -> IntoResponse<'a, Permission, Client> {
+        let body = serde_json::to_string(body);
+        if let Ok(body) = body {
+            let client = self.client.request();
+            client.set_method(Method::PATCH);
+            client.set_body(body);
+        } else if let Err(e) = body {
+            return IntoResponse::new_error(self.client, GraphFailure::from(e));
+        }
+        self.render_item_path(id.as_ref(), &["permissions", permission_id.as_ref()]);
+        IntoResponse::new(self.client)
+    }
+
+    pub fn delete_permission<S: AsRef<str>>(
+        &'a self,
+        id: S,
+        permission_id: S,
+    ) -> IntoResponse<'a, GraphResponse<Content>, Client> {
+        self.client.request().set_method(Method::DELETE);
+        self.render_item_path(id.as_ref(), &["permissions", permission_id.as_ref()]);
         IntoResponse::new(self.client)
     }
 
@@ -457,41 +595,62 @@ where
                 "getActivitiesByInterval(startDateTime='{}',endDateTime='{}',interval='{}')",
                 start, end, interval
             );
-            render_path!(
-                self.client,
-                &template(id.as_ref(), &interval),
-                &serde_json::json!({
-                   "id": id.as_ref(),
-                })
-            );
+            self.render_item_path(id.as_ref(), &[interval.as_str()]);
         } else {
             let interval = format!(
                 "getActivitiesByInterval(startDateTime='{}',interval='{}')",
                 start, interval
             );
-            render_path!(
-                self.client,
-                &template(id.as_ref(), &interval),
-                &serde_json::json!({
-                    "id": id.as_ref(),
-                })
-            );
+            self.render_item_path(id.as_ref(), &[interval.as_str()]);
         }
         IntoResponse::new(self.client)
     }
 }
 
+/// Encode a sharing url as a share id that can be used with the
+/// shares endpoint. The url is base64url encoded without padding
+/// and prefixed with u!.
+///
+/// # See
+/// [Encoding sharing URLs](https://docs.microsoft.com/en-us/graph/api/shares-get?view=graph-rest-1.0#encoding-sharing-urls)
+///
+/// # Example
+/// ```
+/// # use graph_rs::drive::encode_sharing_url;
+/// assert_eq!(
+///     encode_sharing_url("https://onedrive.live.com/redir?resid=1231244193912!12&authKey=1201919!12921!1"),
+///     "u!aHR0cHM6Ly9vbmVkcml2ZS5saXZlLmNvbS9yZWRpcj9yZXNpZD0xMjMxMjQ0MTkzOTEyITEyJmF1dGhLZXk9MTIwMTkxOSExMjkyMSEx"
+/// );
+/// ```
+pub fn encode_sharing_url(url: &str) -> String {
+    format!("u!{}", base64::encode_config(url, base64::URL_SAFE_NO_PAD))
+}
+
+register_client!(SharesRequest,);
+
+impl<'a, Client> SharesRequest<'a, Client>
+where
+    Client: crate::http::RequestClient,
+{
+    get!( | get_shared_item, SharedDriveItem => "shares/{{id}}" );
+    get!( | get_drive_item, DriveItem => "shares/{{id}}/driveItem" );
+    get!( | list_children, Collection<DriveItem> => "shares/{{id}}/driveItem/children" );
+    get!( | get_permission, Permission => "shares/{{id}}/permission" );
+
+    /// Get the drive item of a sharing url. The url is encoded
+    /// using `encode_sharing_url`.
+    pub fn get_drive_item_by_url(&'a self, url: &str) -> IntoResponse<'a, DriveItem, Client> {
+        self.get_drive_item(encode_sharing_url(url))
+    }
+}
+
 impl<'a> DriveRequest<'a, BlockingHttpClient> {
     pub fn download<S: AsRef<str>, P: AsRef<Path>>(
         &'a self,
         id: S,
         directory: P,
     ) -> BlockingDownload {
-        render_path!(
-            self.client,
-            template(id.as_ref(), "content").as_str(),
-            &json!({ "id": encode(id.as_ref()) })
-        );
+        self.render_item_path(id.as_ref(), &["content"]);
         self.client
             .request()
             .set_request(vec![
@@ -506,11 +665,7 @@ impl<'a> DriveRequest<'a, BlockingHttpClient> {
 
 impl<'a> DriveRequest<'a, AsyncHttpClient> {
     pub fn download<S: AsRef<str>, P: AsRef<Path>>(&'a self, id: S, directory: P) -> AsyncDownload {
-        render_path!(
-            self.client,
-            template(id.as_ref(), "content").as_str(),
-            &json!({ "id": encode(id.as_ref()) })
-        );
+        self.render_item_path(id.as_ref(), &["content"]);
         self.client
             .request()
             .set_request(vec![
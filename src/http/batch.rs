@@ -0,0 +1,585 @@
+use crate::client::Graph;
+use crate::http::{
+    AsyncHttpClient, BlockingHttpClient, GraphResponse, IntoResponse, RequestClient, RetryPolicy,
+};
+use crate::url::GraphUrl;
+use graph_error::{ErrorMessage, ErrorType, GraphError, GraphFailure, GraphHeaders, GraphResult};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, ACCEPT, CONTENT_TYPE};
+use reqwest::Method;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+use std::thread;
+use std::time::Duration;
+
+/// The max number of requests Graph accepts in a single batch.
+pub const MAX_BATCH_STEPS: usize = 20;
+
+/// The id of a step in a batch request. The type is the response type
+/// of the request builder the step was created from and is used to
+/// deserialize the response of the step.
+pub struct BatchId<T> {
+    id: String,
+    ty: PhantomData<fn() -> T>,
+}
+
+impl<T> BatchId<T> {
+    fn new(id: String) -> BatchId<T> {
+        BatchId {
+            id,
+            ty: PhantomData,
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.id.as_str()
+    }
+}
+
+impl<T> Clone for BatchId<T> {
+    fn clone(&self) -> Self {
+        BatchId::new(self.id.clone())
+    }
+}
+
+impl<T> std::fmt::Debug for BatchId<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("BatchId").field(&self.id).finish()
+    }
+}
+
+impl<T> AsRef<str> for BatchId<T> {
+    fn as_ref(&self) -> &str {
+        self.id.as_str()
+    }
+}
+
+/// A single request in a JSON batch.
+/// [JSON batching](https://docs.microsoft.com/en-us/graph/json-batching)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
+#[serde(rename_all = "camelCase")]
+#[get = "pub"]
+pub struct BatchStep {
+    id: String,
+    method: String,
+    url: String,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    depends_on: Vec<String>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    headers: HashMap<String, String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body: Option<serde_json::Value>,
+}
+
+impl BatchStep {
+    /// Create a step. The url is relative to the version of the
+    /// API such as `/me/drive/root/children`.
+    pub fn new<S: AsRef<str>>(id: S, method: Method, url: S) -> BatchStep {
+        BatchStep {
+            id: id.as_ref().to_string(),
+            method: method.as_str().to_string(),
+            url: url.as_ref().to_string(),
+            depends_on: Vec::new(),
+            headers: HashMap::new(),
+            body: None,
+        }
+    }
+
+    /// The step is only run after the step with the given id. Steps
+    /// that depend on each other are always sent in the same batch.
+    pub fn depends_on<S: AsRef<str>>(&mut self, id: S) -> &mut Self {
+        let id = id.as_ref().to_string();
+        if !self.depends_on.contains(&id) {
+            self.depends_on.push(id);
+        }
+        self
+    }
+
+    pub fn header<S: AsRef<str>>(&mut self, name: S, value: S) -> &mut Self {
+        self.headers
+            .insert(name.as_ref().to_string(), value.as_ref().to_string());
+        self
+    }
+
+    /// Set the body of the step. Steps with a JSON body also need a
+    /// Content-Type header which is set to application/json if missing.
+    pub fn body(&mut self, body: serde_json::Value) -> &mut Self {
+        let has_content_type = self
+            .headers
+            .keys()
+            .any(|name| name.eq_ignore_ascii_case(CONTENT_TYPE.as_str()));
+        if !has_content_type {
+            self.header(CONTENT_TYPE.as_str(), "application/json");
+        }
+        self.body = Some(body);
+        self
+    }
+
+    // Takes the method, url, headers and body that a request builder set
+    // on the client and resets the headers the same way they are reset
+    // after a request is built.
+    pub(crate) fn from_request<Client: RequestClient>(
+        id: &str,
+        client: &Client,
+    ) -> GraphResult<BatchStep> {
+        // The first segment of the path is the version of the API.
+        let url = client.url();
+        let path: Vec<&str> = url
+            .path()
+            .split('/')
+            .filter(|s| !s.is_empty())
+            .skip(1)
+            .collect();
+        let mut relative = format!("/{}", path.join("/"));
+        if let Some(query) = url.query() {
+            relative.push('?');
+            relative.push_str(query);
+        }
+
+        let mut step = BatchStep::new(id, client.method(), relative.as_str());
+        let body = client.take_body();
+        for (name, value) in client.header_map().iter() {
+            if name == CONTENT_TYPE && body.is_none() {
+                continue;
+            }
+            step.header(name.as_str(), value.to_str().map_err(GraphFailure::from)?);
+        }
+
+        // Bodies that are not JSON, such as file content, are
+        // base64 encoded.
+        if let Some(body) = body {
+            step.body = Some(
+                serde_json::from_slice(&body)
+                    .unwrap_or_else(|_| serde_json::Value::String(base64::encode(&body))),
+            );
+        }
+
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        client.set_header_map(headers);
+        Ok(step)
+    }
+}
+
+/// The response of a single step in a batch.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
+#[get = "pub"]
+pub struct BatchStepResponse {
+    id: String,
+    status: u16,
+    #[serde(default)]
+    headers: HashMap<String, String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body: Option<serde_json::Value>,
+}
+
+impl BatchStepResponse {
+    pub fn is_success(&self) -> bool {
+        self.status < 400
+    }
+
+    pub fn header_map(&self) -> HeaderMap {
+        let mut header_map = HeaderMap::new();
+        for (name, value) in self.headers.iter() {
+            if let (Ok(name), Ok(value)) = (
+                HeaderName::from_bytes(name.as_bytes()),
+                HeaderValue::from_str(value),
+            ) {
+                header_map.insert(name, value);
+            }
+        }
+        header_map
+    }
+
+    /// The error of a step that failed. Steps that were not run because
+    /// a step they depend on failed have a 424 status code.
+    pub fn error(&self) -> Option<GraphFailure> {
+        if self.is_success() {
+            return None;
+        }
+
+        let mut error = GraphError::try_from(self.status).unwrap_or_else(|_| {
+            GraphError::new(
+                None,
+                ErrorType::UnknownError.as_str(),
+                ErrorType::UnknownError,
+                self.status,
+                Default::default(),
+            )
+        });
+        error.set_headers(GraphHeaders::from(self.header_map()));
+        if let Some(body) = self.body.as_ref() {
+            if let Ok(message) = serde_json::from_value::<ErrorMessage>(body.clone()) {
+                error.set_error_message(message);
+            }
+        }
+        Some(GraphFailure::GraphError(error))
+    }
+
+    /// Deserialize the body of the step.
+    pub fn json<T>(&self) -> GraphResult<T>
+    where
+        for<'de> T: serde::Deserialize<'de>,
+    {
+        let body = self.body.clone().unwrap_or(serde_json::Value::Null);
+        Ok(serde_json::from_value(body)?)
+    }
+}
+
+/// The responses of all steps in a batch request in the order the
+/// steps were added.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BatchResponse {
+    responses: Vec<BatchStepResponse>,
+}
+
+impl BatchResponse {
+    pub fn responses(&self) -> &[BatchStepResponse] {
+        self.responses.as_slice()
+    }
+
+    pub fn step<S: AsRef<str>>(&self, id: S) -> Option<&BatchStepResponse> {
+        self.responses
+            .iter()
+            .find(|response| response.id.eq(id.as_ref()))
+    }
+
+    /// The response of a step deserialized into the response type of
+    /// the request builder the step was created from. Returns an error
+    /// if the step failed.
+    pub fn get<T>(&self, id: &BatchId<T>) -> Option<GraphResult<GraphResponse<T>>>
+    where
+        for<'de> T: serde::Deserialize<'de>,
+    {
+        let response = self.step(id)?;
+        if let Some(err) = response.error() {
+            return Some(Err(err));
+        }
+        Some(
+            response
+                .json()
+                .map(|body| GraphResponse::new(body, response.status, response.header_map())),
+        )
+    }
+
+    /// Whether all steps in the batch succeeded.
+    pub fn is_success(&self) -> bool {
+        self.responses.iter().all(|response| response.is_success())
+    }
+}
+
+/// Combines requests from the request builders into JSON batches.
+///
+/// Each request builder that is added becomes a step in the batch with
+/// an auto generated id. Steps are sent in batches of at most 20 and steps
+/// that were throttled are sent again using the retry policy of the client.
+///
+/// # Example
+/// ```rust,ignore
+/// # use graph_rs::prelude::*;
+/// # use graph_rs::models::DriveItem;
+/// # let client = Graph::new("ACCESS_TOKEN");
+/// let mut batch = client.v1().batch_request();
+/// let item = batch.add(client.v1().me().drive().get_item("ITEM_ID"));
+/// let children = batch.add(client.v1().me().drive().list_children("ITEM_ID"));
+/// batch.step(&children).unwrap().depends_on(&item);
+///
+/// let response = batch.send()?;
+/// let drive_item: GraphResponse<DriveItem> = response.get(&item).unwrap()?;
+/// println!("{:#?}", drive_item.body().name());
+/// ```
+pub struct BatchRequest<'a, Client>
+where
+    Client: RequestClient,
+{
+    client: &'a Graph<Client>,
+    url: GraphUrl,
+    steps: Vec<BatchStep>,
+    retry_policy: RetryPolicy,
+    error: Option<GraphFailure>,
+}
+
+impl<'a, Client> BatchRequest<'a, Client>
+where
+    Client: RequestClient,
+{
+    pub(crate) fn new(client: &'a Graph<Client>, url: GraphUrl) -> BatchRequest<'a, Client> {
+        BatchRequest {
+            client,
+            url,
+            steps: Vec::new(),
+            retry_policy: client.request().retry_policy(),
+            error: None,
+        }
+    }
+
+    /// Add the request of a request builder as a step. If the request
+    /// builder returned an error the error is returned when the batch
+    /// is sent.
+    pub fn add<'b, T>(&mut self, request: IntoResponse<'b, T, Client>) -> BatchId<T> {
+        let id = self.next_id();
+        match request.into_batch_step(id.as_str()) {
+            Ok(step) => self.steps.push(step),
+            Err(err) => {
+                if self.error.is_none() {
+                    self.error = Some(err);
+                }
+            },
+        }
+        BatchId::new(id)
+    }
+
+    /// Add a step that was created manually. A step with the
+    /// same id replaces the existing step.
+    pub fn add_step(&mut self, step: BatchStep) -> BatchId<serde_json::Value> {
+        let id = step.id.clone();
+        self.steps.retain(|s| s.id.ne(&id));
+        self.steps.push(step);
+        BatchId::new(id)
+    }
+
+    /// Get a step to set its dependencies, headers or body.
+    pub fn step<S: AsRef<str>>(&mut self, id: S) -> Option<&mut BatchStep> {
+        self.steps.iter_mut().find(|step| step.id.eq(id.as_ref()))
+    }
+
+    pub fn steps(&self) -> &[BatchStep] {
+        self.steps.as_slice()
+    }
+
+    /// Set the policy used to retry steps that were throttled. Defaults
+    /// to the retry policy of the client.
+    pub fn retry_policy(&mut self, retry_policy: RetryPolicy) -> &mut Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// The steps split into the batches they are sent in. Steps that
+    /// depend on each other are kept in the same batch.
+    pub fn chunks(&self) -> GraphResult<Vec<Vec<BatchStep>>> {
+        let index: HashMap<&str, usize> = self
+            .steps
+            .iter()
+            .enumerate()
+            .map(|(i, step)| (step.id.as_str(), i))
+            .collect();
+
+        // Each step starts in its own group and the groups of steps
+        // that depend on each other are merged.
+        let mut groups: Vec<usize> = (0..self.steps.len()).collect();
+        for (i, step) in self.steps.iter().enumerate() {
+            for id in step.depends_on.iter() {
+                let j = *index.get(id.as_str()).ok_or_else(|| {
+                    GraphFailure::invalid(&format!(
+                        "dependsOn of batch step {}: no step has the id {}",
+                        step.id, id
+                    ))
+                })?;
+                let (a, b) = (root(&mut groups, i), root(&mut groups, j));
+                groups[a.max(b)] = a.min(b);
+            }
+        }
+
+        let mut grouped: BTreeMap<usize, Vec<&BatchStep>> = BTreeMap::new();
+        for (i, step) in self.steps.iter().enumerate() {
+            let r = root(&mut groups, i);
+            grouped.entry(r).or_insert_with(Vec::new).push(step);
+        }
+
+        let mut chunks: Vec<Vec<BatchStep>> = Vec::new();
+        let mut chunk: Vec<BatchStep> = Vec::new();
+        for group in grouped.values() {
+            if group.len() > MAX_BATCH_STEPS {
+                return Err(GraphFailure::invalid(&format!(
+                    "batch: more than {} steps depend on each other",
+                    MAX_BATCH_STEPS
+                )));
+            }
+            if chunk.len() + group.len() > MAX_BATCH_STEPS {
+                chunks.push(std::mem::take(&mut chunk));
+            }
+            chunk.extend(group.iter().map(|step| (*step).clone()));
+        }
+        if !chunk.is_empty() {
+            chunks.push(chunk);
+        }
+        Ok(chunks)
+    }
+
+    fn next_id(&self) -> String {
+        let mut n = self.steps.len() + 1;
+        while self.steps.iter().any(|step| step.id.eq(&n.to_string())) {
+            n += 1;
+        }
+        n.to_string()
+    }
+
+    fn set_request(&self, steps: &[BatchStep]) -> GraphResult<()> {
+        let body = serde_json::to_string(&serde_json::json!({ "requests": steps }))?;
+        let client = self.client.request();
+        client.set_url(self.url.clone());
+        client.set_method(Method::POST);
+        client.header(ACCEPT, HeaderValue::from_static("application/json"));
+        client.set_body(body);
+        client.extend_path(&["$batch"]);
+        Ok(())
+    }
+
+    // Moves the responses of the steps that are done into responses and
+    // returns the steps to send again with the delay to wait before
+    // sending them. Steps that were throttled are sent again along with
+    // the steps that failed because they depend on a throttled step.
+    fn retry(
+        &self,
+        attempt: u32,
+        steps: Vec<BatchStep>,
+        sent: Vec<BatchStepResponse>,
+        responses: &mut Vec<BatchStepResponse>,
+    ) -> Option<(Duration, Vec<BatchStep>)> {
+        let mut retry: BTreeSet<String> = BTreeSet::new();
+        if attempt < self.retry_policy.max_attempts() {
+            retry = sent
+                .iter()
+                .filter(|response| RetryPolicy::is_retry_status(response.status))
+                .map(|response| response.id.clone())
+                .collect();
+            let failed_dependency: Vec<&BatchStep> = steps
+                .iter()
+                .filter(|step| {
+                    sent.iter()
+                        .any(|response| response.id.eq(&step.id) && response.status == 424)
+                })
+                .collect();
+            let mut len = 0;
+            while len != retry.len() {
+                len = retry.len();
+                for step in failed_dependency.iter() {
+                    if step.depends_on.iter().any(|id| retry.contains(id)) {
+                        retry.insert(step.id.clone());
+                    }
+                }
+            }
+        }
+
+        let delay = sent
+            .iter()
+            .filter(|response| retry.contains(&response.id))
+            .map(|response| {
+                self.retry_policy
+                    .delay(attempt, &GraphHeaders::from(response.header_map()))
+            })
+            .max()
+            .unwrap_or_default();
+        responses.extend(
+            sent.into_iter()
+                .filter(|response| !retry.contains(&response.id)),
+        );
+        if retry.is_empty() {
+            return None;
+        }
+
+        let steps = steps
+            .into_iter()
+            .filter(|step| retry.contains(&step.id))
+            .map(|mut step| {
+                // Steps that succeeded are not sent again.
+                step.depends_on.retain(|id| retry.contains(id));
+                step
+            })
+            .collect();
+        Some((delay, steps))
+    }
+
+    fn into_response(self, mut responses: Vec<BatchStepResponse>) -> BatchResponse {
+        let steps = self.steps;
+        responses.sort_by_key(|response| {
+            steps
+                .iter()
+                .position(|step| step.id.eq(&response.id))
+                .unwrap_or(steps.len())
+        });
+        BatchResponse { responses }
+    }
+}
+
+fn root(groups: &mut Vec<usize>, mut i: usize) -> usize {
+    while groups[i] != i {
+        groups[i] = groups[groups[i]];
+        i = groups[i];
+    }
+    i
+}
+
+impl<'a> BatchRequest<'a, BlockingHttpClient> {
+    pub fn send(mut self) -> GraphResult<BatchResponse> {
+        if let Some(err) = self.error.take() {
+            return Err(err);
+        }
+
+        let mut responses = Vec::new();
+        for chunk in self.chunks()? {
+            let mut steps = chunk;
+            let mut attempt = 1;
+            loop {
+                let sent = self.send_steps(&steps)?;
+                match self.retry(attempt, steps, sent, &mut responses) {
+                    Some((delay, next)) => {
+                        thread::sleep(delay);
+                        steps = next;
+                        attempt += 1;
+                    },
+                    None => break,
+                }
+            }
+        }
+        Ok(self.into_response(responses))
+    }
+
+    fn send_steps(&self, steps: &[BatchStep]) -> GraphResult<Vec<BatchStepResponse>> {
+        self.set_request(steps)?;
+        let response = self.client.request().response()?;
+        if let Some(err) = GraphFailure::from_response(&response) {
+            return Err(err);
+        }
+        let batch: BatchResponse = response.json()?;
+        Ok(batch.responses)
+    }
+}
+
+impl<'a> BatchRequest<'a, AsyncHttpClient> {
+    pub async fn send(mut self) -> GraphResult<BatchResponse> {
+        if let Some(err) = self.error.take() {
+            return Err(err);
+        }
+
+        let mut responses = Vec::new();
+        for chunk in self.chunks()? {
+            let mut steps = chunk;
+            let mut attempt = 1;
+            loop {
+                let sent = self.send_steps(&steps).await?;
+                match self.retry(attempt, steps, sent, &mut responses) {
+                    Some((delay, next)) => {
+                        tokio::time::delay_for(delay).await;
+                        steps = next;
+                        attempt += 1;
+                    },
+                    None => break,
+                }
+            }
+        }
+        Ok(self.into_response(responses))
+    }
+
+    async fn send_steps(&self, steps: &[BatchStep]) -> GraphResult<Vec<BatchStepResponse>> {
+        self.set_request(steps)?;
+        let response = self.client.request().response().await?;
+        if let Some(err) = GraphFailure::from_async_response(&response) {
+            return Err(err);
+        }
+        let batch: BatchResponse = response.json().await?;
+        Ok(batch.responses)
+    }
+}
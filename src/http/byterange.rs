@@ -1,245 +1,445 @@
+use bytes::Bytes;
 use graph_error::{GraphFailure, GraphResult};
 use std::collections::VecDeque;
-use std::convert::TryFrom;
+use std::fmt::{Debug, Formatter};
 use std::fs;
 use std::fs::File;
 use std::io::{ErrorKind, Read, Seek, SeekFrom};
 use std::path::{Path, PathBuf};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeekExt};
 
-// The size of each byte range must be a multiple of 320 KiB (327,680 bytes).
-static RANGE_MULTIPLES: [usize; 32] = [
-    2, 4, 5, 8, 10, 16, 20, 32, 40, 64, 80, 128, 160, 256, 320, 512, 640, 1024, 1280, 2048, 2560,
-    4096, 5120, 8192, 10240, 16384, 20480, 32768, 40960, 65536, 81920, 163_840,
-];
+/// The size of each chunk of an upload session, except for the last
+/// chunk, must be a multiple of 320 KiB.
+pub const UPLOAD_CHUNK_MULTIPLE: u64 = 327_680;
 
-/// Get the byte ranges for a given file.
-pub struct ByteRange {
-    file: PathBuf,
-    byte_ranges: VecDeque<(u64, u64, Vec<u8>)>,
+/// The default chunk size of an upload session: 10 MiB.
+pub const DEFAULT_UPLOAD_CHUNK_SIZE: u64 = 32 * UPLOAD_CHUNK_MULTIPLE;
+
+/// Content smaller than 4 MiB can be uploaded using a single PUT request.
+/// Larger content is uploaded using an upload session.
+pub const SIMPLE_UPLOAD_MAX_SIZE: u64 = 4 * 1024 * 1024;
+
+// The bytes in a single request of an upload session must be less than 60 MiB.
+const MAX_UPLOAD_CHUNK_SIZE: u64 = 60 * 1024 * 1024;
+
+/// The content of an upload: a file, bytes in memory or a reader
+/// with a known length.
+///
+/// Readers are read as the content is uploaded and can only be read
+/// once, so an upload of a reader can not be resumed after the process
+/// exits. An async reader can only be uploaded using the async client.
+pub enum UploadContent {
+    File(PathBuf),
+    Bytes(Bytes),
+    Reader(Box<dyn Read + Send>, u64),
+    AsyncReader(Box<dyn AsyncRead + Send + Unpin>, u64),
 }
 
-impl ByteRange {
-    pub fn new<P: AsRef<Path>>(file: P) -> ByteRange {
-        ByteRange {
-            file: file.as_ref().to_path_buf(),
-            byte_ranges: Default::default(),
-        }
+impl UploadContent {
+    pub fn reader<R: Read + Send + 'static>(reader: R, content_length: u64) -> UploadContent {
+        UploadContent::Reader(Box::new(reader), content_length)
     }
 
-    pub fn file_size(&self) -> std::io::Result<u64> {
-        let metadata = fs::metadata(self.file.as_os_str())?;
-        Ok(metadata.len())
+    pub fn async_reader<R: AsyncRead + Send + Unpin + 'static>(
+        reader: R,
+        content_length: u64,
+    ) -> UploadContent {
+        UploadContent::AsyncReader(Box::new(reader), content_length)
     }
 
-    pub fn read_to_vec_range(
-        mut self,
-        start: u64,
-        end: u64,
-    ) -> std::io::Result<VecDeque<(u64, u64, Vec<u8>)>> {
-        let size = self.file_size()?;
-        if start > size || end > size {
-            return Err(std::io::Error::new(
-                ErrorKind::InvalidData,
-                "start or end range is greater than file size",
-            ));
-        } else if end <= start {
-            return Err(std::io::Error::new(
-                ErrorKind::InvalidData,
-                "start range cannot be greater than or equal to end range",
-            ));
+    /// The number of bytes in the content.
+    pub fn content_length(&self) -> GraphResult<u64> {
+        match self {
+            UploadContent::File(file) => Ok(fs::metadata(file.as_path())?.len()),
+            UploadContent::Bytes(bytes) => Ok(bytes.len() as u64),
+            UploadContent::Reader(_, content_length) => Ok(*content_length),
+            UploadContent::AsyncReader(_, content_length) => Ok(*content_length),
         }
+    }
 
-        let byte_range = end - start;
-        let mut f = File::open(self.file.as_os_str())?;
-        let mut end = end;
-        let mut counter = start;
-
-        while counter < size {
-            let from_pos = counter;
-            let mut v: Vec<u8> = Vec::new();
-            f.seek(SeekFrom::Start(from_pos))?;
-
-            if size - counter < byte_range {
-                counter += size - counter;
-                {
-                    f.by_ref().take(counter).read_to_end(&mut v)?;
-                }
-            } else {
-                counter += byte_range;
-                {
-                    f.by_ref().take(byte_range).read_to_end(&mut v)?;
-                }
-            }
+    /// Read all of the content into memory.
+    pub fn read_to_vec(self) -> GraphResult<Vec<u8>> {
+        let content_length = self.content_length()?;
+        let body = match self {
+            UploadContent::File(file) => fs::read(file)?,
+            UploadContent::Bytes(bytes) => bytes.to_vec(),
+            UploadContent::Reader(reader, _) => {
+                let mut body = Vec::with_capacity(content_length as usize);
+                reader.take(content_length).read_to_end(&mut body)?;
+                body
+            },
+            UploadContent::AsyncReader(..) => {
+                return Err(GraphFailure::invalid(
+                    "content. An async reader can only be uploaded using the async client",
+                ));
+            },
+        };
+        UploadContent::check_content_length(body, content_length)
+    }
 
-            let range = end + v.len() as u64;
-            if end != 0 && range < size {
-                end += 1;
-            }
+    pub async fn read_to_vec_async(self) -> GraphResult<Vec<u8>> {
+        let content_length = self.content_length()?;
+        let body = match self {
+            UploadContent::File(file) => tokio::fs::read(file).await?,
+            UploadContent::AsyncReader(reader, _) => {
+                let mut body = Vec::with_capacity(content_length as usize);
+                reader.take(content_length).read_to_end(&mut body).await?;
+                body
+            },
+            content => return content.read_to_vec(),
+        };
+        UploadContent::check_content_length(body, content_length)
+    }
 
-            let start = end;
-            if range > size {
-                end += size - end;
-            } else {
-                end += (v.len() as u64) - 1;
-            }
-            self.byte_ranges.push_back((start, end, v));
+    fn check_content_length(body: Vec<u8>, content_length: u64) -> GraphResult<Vec<u8>> {
+        if body.len() as u64 != content_length {
+            return Err(GraphFailure::error_kind(
+                ErrorKind::UnexpectedEof,
+                "the content is smaller than the content length",
+            ));
         }
-        Ok(self.byte_ranges)
-    }
-
-    pub fn read_to_vec(mut self) -> std::io::Result<VecDeque<(u64, u64, Vec<u8>)>> {
-        let byte_range = self.find_byte_range()?;
-        let size = self.file_size()?;
-        let mut f = File::open(self.file.as_os_str())?;
-
-        let mut end = 0;
-        let mut counter = 0;
-        while counter < size {
-            let from_pos = counter;
-
-            let mut v: Vec<u8> = Vec::new();
-            f.seek(SeekFrom::Start(from_pos))?;
-            if size - counter < byte_range {
-                counter += size - counter;
-                {
-                    let reference = f.by_ref();
-                    reference.take(counter).read_to_end(&mut v)?;
-                }
-            } else {
-                counter += byte_range;
-                {
-                    let reference = f.by_ref();
-                    reference.take(byte_range).read_to_end(&mut v)?;
-                }
-            }
-
-            let range = end + v.len() as u64;
-            if end != 0 && range < size {
-                end += 1;
-            }
+        Ok(body)
+    }
+}
 
-            let start = end;
-            if range > size {
-                end += size - end;
-            } else {
-                end += (v.len() as u64) - 1;
-            }
-            self.byte_ranges.push_back((start, end, v));
+impl Debug for UploadContent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UploadContent::File(file) => f.debug_tuple("File").field(file).finish(),
+            UploadContent::Bytes(bytes) => f.debug_tuple("Bytes").field(&bytes.len()).finish(),
+            UploadContent::Reader(_, content_length) => {
+                f.debug_tuple("Reader").field(content_length).finish()
+            },
+            UploadContent::AsyncReader(_, content_length) => {
+                f.debug_tuple("AsyncReader").field(content_length).finish()
+            },
         }
-        Ok(self.byte_ranges)
     }
+}
 
-    fn find_byte_range(&mut self) -> std::io::Result<u64> {
-        let file_size = self.file_size()?;
-        for (i, next) in RANGE_MULTIPLES.iter().rev().enumerate() {
-            let n = *next as u64;
-            let div = file_size / n;
-            let half = file_size / 2;
+impl From<PathBuf> for UploadContent {
+    fn from(file: PathBuf) -> Self {
+        UploadContent::File(file)
+    }
+}
 
-            if div > 2 && div < half {
-                let num: u64 = file_size / n;
-                if num > 15 || i > 25 {
-                    return Ok(n);
-                }
-            }
-        }
+impl From<&Path> for UploadContent {
+    fn from(file: &Path) -> Self {
+        UploadContent::File(file.to_path_buf())
+    }
+}
 
-        Ok(1)
+impl From<Bytes> for UploadContent {
+    fn from(bytes: Bytes) -> Self {
+        UploadContent::Bytes(bytes)
     }
 }
 
-#[derive(Debug, Default)]
-pub struct HttpByteRange {
-    file_size: u64,
-    byte_range: VecDeque<(u64, u64, Vec<u8>)>,
+impl From<Vec<u8>> for UploadContent {
+    fn from(bytes: Vec<u8>) -> Self {
+        UploadContent::Bytes(Bytes::from(bytes))
+    }
 }
 
-impl HttpByteRange {
-    pub fn new(file_size: u64, byte_range: VecDeque<(u64, u64, Vec<u8>)>) -> HttpByteRange {
-        HttpByteRange {
-            file_size,
-            byte_range,
+// Where the chunks of a chunked file are read from.
+enum ChunkSource {
+    File,
+    Bytes(Bytes),
+    Reader(Box<dyn Read + Send>, ReadBuffer),
+    AsyncReader(Box<dyn AsyncRead + Send + Unpin>, ReadBuffer),
+}
+
+// The bytes read from a reader that have not been uploaded. A reader can
+// only be read once so the bytes of a chunk are kept until the next chunk
+// is read in case the chunk needs to be sent again.
+#[derive(Default)]
+struct ReadBuffer {
+    start: u64,
+    bytes: Vec<u8>,
+}
+
+impl ReadBuffer {
+    // Drops the bytes before start. Returns the number of bytes that
+    // need to be skipped in the reader to get to start.
+    fn seek(&mut self, start: u64) -> GraphResult<u64> {
+        if start < self.start {
+            return Err(GraphFailure::invalid(&format!(
+                "byte range. The reader can not be read again from byte {}",
+                start
+            )));
         }
+        let position = self.position();
+        if start >= position {
+            self.bytes.clear();
+            self.start = start;
+            return Ok(start - position);
+        }
+        self.bytes.drain(..(start - self.start) as usize);
+        self.start = start;
+        Ok(0)
     }
 
-    pub fn from_range<P: AsRef<Path>>(start: u64, end: u64, file: P) -> GraphResult<HttpByteRange> {
-        let path: &Path = file.as_ref();
-        let byte_range = ByteRange::new(path.to_path_buf());
-        let file_size = byte_range.file_size()?;
-        Ok(HttpByteRange::new(
-            file_size,
-            byte_range.read_to_vec_range(start, end)?,
-        ))
+    fn position(&self) -> u64 {
+        self.start + self.bytes.len() as u64
+    }
+
+    // The number of bytes that need to be read to have the bytes up to end.
+    fn remaining(&self, end: u64) -> u64 {
+        (end + 1).saturating_sub(self.position())
+    }
+
+    fn chunk(&self, content_length: u64) -> Vec<u8> {
+        let len = (content_length as usize).min(self.bytes.len());
+        self.bytes[..len].to_vec()
+    }
+}
+
+/// A file, or other upload content, that is read one chunk at a time
+/// as it is uploaded.
+///
+/// Only the chunk that is being uploaded is kept in memory. The remaining
+/// byte ranges can be set from the nextExpectedRanges of an upload session
+/// to resume an upload that was interrupted.
+pub struct ChunkedFile {
+    file: PathBuf,
+    source: ChunkSource,
+    file_size: u64,
+    chunk_size: u64,
+    ranges: VecDeque<(u64, u64)>,
+}
+
+impl ChunkedFile {
+    pub fn new<P: AsRef<Path>>(file: P) -> GraphResult<ChunkedFile> {
+        ChunkedFile::from_content(UploadContent::File(file.as_ref().to_path_buf()))
     }
 
-    pub async fn async_from_range<P: AsRef<Path>>(
-        start: u64,
-        end: u64,
-        file: P,
-    ) -> std::io::Result<HttpByteRange> {
-        let path: &Path = file.as_ref();
-        let byte_range = ByteRange::new(path.to_path_buf());
-        let file_size = byte_range.file_size()?;
-        Ok(HttpByteRange::new(
+    pub fn from_content(content: UploadContent) -> GraphResult<ChunkedFile> {
+        let file_size = content.content_length()?;
+        let (file, source) = match content {
+            UploadContent::File(file) => (file, ChunkSource::File),
+            UploadContent::Bytes(bytes) => (PathBuf::new(), ChunkSource::Bytes(bytes)),
+            UploadContent::Reader(reader, _) => (
+                PathBuf::new(),
+                ChunkSource::Reader(reader, ReadBuffer::default()),
+            ),
+            UploadContent::AsyncReader(reader, _) => (
+                PathBuf::new(),
+                ChunkSource::AsyncReader(reader, ReadBuffer::default()),
+            ),
+        };
+        let mut ranges = VecDeque::new();
+        if file_size > 0 {
+            ranges.push_back((0, file_size - 1));
+        }
+        Ok(ChunkedFile {
+            file,
+            source,
             file_size,
-            byte_range.read_to_vec_range(start, end)?,
-        ))
+            chunk_size: DEFAULT_UPLOAD_CHUNK_SIZE,
+            ranges,
+        })
     }
 
-    pub async fn try_from_async(value: PathBuf) -> Result<HttpByteRange, GraphFailure> {
-        let byte_range = ByteRange::new(value);
-        let file_size = byte_range.file_size()?;
-        let bytes = byte_range.read_to_vec()?;
-        Ok(HttpByteRange::new(file_size, bytes))
+    /// The path of the file. The path is empty if the content
+    /// is not a file.
+    pub fn file(&self) -> &Path {
+        self.file.as_path()
     }
 
     pub fn file_size(&self) -> u64 {
         self.file_size
     }
 
+    pub fn chunk_size(&self) -> u64 {
+        self.chunk_size
+    }
+
+    /// Set the size of each chunk. The size must be a multiple of
+    /// 320 KiB (327,680 bytes) and less than 60 MiB.
+    pub fn set_chunk_size(&mut self, chunk_size: u64) -> GraphResult<()> {
+        if chunk_size == 0 ||
+            chunk_size % UPLOAD_CHUNK_MULTIPLE != 0 ||
+            chunk_size >= MAX_UPLOAD_CHUNK_SIZE
+        {
+            return Err(GraphFailure::invalid(
+                "chunk size. The chunk size must be a multiple of 320 KiB and less than 60 MiB",
+            ));
+        }
+        self.chunk_size = chunk_size;
+        Ok(())
+    }
+
+    /// Only upload the bytes from start to end, inclusive.
+    pub fn set_range(&mut self, start: u64, end: u64) -> GraphResult<()> {
+        if start > end || end >= self.file_size {
+            return Err(GraphFailure::invalid(
+                "byte range. The start must not be greater than the end and the end must be less than the file size",
+            ));
+        }
+        self.ranges.clear();
+        self.ranges.push_back((start, end));
+        Ok(())
+    }
+
+    /// Set the remaining byte ranges from the nextExpectedRanges of an
+    /// upload session such as 0-1023 or 1024-, where a missing end is the
+    /// end of the file.
+    pub fn set_next_expected_ranges<S: AsRef<str>>(&mut self, ranges: &[S]) -> GraphResult<()> {
+        let mut next = VecDeque::new();
+        for range in ranges.iter() {
+            let range = range.as_ref();
+            let mut split = range.splitn(2, '-');
+            let start: u64 = split.next().unwrap_or_default().trim().parse()?;
+            let end: u64 = match split.next().map(|s| s.trim()) {
+                Some(end) if !end.is_empty() => end.parse()?,
+                _ => self.file_size.saturating_sub(1),
+            };
+            if start > end || end >= self.file_size {
+                return Err(GraphFailure::invalid(&format!(
+                    "nextExpectedRanges: the range {} is not in the file",
+                    range
+                )));
+            }
+            next.push_back((start, end));
+        }
+        self.ranges = next;
+        Ok(())
+    }
+
     pub fn is_empty(&self) -> bool {
-        self.byte_range.is_empty()
-    }
-
-    pub fn pop_front(&mut self) -> Option<(Vec<u8>, u64, String)> {
-        let next = self.byte_range.pop_front()?;
-        let mut content_length = next.1 - next.0;
-        content_length += 1;
-        let start = next.0.to_string();
-        let end = next.1.to_string();
-        let file_size = self.file_size.to_string();
-        Some((
-            next.2,
-            content_length,
-            format!(
-                "bytes {}-{}/{}",
-                start.as_str(),
-                end.as_str(),
-                file_size.as_str()
-            ),
-        ))
+        self.ranges.is_empty()
+    }
+
+    /// The number of bytes that have not been uploaded.
+    pub fn bytes_remaining(&self) -> u64 {
+        self.ranges.iter().map(|(start, end)| end - start + 1).sum()
     }
 
     pub fn clear(&mut self) {
-        self.byte_range.clear();
+        self.ranges.clear();
+    }
+
+    /// Read the next chunk: the body, the content length and the
+    /// content range. The chunk is not removed until `advance` is called
+    /// so that it can be sent again if the request fails.
+    pub fn next_chunk(&mut self) -> GraphResult<Option<(Vec<u8>, u64, String)>> {
+        let (start, end) = match self.next_range() {
+            Some(range) => range,
+            None => return Ok(None),
+        };
+        let content_length = end - start + 1;
+        let body = match &mut self.source {
+            ChunkSource::File => {
+                let mut file = File::open(self.file.as_path())?;
+                file.seek(SeekFrom::Start(start))?;
+                let mut body = Vec::with_capacity(content_length as usize);
+                file.take(content_length).read_to_end(&mut body)?;
+                body
+            },
+            ChunkSource::Bytes(bytes) => ChunkedFile::slice(bytes, start, end),
+            ChunkSource::Reader(reader, buffer) => {
+                let skip = buffer.seek(start)?;
+                std::io::copy(&mut reader.by_ref().take(skip), &mut std::io::sink())?;
+                let remaining = buffer.remaining(end);
+                reader
+                    .by_ref()
+                    .take(remaining)
+                    .read_to_end(&mut buffer.bytes)?;
+                buffer.chunk(content_length)
+            },
+            ChunkSource::AsyncReader(..) => {
+                return Err(GraphFailure::invalid(
+                    "content. An async reader can only be uploaded using the async client",
+                ));
+            },
+        };
+        self.chunk(start, end, body).map(Some)
+    }
+
+    pub async fn next_chunk_async(&mut self) -> GraphResult<Option<(Vec<u8>, u64, String)>> {
+        let (start, end) = match self.next_range() {
+            Some(range) => range,
+            None => return Ok(None),
+        };
+        let content_length = end - start + 1;
+        let body = match &mut self.source {
+            ChunkSource::File => {
+                let mut file = tokio::fs::File::open(self.file.as_path()).await?;
+                file.seek(SeekFrom::Start(start)).await?;
+                let mut body = Vec::with_capacity(content_length as usize);
+                file.take(content_length).read_to_end(&mut body).await?;
+                body
+            },
+            ChunkSource::AsyncReader(reader, buffer) => {
+                let skip = buffer.seek(start)?;
+                tokio::io::copy(&mut (&mut *reader).take(skip), &mut tokio::io::sink()).await?;
+                let remaining = buffer.remaining(end);
+                (&mut *reader)
+                    .take(remaining)
+                    .read_to_end(&mut buffer.bytes)
+                    .await?;
+                buffer.chunk(content_length)
+            },
+            _ => return self.next_chunk(),
+        };
+        self.chunk(start, end, body).map(Some)
+    }
+
+    /// Remove the bytes of a chunk that was uploaded.
+    pub fn advance(&mut self, content_length: u64) {
+        if let Some((start, end)) = self.ranges.pop_front() {
+            let next = start + content_length;
+            if next <= end {
+                self.ranges.push_front((next, end));
+            }
+        }
     }
-}
 
-impl Iterator for HttpByteRange {
-    type Item = (Vec<u8>, u64, String);
+    fn next_range(&self) -> Option<(u64, u64)> {
+        let (start, end) = *self.ranges.front()?;
+        Some((start, end.min(start + self.chunk_size - 1)))
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self.pop_front()
+    fn slice(bytes: &Bytes, start: u64, end: u64) -> Vec<u8> {
+        let end = (end as usize + 1).min(bytes.len());
+        bytes.slice((start as usize).min(end)..end).to_vec()
+    }
+
+    fn chunk(&self, start: u64, end: u64, body: Vec<u8>) -> GraphResult<(Vec<u8>, u64, String)> {
+        let content_length = end - start + 1;
+        if body.len() as u64 != content_length {
+            return Err(GraphFailure::error_kind(
+                ErrorKind::UnexpectedEof,
+                "the content is smaller than when the upload session started",
+            ));
+        }
+        Ok((
+            body,
+            content_length,
+            format!("bytes {}-{}/{}", start, end, self.file_size),
+        ))
     }
 }
 
-impl TryFrom<PathBuf> for HttpByteRange {
-    type Error = GraphFailure;
+impl Debug for ChunkedFile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ChunkedFile")
+            .field("file", &self.file)
+            .field("file_size", &self.file_size)
+            .field("chunk_size", &self.chunk_size)
+            .field("ranges", &self.ranges)
+            .finish()
+    }
+}
 
-    fn try_from(value: PathBuf) -> Result<Self, Self::Error> {
-        let byte_range = ByteRange::new(value);
-        let file_size = byte_range.file_size()?;
-        let bytes = byte_range.read_to_vec()?;
-        Ok(HttpByteRange::new(file_size, bytes))
+impl Default for ChunkedFile {
+    fn default() -> Self {
+        ChunkedFile {
+            file: PathBuf::new(),
+            source: ChunkSource::File,
+            file_size: 0,
+            chunk_size: DEFAULT_UPLOAD_CHUNK_SIZE,
+            ranges: VecDeque::new(),
+        }
     }
 }
@@ -1,34 +1,85 @@
-use crate::http::{AsyncTryFrom, GraphResponse};
+use crate::http::{
+    send_with_token_provider, send_with_token_provider_async, AsyncTryFrom, GraphResponse,
+    Pipeline, RetryPolicy, TokenProvider,
+};
 use crate::types::delta::{Delta, NextLink};
 use graph_error::{GraphFailure, GraphResult};
 use reqwest::header::CONTENT_TYPE;
 use std::marker::PhantomData;
 use std::sync::mpsc::{channel, Receiver};
+use std::sync::Arc;
 use std::thread;
 
-pub struct IntoDeltaRequest<T, Builder> {
+pub struct IntoDeltaRequest<T, Builder, Client> {
     token: String,
     client: Builder,
     error: Option<GraphFailure>,
+    retry_policy: RetryPolicy,
+    pipeline: Pipeline,
+    token_provider: Option<Arc<dyn TokenProvider>>,
+    http_client: Option<Client>,
     phantom: PhantomData<T>,
 }
 
-impl<T, Builder> IntoDeltaRequest<T, Builder> {
+impl<T, Builder, Client> IntoDeltaRequest<T, Builder, Client> {
+    /// Create a delta request from a request builder that does not have
+    /// the Authorization header. The bearer token is added to the initial
+    /// request and each request for the next link when they are sent.
     pub fn new(
         token: String,
         client: Builder,
         error: Option<GraphFailure>,
-    ) -> IntoDeltaRequest<T, Builder> {
+    ) -> IntoDeltaRequest<T, Builder, Client> {
         IntoDeltaRequest {
             token,
             client,
             error,
+            retry_policy: Default::default(),
+            pipeline: Default::default(),
+            token_provider: None,
+            http_client: None,
             phantom: Default::default(),
         }
     }
+
+    /// Set the retry policy used for the initial request and
+    /// each request for the next link.
+    pub fn retry_policy(
+        mut self,
+        retry_policy: RetryPolicy,
+    ) -> IntoDeltaRequest<T, Builder, Client> {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Set the middleware that the initial request and each request
+    /// for the next link are sent through.
+    pub fn pipeline(mut self, pipeline: Pipeline) -> IntoDeltaRequest<T, Builder, Client> {
+        self.pipeline = pipeline;
+        self
+    }
+
+    /// Set the token provider used for the bearer token of
+    /// each request for the next link.
+    pub fn token_provider(
+        mut self,
+        token_provider: Option<Arc<dyn TokenProvider>>,
+    ) -> IntoDeltaRequest<T, Builder, Client> {
+        self.token_provider = token_provider;
+        self
+    }
+
+    /// Set the client that the initial request was built with. Requests
+    /// for the next link are sent with the same client so that they use
+    /// the same connection pool and configuration.
+    pub fn http_client(mut self, http_client: Client) -> IntoDeltaRequest<T, Builder, Client> {
+        self.http_client = Some(http_client);
+        self
+    }
 }
 
-impl<T: 'static + Send + NextLink + Clone> IntoDeltaRequest<T, reqwest::blocking::RequestBuilder>
+impl<T: 'static + Send + NextLink + Clone>
+    IntoDeltaRequest<T, reqwest::blocking::RequestBuilder, reqwest::blocking::Client>
 where
     for<'de> T: serde::Deserialize<'de>,
 {
@@ -41,29 +92,42 @@ where
             return receiver;
         }
 
-        let initial_res: GraphResult<reqwest::blocking::Response> =
-            self.client.send().map_err(GraphFailure::from);
+        let retry_policy = self.retry_policy;
+        let pipeline = self.pipeline;
+        let client = self.http_client.unwrap_or_default();
+        let mut token = self.token;
+        let token_provider = self.token_provider;
+        let initial_res = send_page(
+            self.client,
+            &mut token,
+            token_provider.as_ref(),
+            &retry_policy,
+            &pipeline,
+            &client,
+        );
         let response: GraphResult<GraphResponse<T>> = std::convert::TryFrom::try_from(initial_res);
         if let Err(err) = response {
             sender.send(Delta::Done(Some(err))).unwrap();
             return receiver;
         }
 
-        let token = self.token;
         let response = response.unwrap();
         let mut next_link = response.body().next_link();
         sender.send(Delta::Next(response)).unwrap();
 
         thread::spawn(move || {
             let mut is_done = false;
-            let client = reqwest::blocking::Client::new();
             while let Some(next) = next_link {
-                let res = client
-                    .get(next.as_str())
-                    .header(CONTENT_TYPE, "application/json")
-                    .bearer_auth(token.as_str())
-                    .send()
-                    .map_err(GraphFailure::from);
+                let res = send_page(
+                    client
+                        .get(next.as_str())
+                        .header(CONTENT_TYPE, "application/json"),
+                    &mut token,
+                    token_provider.as_ref(),
+                    &retry_policy,
+                    &pipeline,
+                    &client,
+                );
 
                 if let Err(err) = res {
                     next_link = None;
@@ -104,7 +168,8 @@ where
     }
 }
 
-impl<T: 'static + Send + NextLink + Clone> IntoDeltaRequest<T, reqwest::RequestBuilder>
+impl<T: 'static + Send + NextLink + Clone>
+    IntoDeltaRequest<T, reqwest::RequestBuilder, reqwest::Client>
 where
     for<'de> T: serde::Deserialize<'de>,
 {
@@ -119,8 +184,20 @@ where
             return receiver;
         }
 
-        let initial_res: GraphResult<reqwest::Response> =
-            self.client.send().await.map_err(GraphFailure::from);
+        let retry_policy = self.retry_policy;
+        let pipeline = self.pipeline;
+        let client = self.http_client.unwrap_or_default();
+        let mut token = self.token;
+        let token_provider = self.token_provider;
+        let initial_res = send_page_async(
+            self.client,
+            &mut token,
+            token_provider.as_ref(),
+            &retry_policy,
+            &pipeline,
+            &client,
+        )
+        .await;
         let response: GraphResult<GraphResponse<T>> =
             AsyncTryFrom::<GraphResult<reqwest::Response>>::try_from(initial_res).await;
         if let Err(err) = response {
@@ -128,22 +205,24 @@ where
             return receiver;
         }
 
-        let token = self.token;
         let response = response.unwrap();
         let mut next_link = response.body().next_link();
         sender.send(Delta::Next(response)).await.unwrap();
 
         tokio::spawn(async move {
             let mut is_done = false;
-            let client = reqwest::Client::new();
             while let Some(next) = next_link {
-                let res = client
-                    .get(next.as_str())
-                    .header(CONTENT_TYPE, "application/json")
-                    .bearer_auth(token.as_str())
-                    .send()
-                    .await
-                    .map_err(GraphFailure::from);
+                let res = send_page_async(
+                    client
+                        .get(next.as_str())
+                        .header(CONTENT_TYPE, "application/json"),
+                    &mut token,
+                    token_provider.as_ref(),
+                    &retry_policy,
+                    &pipeline,
+                    &client,
+                )
+                .await;
 
                 if let Err(err) = res {
                     next_link = None;
@@ -185,3 +264,44 @@ where
         receiver
     }
 }
+
+// Sends the request for a page. The bearer token is added to the request
+// here so that the request can be sent again with a refreshed token when
+// the token provider is set and the token was rejected.
+fn send_page(
+    builder: reqwest::blocking::RequestBuilder,
+    token: &mut String,
+    token_provider: Option<&Arc<dyn TokenProvider>>,
+    retry_policy: &RetryPolicy,
+    pipeline: &Pipeline,
+    client: &reqwest::blocking::Client,
+) -> GraphResult<reqwest::blocking::Response> {
+    let send = move |builder: reqwest::blocking::RequestBuilder| {
+        retry_policy.send_with(builder, |builder| pipeline.send(client, builder))
+    };
+    match token_provider {
+        Some(token_provider) => {
+            send_with_token_provider(token_provider.as_ref(), builder, token, send)
+        },
+        None => send(builder.bearer_auth(token.as_str())),
+    }
+}
+
+async fn send_page_async(
+    builder: reqwest::RequestBuilder,
+    token: &mut String,
+    token_provider: Option<&Arc<dyn TokenProvider>>,
+    retry_policy: &RetryPolicy,
+    pipeline: &Pipeline,
+    client: &reqwest::Client,
+) -> GraphResult<reqwest::Response> {
+    let send = move |builder: reqwest::RequestBuilder| {
+        retry_policy.send_with_async(builder, move |builder| pipeline.send_async(client, builder))
+    };
+    match token_provider {
+        Some(token_provider) => {
+            send_with_token_provider_async(token_provider.as_ref(), builder, token, send).await
+        },
+        None => send(builder.bearer_auth(token.as_str())).await,
+    }
+}
@@ -0,0 +1,450 @@
+use crate::client::Graph;
+use crate::http::{AsyncHttpClient, BlockingHttpClient, GraphResponse, RequestClient};
+use crate::types::collection::Collection;
+use crate::url::GraphUrl;
+use futures::Stream;
+use graph_error::{GraphFailure, GraphResult};
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
+use reqwest::Method;
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::fmt;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// Storage for the latest @odata.deltaLink of a delta sync.
+///
+/// Delta links are stored under a key chosen by the caller, such as the
+/// resource that is being synced, so that one store can be used for
+/// several delta queries.
+pub trait DeltaStore: Send + Sync {
+    fn get(&self, key: &str) -> GraphResult<Option<String>>;
+
+    fn set(&self, key: &str, delta_link: &str) -> GraphResult<()>;
+
+    fn remove(&self, key: &str) -> GraphResult<()>;
+}
+
+/// A delta store that only lasts for the life of the process.
+#[derive(Debug, Default)]
+pub struct InMemoryDeltaStore {
+    links: Mutex<HashMap<String, String>>,
+}
+
+impl InMemoryDeltaStore {
+    pub fn new() -> InMemoryDeltaStore {
+        InMemoryDeltaStore::default()
+    }
+
+    fn links(&self) -> GraphResult<std::sync::MutexGuard<HashMap<String, String>>> {
+        self.links
+            .lock()
+            .map_err(|_| GraphFailure::invalid("delta store lock"))
+    }
+}
+
+impl DeltaStore for InMemoryDeltaStore {
+    fn get(&self, key: &str) -> GraphResult<Option<String>> {
+        Ok(self.links()?.get(key).cloned())
+    }
+
+    fn set(&self, key: &str, delta_link: &str) -> GraphResult<()> {
+        self.links()?
+            .insert(key.to_string(), delta_link.to_string());
+        Ok(())
+    }
+
+    fn remove(&self, key: &str) -> GraphResult<()> {
+        self.links()?.remove(key);
+        Ok(())
+    }
+}
+
+/// A delta store that stores delta links in a JSON file.
+///
+/// # Example
+/// ```rust,ignore
+/// # use graph_rs::http::FileDeltaStore;
+/// let store = FileDeltaStore::new("./delta.json");
+/// ```
+pub struct FileDeltaStore {
+    path: PathBuf,
+    lock: Mutex<()>,
+}
+
+impl FileDeltaStore {
+    pub fn new<P: AsRef<Path>>(path: P) -> FileDeltaStore {
+        FileDeltaStore {
+            path: path.as_ref().to_path_buf(),
+            lock: Mutex::new(()),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        self.path.as_path()
+    }
+
+    fn read(&self) -> GraphResult<BTreeMap<String, String>> {
+        match fs::read(self.path.as_path()) {
+            Ok(content) => Ok(serde_json::from_slice(&content)?),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(BTreeMap::new()),
+            Err(err) => Err(GraphFailure::from(err)),
+        }
+    }
+
+    fn update<F>(&self, f: F) -> GraphResult<()>
+    where
+        F: FnOnce(&mut BTreeMap<String, String>),
+    {
+        let _lock = self
+            .lock
+            .lock()
+            .map_err(|_| GraphFailure::invalid("delta store lock"))?;
+        let mut links = self.read()?;
+        f(&mut links);
+
+        // Write to a temporary file first so that the store is not left
+        // truncated if the process stops part way through the write.
+        let mut temp_path = self.path.clone().into_os_string();
+        temp_path.push(".tmp");
+        fs::write(&temp_path, serde_json::to_vec(&links)?)?;
+        fs::rename(&temp_path, self.path.as_path())?;
+        Ok(())
+    }
+}
+
+impl DeltaStore for FileDeltaStore {
+    fn get(&self, key: &str) -> GraphResult<Option<String>> {
+        let _lock = self
+            .lock
+            .lock()
+            .map_err(|_| GraphFailure::invalid("delta store lock"))?;
+        Ok(self.read()?.remove(key))
+    }
+
+    fn set(&self, key: &str, delta_link: &str) -> GraphResult<()> {
+        self.update(|links| {
+            links.insert(key.to_string(), delta_link.to_string());
+        })
+    }
+
+    fn remove(&self, key: &str) -> GraphResult<()> {
+        self.update(|links| {
+            links.remove(key);
+        })
+    }
+}
+
+impl fmt::Debug for FileDeltaStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FileDeltaStore")
+            .field("path", &self.path)
+            .finish()
+    }
+}
+
+/// A change returned by a delta sync.
+///
+/// Delta responses do not say whether an item was created or changed.
+/// Items returned by the initial sync, which does not have a delta
+/// link to start from, are Added and items returned when following a
+/// stored delta link are Updated.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DeltaChange<T> {
+    Added(T),
+    Updated(T),
+    /// An item that was deleted or, for a reason of changed, is no
+    /// longer in the scope of the delta query.
+    Removed {
+        id: String,
+        reason: Option<String>,
+    },
+    /// The stored delta link was no longer valid and the sync restarted
+    /// from the initial request. Every item is returned again as Added.
+    Resync,
+}
+
+impl<T> DeltaChange<T>
+where
+    for<'de> T: serde::Deserialize<'de>,
+{
+    /// Create a change from an item of a delta response. Items that have
+    /// an @removed property, or a deleted facet in the case of drive
+    /// items, are Removed.
+    pub fn from_value(value: Value, initial_sync: bool) -> GraphResult<DeltaChange<T>> {
+        if value.get("@removed").is_some() || value.get("deleted").is_some() {
+            let id = value["id"]
+                .as_str()
+                .ok_or_else(|| GraphFailure::invalid("id of the removed item"))?;
+            return Ok(DeltaChange::Removed {
+                id: id.to_string(),
+                reason: value["@removed"]["reason"].as_str().map(|s| s.to_string()),
+            });
+        }
+
+        let item: T = serde_json::from_value(value)?;
+        if initial_sync {
+            Ok(DeltaChange::Added(item))
+        } else {
+            Ok(DeltaChange::Updated(item))
+        }
+    }
+}
+
+/// Syncs a resource using a delta query and stores the latest
+/// @odata.deltaLink in a [DeltaStore](trait.DeltaStore.html).
+///
+/// The sync starts from the delta link in the store for the key or
+/// from the delta request if there is not one. Each page is requested by
+/// following the @odata.nextLink of the previous page. The delta link of
+/// the last page is written to the store once every change has been
+/// returned so that the next sync only returns the changes made since.
+/// A sync that is dropped or stops on an error before then leaves the
+/// stored delta link as it was and the changes are returned again.
+///
+/// If the stored delta link is no longer valid, such as when Microsoft
+/// Graph returns 410 Gone or a resync error code, the delta link is
+/// removed from the store and the sync restarts once from the delta
+/// request after returning [DeltaChange::Resync](enum.DeltaChange.html).
+///
+/// The blocking client returns an iterator and the async client returns
+/// a stream using `stream()`. Iteration stops after the first error.
+///
+/// # Example
+/// ```rust,ignore
+/// # use graph_rs::prelude::*;
+/// # use graph_rs::http::{DeltaChange, FileDeltaStore};
+/// # use std::sync::Arc;
+/// # let client = Graph::new("ACCESS_TOKEN");
+/// let store = Arc::new(FileDeltaStore::new("./delta.json"));
+///
+/// for change in client.v1().me().drive().delta().sync("me/drive", store) {
+///     match change? {
+///         DeltaChange::Added(item) | DeltaChange::Updated(item) => println!("{:#?}", item.name()),
+///         DeltaChange::Removed { id, .. } => println!("removed {}", id),
+///         DeltaChange::Resync => println!("resync"),
+///     }
+/// }
+/// ```
+pub struct DeltaSync<'a, T, Client>
+where
+    Client: RequestClient,
+{
+    client: &'a Graph<Client>,
+    key: String,
+    store: Arc<dyn DeltaStore>,
+    initial_url: GraphUrl,
+    headers: HeaderMap,
+    next: Option<GraphUrl>,
+    initial_sync: bool,
+    restarted: bool,
+    changes: VecDeque<DeltaChange<T>>,
+    pending_delta_link: Option<String>,
+    delta_link: Option<String>,
+    error: Option<GraphFailure>,
+}
+
+impl<'a, T, Client> DeltaSync<'a, T, Client>
+where
+    Client: RequestClient,
+{
+    // The request the request builder set on the client is taken so that
+    // the client can be used for other requests between pages.
+    pub(crate) fn new(
+        client: &'a Graph<Client>,
+        key: &str,
+        store: Arc<dyn DeltaStore>,
+        error: Option<GraphFailure>,
+    ) -> Self {
+        let request = client.request();
+        let initial_url = request.url();
+        let headers = request.header_map();
+        let mut headers_default = HeaderMap::new();
+        headers_default.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        request.set_header_map(headers_default);
+
+        let mut delta_sync = DeltaSync {
+            client,
+            key: key.to_string(),
+            store,
+            initial_url: initial_url.clone(),
+            headers,
+            next: None,
+            initial_sync: true,
+            restarted: false,
+            changes: VecDeque::new(),
+            pending_delta_link: None,
+            delta_link: None,
+            error,
+        };
+
+        if delta_sync.error.is_none() {
+            match delta_sync.stored_delta_link() {
+                Ok(Some(delta_link)) => {
+                    delta_sync.next = Some(delta_link);
+                    delta_sync.initial_sync = false;
+                },
+                Ok(None) => delta_sync.next = Some(initial_url),
+                Err(err) => delta_sync.error = Some(err),
+            }
+        }
+        delta_sync
+    }
+
+    /// The key the delta link is stored under.
+    pub fn key(&self) -> &str {
+        self.key.as_str()
+    }
+
+    /// The delta link of the last page once the sync is done and the
+    /// delta link has been written to the store.
+    pub fn delta_link(&self) -> Option<&String> {
+        self.delta_link.as_ref()
+    }
+
+    fn stored_delta_link(&self) -> GraphResult<Option<GraphUrl>> {
+        match self.store.get(self.key.as_str())? {
+            Some(delta_link) => Ok(Some(GraphUrl::parse(delta_link.as_str())?)),
+            None => Ok(None),
+        }
+    }
+
+    // Writes the delta link of the last page to the store. This is only
+    // called after every change has been returned.
+    fn commit_delta_link(&mut self) -> GraphResult<()> {
+        if let Some(delta_link) = self.pending_delta_link.take() {
+            self.store.set(self.key.as_str(), delta_link.as_str())?;
+            self.delta_link = Some(delta_link);
+        }
+        Ok(())
+    }
+
+    // Sets the request for the next page on the client. Returns
+    // false if there are no more pages.
+    fn set_next_request(&mut self) -> bool {
+        let url = match self.next.take() {
+            Some(url) => url,
+            None => return false,
+        };
+
+        let request = self.client.request();
+        request.set_url(url);
+        request.set_method(Method::GET);
+        request.set_header_map(self.headers.clone());
+        true
+    }
+
+    fn add_page(
+        &mut self,
+        response: GraphResult<GraphResponse<Collection<Value>>>,
+    ) -> GraphResult<()>
+    where
+        for<'de> T: serde::Deserialize<'de>,
+    {
+        let collection = match response {
+            Ok(response) => response.into_body(),
+            Err(err) if !self.restarted && is_resync(&err) => {
+                self.store.remove(self.key.as_str())?;
+                self.next = Some(self.initial_url.clone());
+                self.initial_sync = true;
+                self.restarted = true;
+                self.changes.push_back(DeltaChange::Resync);
+                return Ok(());
+            },
+            Err(err) => return Err(err),
+        };
+
+        if let Some(next_link) = collection.odata_next_link() {
+            self.next = Some(GraphUrl::parse(next_link)?);
+        }
+        if let Some(delta_link) = collection.odata_delta_link() {
+            self.pending_delta_link = Some(delta_link.to_string());
+        }
+        for value in collection.into_inner() {
+            self.changes
+                .push_back(DeltaChange::from_value(value, self.initial_sync)?);
+        }
+        Ok(())
+    }
+}
+
+impl<'a, T> Iterator for DeltaSync<'a, T, BlockingHttpClient>
+where
+    for<'de> T: serde::Deserialize<'de>,
+{
+    type Item = GraphResult<DeltaChange<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(err) = self.error.take() {
+            self.next = None;
+            return Some(Err(err));
+        }
+
+        loop {
+            if let Some(change) = self.changes.pop_front() {
+                return Some(Ok(change));
+            }
+            if !self.set_next_request() {
+                return self.commit_delta_link().err().map(Err);
+            }
+            let response = self.client.request().execute();
+            if let Err(err) = self.add_page(response) {
+                self.next = None;
+                return Some(Err(err));
+            }
+        }
+    }
+}
+
+impl<'a, T> DeltaSync<'a, T, AsyncHttpClient>
+where
+    T: 'a,
+    for<'de> T: serde::Deserialize<'de>,
+{
+    /// A stream of the changes in each page.
+    pub fn stream(self) -> impl Stream<Item = GraphResult<DeltaChange<T>>> + 'a {
+        futures::stream::unfold(self, |mut delta_sync| async move {
+            let change = delta_sync.next_async().await?;
+            Some((change, delta_sync))
+        })
+    }
+
+    async fn next_async(&mut self) -> Option<GraphResult<DeltaChange<T>>> {
+        if let Some(err) = self.error.take() {
+            self.next = None;
+            return Some(Err(err));
+        }
+
+        loop {
+            if let Some(change) = self.changes.pop_front() {
+                return Some(Ok(change));
+            }
+            if !self.set_next_request() {
+                return self.commit_delta_link().err().map(Err);
+            }
+            let response = self.client.request().execute().await;
+            if let Err(err) = self.add_page(response) {
+                self.next = None;
+                return Some(Err(err));
+            }
+        }
+    }
+}
+
+// 410 Gone is returned when a delta link or next link has expired. The
+// resync error codes may also be returned with other status codes.
+fn is_resync(err: &GraphFailure) -> bool {
+    match err {
+        GraphFailure::GraphError(error) => {
+            error.code == 410 ||
+                error
+                    .code_property()
+                    .into_iter()
+                    .chain(error.detailed_error_code())
+                    .any(|code| code.starts_with("resync") || code.starts_with("syncState"))
+        },
+        _ => false,
+    }
+}
@@ -1,17 +1,29 @@
 use crate::graph_error::AsRes;
 use crate::http::{
-    AsyncClient, BlockingClient, GraphRequestType, HttpClient, IoTools, RequestAttribute,
-    RequestClient,
+    AsyncClient, BlockingClient, DownloadHasher, GraphRequestType, HttpClient, IoTools,
+    RequestAttribute, RequestClient,
 };
+use crate::models::Hashes;
 use crate::url::GraphUrl;
+use bytes::Bytes;
+use futures::{stream, Stream, StreamExt};
 use graph_error::{ErrorMessage, GraphError, GraphFailure, GraphResult, GraphRsError};
-use reqwest::header::HeaderMap;
+use reqwest::header::{HeaderMap, HeaderValue, RANGE};
 use reqwest::Method;
 use std::cell::RefCell;
 use std::convert::TryFrom;
 use std::ffi::OsString;
+use std::fs;
+use std::io::{Read, Write};
 use std::path::Path;
 use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWrite};
+
+/// The callback for the progress of a download. The callback is called
+/// with the number of bytes in the file and the size of the file when
+/// the response includes the content length.
+pub type DownloadProgress = Arc<dyn Fn(u64, Option<u64>) + Send + Sync>;
 
 pub struct DownloadRequest {
     path: PathBuf,
@@ -19,6 +31,10 @@ pub struct DownloadRequest {
     overwrite_existing_file: bool,
     file_name: Option<OsString>,
     extension: Option<String>,
+    resume: bool,
+    range: Option<(u64, Option<u64>)>,
+    progress: Option<DownloadProgress>,
+    hashes: Option<Hashes>,
 }
 
 impl DownloadRequest {
@@ -29,6 +45,73 @@ impl DownloadRequest {
             overwrite_existing_file: false,
             file_name: None,
             extension: None,
+            resume: false,
+            range: None,
+            progress: None,
+            hashes: None,
+        }
+    }
+
+    // The file is downloaded to a file with a .part extension added
+    // and renamed once the download is complete.
+    fn part_path(path: &Path) -> PathBuf {
+        let mut part = path.as_os_str().to_os_string();
+        part.push(".part");
+        PathBuf::from(part)
+    }
+
+    // The size of a partially downloaded file when resuming downloads.
+    fn resume_offset(&self, part: &Path) -> u64 {
+        if !self.resume {
+            return 0;
+        }
+        fs::metadata(part).map(|m| m.len()).unwrap_or_default()
+    }
+
+    // The Range header for the bytes that have not been downloaded.
+    // Returns None if the whole range is already in the file.
+    fn range_header(&self, offset: u64) -> Option<HeaderValue> {
+        let range = match self.range {
+            Some((start, Some(end))) if start + offset > end => return None,
+            Some((start, Some(end))) => format!("bytes={}-{}", start + offset, end),
+            Some((start, None)) => format!("bytes={}-", start + offset),
+            None if offset > 0 => format!("bytes={}-", offset),
+            None => return None,
+        };
+        HeaderValue::from_str(range.as_str()).ok()
+    }
+
+    // The path of the file, the .part file that it is downloaded to and
+    // the number of bytes that are already in the .part file.
+    fn part_file(&self, path: PathBuf) -> GraphResult<(PathBuf, PathBuf, u64)> {
+        self.check_existing_file(&path)?;
+        let part = DownloadRequest::part_path(&path);
+        let offset = self.resume_offset(&part);
+        Ok((path, part, offset))
+    }
+
+    fn check_hashes(&self) -> GraphResult<()> {
+        if self.hashes.is_some() && self.range.is_some() {
+            return Err(GraphFailure::invalid(
+                "hashes. The hashes of a file can only be verified when downloading the whole file",
+            ));
+        }
+        Ok(())
+    }
+
+    fn check_existing_file(&self, path: &Path) -> GraphResult<()> {
+        if path.exists() && !self.overwrite_existing_file {
+            return GraphRsError::DownloadFileExists {
+                name: path.to_string_lossy().to_string(),
+            }
+            .as_err_res();
+        }
+        Ok(())
+    }
+
+    fn progress(&self, written: u64, size: Option<u64>) {
+        if let Some(progress) = self.progress.as_ref() {
+            progress(written, size);
         }
     }
 }
@@ -79,6 +162,30 @@ impl<Client, Request> DownloadClient<Client, Request> {
         }
         Ok(())
     }
+
+    // The path of the file using the name set with rename() or, when the
+    // headers of the response are given, the Content-Disposition header.
+    fn file_path(
+        &self,
+        request: &DownloadRequest,
+        headers: Option<&HeaderMap>,
+    ) -> GraphResult<Option<PathBuf>> {
+        let name = match (request.file_name.as_ref(), headers) {
+            (Some(name), _) => name.clone(),
+            (None, Some(headers)) => match self.parse_content_disposition(headers) {
+                Some(name) => name,
+                None => return Ok(None),
+            },
+            (None, None) => return Ok(None),
+        };
+        self.check_file_name_length(&name)?;
+
+        let path = request.path.join(name);
+        if let Some(ext) = request.extension.as_ref() {
+            path.with_extension(ext.as_str());
+        }
+        Ok(Some(path))
+    }
 }
 
 impl BlockingDownload {
@@ -108,6 +215,50 @@ impl BlockingDownload {
         self.request.borrow().overwrite_existing_file
     }
 
+    /// Resume the download of a file that was interrupted. Files are
+    /// downloaded to a file with a .part extension that is renamed when
+    /// the download completes. If the .part file exists the remaining
+    /// bytes are requested using a Range header.
+    ///
+    /// Set the name of the file using `rename()` so that only the Range
+    /// request is sent. Otherwise the name of the file is read from the
+    /// response to a request for the whole file before the Range request
+    /// is sent.
+    pub fn resume(&self, value: bool) -> &Self {
+        self.request.borrow_mut().resume = value;
+        self
+    }
+
+    pub fn is_resume(&self) -> bool {
+        self.request.borrow().resume
+    }
+
+    /// Download only the bytes from start to end, inclusive. If end is
+    /// None the bytes from start to the end of the file are downloaded.
+    pub fn range(&self, start: u64, end: Option<u64>) -> &Self {
+        self.request.borrow_mut().range = Some((start, end));
+        self
+    }
+
+    /// Called with the number of bytes written and the size of the file,
+    /// if known, as the file is downloaded. Use a channel in the callback
+    /// to receive the progress on another thread.
+    pub fn on_progress<F>(&self, progress: F) -> &Self
+    where
+        F: Fn(u64, Option<u64>) + Send + Sync + 'static,
+    {
+        self.request.borrow_mut().progress = Some(Arc::new(progress));
+        self
+    }
+
+    /// Verify the downloaded file using the hashes of the drive item.
+    /// The sha1Hash, sha256Hash and quickXorHash are checked when
+    /// present and an error is returned if any of them do not match.
+    pub fn verify_hashes(&self, hashes: Hashes) -> &Self {
+        self.request.borrow_mut().hashes = Some(hashes);
+        self
+    }
+
     pub fn rename(&self, value: OsString) -> &Self {
         self.request.borrow_mut().file_name = Some(value);
         self
@@ -144,22 +295,78 @@ impl BlockingDownload {
         self.client.url_mut(|url| url.format(format));
     }
 
-    fn check_existing_file(&self, path: &PathBuf) -> GraphResult<()> {
-        if path.exists() && !self.is_overwrite_existing_file() {
-            return GraphRsError::DownloadFileExists {
-                name: path.to_string_lossy().to_string(),
+    fn check_response(
+        response: reqwest::blocking::Response,
+    ) -> GraphResult<reqwest::blocking::Response> {
+        if let Ok(mut error) = GraphError::try_from(&response) {
+            let error_message: GraphResult<ErrorMessage> =
+                response.json().map_err(GraphFailure::from);
+            if let Ok(message) = error_message {
+                error.set_error_message(message);
             }
-            .as_err_res();
+            return Err(GraphFailure::from(error));
         }
-        Ok(())
+        Ok(response)
     }
 
     pub fn send(self) -> GraphResult<PathBuf> {
         self.download()
     }
 
+    /// Write the content of the file to the writer instead of a file
+    /// in the download directory. Returns the number of bytes written.
+    ///
+    /// The hashes set using `verify_hashes()` are checked after all of
+    /// the content is written. Resume is not used when writing to a writer.
+    pub fn write_to<W: Write>(self, writer: &mut W) -> GraphResult<u64> {
+        let request = self.request.borrow();
+        request.check_hashes()?;
+        let response = self.content_response(&request, 0)?;
+
+        let size = response.content_length();
+        let mut hasher = request.hashes.clone().map(DownloadHasher::new);
+        let mut written = 0;
+        request.progress(written, size);
+        IoTools::write_to(writer, response, |bytes| {
+            if let Some(hasher) = hasher.as_mut() {
+                hasher.update(bytes);
+            }
+            written += bytes.len() as u64;
+            request.progress(written, size);
+        })?;
+
+        if let Some(hasher) = hasher {
+            hasher.verify()?;
+        }
+        Ok(written)
+    }
+
+    // Sends the request for the content of the file starting from offset.
+    // The redirect to the content of a download is followed by the client
+    // and the Range header is sent with the first request so that the whole
+    // file is never requested before the range. A 416 response to a request
+    // with an offset is returned as it is.
+    fn content_response(
+        &self,
+        request: &DownloadRequest,
+        offset: u64,
+    ) -> GraphResult<reqwest::blocking::Response> {
+        if let Some(range) = request.range_header(offset) {
+            let mut headers = self.client.header_map();
+            headers.insert(RANGE, range);
+            self.client.set_header_map(headers);
+        }
+
+        let response = self.client.response()?;
+        if offset > 0 && response.status().as_u16() == 416 {
+            return Ok(response);
+        }
+        BlockingDownload::check_response(response)
+    }
+
     fn download(self) -> GraphResult<PathBuf> {
         let request = self.request.borrow();
+        request.check_hashes()?;
 
         // Create the directory if it does not exist.
         if request.create_dir_all.eq(&true) {
@@ -169,54 +376,89 @@ impl BlockingDownload {
             return GraphRsError::DownloadDirNoExists { dir }.as_err_res();
         }
 
-        if self.client.request_type().eq(&GraphRequestType::Redirect) {
-            let response = self.client.build().send()?;
-
-            if let Ok(mut error) = GraphError::try_from(&response) {
-                let error_message: GraphResult<ErrorMessage> =
-                    response.json().map_err(GraphFailure::from);
-                if let Ok(message) = error_message {
-                    error.set_error_message(message);
-                }
-                return Err(GraphFailure::from(error));
+        // When the name of the file is known the Range request for the rest
+        // of a partial download is the first request that is sent.
+        let file = match self.file_path(&request, None)? {
+            Some(path) => Some(request.part_file(path)?),
+            None => None,
+        };
+        if let Some((path, part, offset)) = file.as_ref() {
+            if *offset > 0 && request.range_header(*offset).is_none() {
+                return BlockingDownload::complete(&request, part.clone(), path.clone());
             }
-
-            self.client.set_request(vec![
-                RequestAttribute::ClearHeaders,
-                RequestAttribute::Method(Method::GET),
-                RequestAttribute::RequestType(GraphRequestType::Basic),
-                RequestAttribute::Url(GraphUrl::from(response.url().clone())),
-            ])?;
         }
 
-        let response = self.client.build().send()?;
-        if let Ok(mut error) = GraphError::try_from(&response) {
-            let error_message: GraphResult<ErrorMessage> =
-                response.json().map_err(GraphFailure::from);
-            if let Ok(message) = error_message {
-                error.set_error_message(message);
+        let offset = file.as_ref().map(|(_, _, offset)| *offset).unwrap_or(0);
+        let mut response = self.content_response(&request, offset)?;
+
+        // Otherwise the name is read from the response and the Range request
+        // is sent once the size of the .part file is known.
+        let (path, part, mut offset) = match file {
+            Some(file) => file,
+            None => {
+                let path = self
+                    .file_path(&request, Some(response.headers()))?
+                    .ok_or_else(|| GraphFailure::from(GraphRsError::DownloadFileName))?;
+                let (path, part, offset) = request.part_file(path)?;
+                if offset > 0 {
+                    if request.range_header(offset).is_none() {
+                        return BlockingDownload::complete(&request, part, path);
+                    }
+                    let url = GraphUrl::from(response.url().clone());
+                    self.client.set_request(vec![
+                        RequestAttribute::ClearHeaders,
+                        RequestAttribute::Method(Method::GET),
+                        RequestAttribute::RequestType(GraphRequestType::Basic),
+                        RequestAttribute::Url(url),
+                    ])?;
+                    response = self.content_response(&request, offset)?;
+                }
+                (path, part, offset)
+            },
+        };
+
+        if offset > 0 {
+            // The .part file already has all of the bytes of the file.
+            if response.status().as_u16() == 416 {
+                return BlockingDownload::complete(&request, part, path);
+            }
+            // The whole file was sent instead of the remaining bytes.
+            if response.status().as_u16() != 206 {
+                offset = 0;
             }
-            return Err(GraphFailure::from(error));
         }
 
-        let path = {
-            if let Some(name) = request.file_name.as_ref() {
-                self.check_file_name_length(name)?;
-                request.path.join(name)
-            } else if let Some(name) = self.parse_content_disposition(response.headers()) {
-                self.check_file_name_length(&name)?;
-                request.path.join(name)
-            } else {
-                return GraphRsError::DownloadFileName.as_err_res();
-            }
-        };
+        let size = response.content_length().map(|len| len + offset);
+        let mut written = offset;
+        request.progress(written, size);
+        IoTools::copy_to(part.as_path(), response, offset > 0, |bytes| {
+            written += bytes.len() as u64;
+            request.progress(written, size);
+        })?;
+        BlockingDownload::complete(&request, part, path)
+    }
 
-        if let Some(ext) = request.extension.as_ref() {
-            path.with_extension(ext.as_str());
+    // Renames the .part file and verifies the hashes of the file.
+    fn complete(request: &DownloadRequest, part: PathBuf, path: PathBuf) -> GraphResult<PathBuf> {
+        if path.exists() {
+            fs::remove_file(&path)?;
         }
-
-        self.check_existing_file(&path)?;
-        IoTools::copy(path, response)
+        fs::rename(&part, &path)?;
+
+        if let Some(hashes) = request.hashes.as_ref() {
+            let mut hasher = DownloadHasher::new(hashes.clone());
+            let mut file = fs::File::open(&path)?;
+            let mut buf = vec![0; 64 * 1024];
+            loop {
+                let len = file.read(&mut buf)?;
+                if len == 0 {
+                    break;
+                }
+                hasher.update(&buf[..len]);
+            }
+            hasher.verify()?;
+        }
+        Ok(path)
     }
 }
 
@@ -247,6 +489,50 @@ impl AsyncDownload {
         self.request.lock().await.overwrite_existing_file
     }
 
+    /// Resume the download of a file that was interrupted. Files are
+    /// downloaded to a file with a .part extension that is renamed when
+    /// the download completes. If the .part file exists the remaining
+    /// bytes are requested using a Range header.
+    ///
+    /// Set the name of the file using `rename()` so that only the Range
+    /// request is sent. Otherwise the name of the file is read from the
+    /// response to a request for the whole file before the Range request
+    /// is sent.
+    pub async fn resume(&self, value: bool) -> &Self {
+        self.request.lock().await.resume = value;
+        self
+    }
+
+    pub async fn is_resume(&self) -> bool {
+        self.request.lock().await.resume
+    }
+
+    /// Download only the bytes from start to end, inclusive. If end is
+    /// None the bytes from start to the end of the file are downloaded.
+    pub async fn range(&self, start: u64, end: Option<u64>) -> &Self {
+        self.request.lock().await.range = Some((start, end));
+        self
+    }
+
+    /// Called with the number of bytes written and the size of the file,
+    /// if known, as the file is downloaded. Use a channel in the callback
+    /// to receive the progress in another task.
+    pub async fn on_progress<F>(&self, progress: F) -> &Self
+    where
+        F: Fn(u64, Option<u64>) + Send + Sync + 'static,
+    {
+        self.request.lock().await.progress = Some(Arc::new(progress));
+        self
+    }
+
+    /// Verify the downloaded file using the hashes of the drive item.
+    /// The sha1Hash, sha256Hash and quickXorHash are checked when
+    /// present and an error is returned if any of them do not match.
+    pub async fn verify_hashes(&self, hashes: Hashes) -> &Self {
+        self.request.lock().await.hashes = Some(hashes);
+        self
+    }
+
     pub async fn rename(&self, value: OsString) -> &Self {
         self.request.lock().await.file_name = Some(value);
         self
@@ -285,22 +571,112 @@ impl AsyncDownload {
         });
     }
 
-    async fn check_existing_file(&self, path: &PathBuf) -> GraphResult<()> {
-        if path.exists() && !self.is_overwrite_existing_file().await {
-            return GraphRsError::DownloadFileExists {
-                name: path.to_string_lossy().to_string(),
+    async fn check_response(response: reqwest::Response) -> GraphResult<reqwest::Response> {
+        if let Ok(mut error) = GraphError::try_from(&response) {
+            let error_message: GraphResult<ErrorMessage> =
+                response.json().await.map_err(GraphFailure::from);
+            if let Ok(message) = error_message {
+                error.set_error_message(message);
             }
-            .as_err_res();
+            return Err(GraphFailure::from(error));
         }
-        Ok(())
+        Ok(response)
     }
 
     pub async fn send(self) -> GraphResult<PathBuf> {
         self.download_async().await
     }
 
+    /// Write the content of the file to the writer instead of a file
+    /// in the download directory. Returns the number of bytes written.
+    ///
+    /// The hashes set using `verify_hashes()` are checked after all of
+    /// the content is written. Resume is not used when writing to a writer.
+    pub async fn write_to<W: AsyncWrite + Unpin>(self, writer: &mut W) -> GraphResult<u64> {
+        let request = self.request.lock().await;
+        request.check_hashes()?;
+        let response = self.content_response(&request, 0).await?;
+
+        let size = response.content_length();
+        let mut hasher = request.hashes.clone().map(DownloadHasher::new);
+        let mut written = 0;
+        request.progress(written, size);
+        IoTools::write_to_async(writer, response, |bytes| {
+            if let Some(hasher) = hasher.as_mut() {
+                hasher.update(bytes);
+            }
+            written += bytes.len() as u64;
+            request.progress(written, size);
+        })
+        .await?;
+
+        if let Some(hasher) = hasher {
+            hasher.verify()?;
+        }
+        Ok(written)
+    }
+
+    /// A stream of the content of the file. The request for the content
+    /// is sent, and any error response returned, before the stream is
+    /// returned.
+    ///
+    /// The hashes set using `verify_hashes()` are computed as the content
+    /// is streamed. If the hashes do not match, an error is the last item
+    /// of the stream.
+    pub async fn stream(self) -> GraphResult<impl Stream<Item = GraphResult<Bytes>>> {
+        let request = self.request.lock().await;
+        request.check_hashes()?;
+        let response = self.content_response(&request, 0).await?;
+
+        let hasher = request.hashes.clone().map(DownloadHasher::new);
+        let bytes_stream = Box::pin(response.bytes_stream());
+        Ok(stream::unfold(
+            (bytes_stream, hasher),
+            |(mut stream, mut hasher)| async move {
+                match stream.next().await {
+                    Some(Ok(bytes)) => {
+                        if let Some(hasher) = hasher.as_mut() {
+                            hasher.update(&bytes);
+                        }
+                        Some((Ok(bytes), (stream, hasher)))
+                    },
+                    Some(Err(err)) => Some((Err(GraphFailure::from(err)), (stream, None))),
+                    None => match hasher?.verify() {
+                        Ok(()) => None,
+                        Err(err) => Some((Err(err), (stream, None))),
+                    },
+                }
+            },
+        ))
+    }
+
+    // Sends the request for the content of the file starting from offset.
+    // The redirect to the content of a download is followed by the client
+    // and the Range header is sent with the first request so that the whole
+    // file is never requested before the range. A 416 response to a request
+    // with an offset is returned as it is.
+    async fn content_response(
+        &self,
+        request: &DownloadRequest,
+        offset: u64,
+    ) -> GraphResult<reqwest::Response> {
+        if let Some(range) = request.range_header(offset) {
+            let mut headers = self.client.header_map();
+            headers.insert(RANGE, range);
+            self.client.set_header_map(headers);
+        }
+
+        let response = self.client.response().await?;
+        if offset > 0 && response.status().as_u16() == 416 {
+            return Ok(response);
+        }
+        AsyncDownload::check_response(response).await
+    }
+
     async fn download_async(self) -> GraphResult<PathBuf> {
         let request = self.request.lock().await;
+        request.check_hashes()?;
+
         // Create the directory if it does not exist.
         if request.create_dir_all.eq(&true) {
             IoTools::create_dir_async(request.path.as_path()).await?;
@@ -309,52 +685,93 @@ impl AsyncDownload {
             return GraphRsError::DownloadDirNoExists { dir }.as_err_res();
         }
 
-        if self.client.request_type().eq(&GraphRequestType::Redirect) {
-            let response = self.client.build().await.send().await?;
-            if let Ok(mut error) = GraphError::try_from(&response) {
-                let error_message: GraphResult<ErrorMessage> =
-                    response.json().await.map_err(GraphFailure::from);
-                if let Ok(message) = error_message {
-                    error.set_error_message(message);
-                }
-                return Err(GraphFailure::from(error));
+        // When the name of the file is known the Range request for the rest
+        // of a partial download is the first request that is sent.
+        let file = match self.file_path(&request, None)? {
+            Some(path) => Some(request.part_file(path)?),
+            None => None,
+        };
+        if let Some((path, part, offset)) = file.as_ref() {
+            if *offset > 0 && request.range_header(*offset).is_none() {
+                return AsyncDownload::complete(&request, part.clone(), path.clone()).await;
             }
-
-            self.client.set_request(vec![
-                RequestAttribute::ClearHeaders,
-                RequestAttribute::Method(Method::GET),
-                RequestAttribute::RequestType(GraphRequestType::Basic),
-                RequestAttribute::Url(GraphUrl::from(response.url().clone())),
-            ])?;
         }
 
-        let response = self.client.build().await.send().await?;
-        if let Ok(mut error) = GraphError::try_from(&response) {
-            let error_message: GraphResult<ErrorMessage> =
-                response.json().await.map_err(GraphFailure::from);
-            if let Ok(message) = error_message {
-                error.set_error_message(message);
+        let offset = file.as_ref().map(|(_, _, offset)| *offset).unwrap_or(0);
+        let mut response = self.content_response(&request, offset).await?;
+
+        // Otherwise the name is read from the response and the Range request
+        // is sent once the size of the .part file is known.
+        let (path, part, mut offset) = match file {
+            Some(file) => file,
+            None => {
+                let path = self
+                    .file_path(&request, Some(response.headers()))?
+                    .ok_or_else(|| GraphFailure::from(GraphRsError::DownloadFileName))?;
+                let (path, part, offset) = request.part_file(path)?;
+                if offset > 0 {
+                    if request.range_header(offset).is_none() {
+                        return AsyncDownload::complete(&request, part, path).await;
+                    }
+                    let url = GraphUrl::from(response.url().clone());
+                    self.client.set_request(vec![
+                        RequestAttribute::ClearHeaders,
+                        RequestAttribute::Method(Method::GET),
+                        RequestAttribute::RequestType(GraphRequestType::Basic),
+                        RequestAttribute::Url(url),
+                    ])?;
+                    response = self.content_response(&request, offset).await?;
+                }
+                (path, part, offset)
+            },
+        };
+
+        if offset > 0 {
+            // The .part file already has all of the bytes of the file.
+            if response.status().as_u16() == 416 {
+                return AsyncDownload::complete(&request, part, path).await;
+            }
+            // The whole file was sent instead of the remaining bytes.
+            if response.status().as_u16() != 206 {
+                offset = 0;
             }
-            return Err(GraphFailure::from(error));
         }
 
-        let path = {
-            if let Some(name) = request.file_name.as_ref() {
-                self.check_file_name_length(name)?;
-                request.path.join(name)
-            } else if let Some(name) = self.parse_content_disposition(response.headers()) {
-                self.check_file_name_length(&name)?;
-                request.path.join(name)
-            } else {
-                return GraphRsError::DownloadFileName.as_err_res();
+        let size = response.content_length().map(|len| len + offset);
+        let mut written = offset;
+        request.progress(written, size);
+        IoTools::copy_to_async(part.as_path(), response, offset > 0, |bytes| {
+            written += bytes.len() as u64;
+            request.progress(written, size);
+        })
+        .await?;
+        AsyncDownload::complete(&request, part, path).await
+    }
+
+    // Renames the .part file and verifies the hashes of the file.
+    async fn complete(
+        request: &DownloadRequest,
+        part: PathBuf,
+        path: PathBuf,
+    ) -> GraphResult<PathBuf> {
+        if path.exists() {
+            tokio::fs::remove_file(&path).await?;
+        }
+        tokio::fs::rename(&part, &path).await?;
+
+        if let Some(hashes) = request.hashes.as_ref() {
+            let mut hasher = DownloadHasher::new(hashes.clone());
+            let mut file = tokio::fs::File::open(&path).await?;
+            let mut buf = vec![0; 64 * 1024];
+            loop {
+                let len = file.read(&mut buf).await?;
+                if len == 0 {
+                    break;
+                }
+                hasher.update(&buf[..len]);
             }
-        };
-
-        if let Some(ext) = request.extension.as_ref() {
-            path.with_extension(ext.as_str());
+            hasher.verify()?;
         }
-
-        self.check_existing_file(&path).await?;
-        IoTools::copy_async(path, response).await
+        Ok(path)
     }
 }
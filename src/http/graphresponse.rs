@@ -65,6 +65,17 @@ impl<T> GraphResponse<T> {
     }
 }
 
+impl GraphResponse<serde_json::Value> {
+    /// Deserialize the JSON body of the response into T.
+    pub fn deserialize_body<T>(self) -> GraphResult<GraphResponse<T>>
+    where
+        for<'de> T: serde::Deserialize<'de>,
+    {
+        let body: T = serde_json::from_value(self.body)?;
+        Ok(GraphResponse::new(body, self.status, self.headers))
+    }
+}
+
 impl<T> AsRef<T> for GraphResponse<T> {
     fn as_ref(&self) -> &T {
         &self.body
@@ -0,0 +1,165 @@
+use crate::graph_error::AsRes;
+use crate::models::Hashes;
+use graph_error::{GraphResult, GraphRsError};
+use sha1::{Digest, Sha1};
+
+const WIDTH_IN_BITS: usize = 160;
+const SHIFT: usize = 11;
+const BITS_IN_LAST_CELL: usize = 32;
+
+/// The quickXorHash used by OneDrive for Business and SharePoint to
+/// check the content of a file.
+///
+/// # See
+/// [QuickXorHash](https://docs.microsoft.com/en-us/onedrive/developer/code-snippets/quickxorhash)
+///
+/// # Example
+/// ```
+/// # use graph_rs::http::QuickXorHash;
+/// let mut hash = QuickXorHash::new();
+/// hash.update(b"");
+/// assert_eq!(hash.base64(), "AAAAAAAAAAAAAAAAAAAAAAAAAAA=");
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct QuickXorHash {
+    data: [u64; 3],
+    length: u64,
+    shift: usize,
+}
+
+impl QuickXorHash {
+    pub fn new() -> QuickXorHash {
+        QuickXorHash::default()
+    }
+
+    pub fn update(&mut self, bytes: &[u8]) {
+        let mut index = self.shift / 64;
+        let mut offset = self.shift % 64;
+        let iterations = bytes.len().min(WIDTH_IN_BITS);
+
+        for i in 0..iterations {
+            let is_last_cell = index == self.data.len() - 1;
+            let bits_in_cell = if is_last_cell { BITS_IN_LAST_CELL } else { 64 };
+
+            if offset <= bits_in_cell - 8 {
+                for byte in bytes.iter().skip(i).step_by(WIDTH_IN_BITS) {
+                    self.data[index] ^= u64::from(*byte) << offset;
+                }
+            } else {
+                let next = if is_last_cell { 0 } else { index + 1 };
+                let low = bits_in_cell - offset;
+                let xored = bytes
+                    .iter()
+                    .skip(i)
+                    .step_by(WIDTH_IN_BITS)
+                    .fold(0u8, |xored, byte| xored ^ *byte);
+                self.data[index] ^= u64::from(xored) << offset;
+                self.data[next] ^= u64::from(xored) >> low;
+            }
+
+            offset += SHIFT;
+            while offset >= bits_in_cell {
+                index = if is_last_cell { 0 } else { index + 1 };
+                offset -= bits_in_cell;
+            }
+        }
+
+        self.shift = (self.shift + SHIFT * (bytes.len() % WIDTH_IN_BITS)) % WIDTH_IN_BITS;
+        self.length += bytes.len() as u64;
+    }
+
+    pub fn finalize(&self) -> Vec<u8> {
+        let mut hash = Vec::with_capacity(WIDTH_IN_BITS / 8);
+        hash.extend_from_slice(&self.data[0].to_le_bytes());
+        hash.extend_from_slice(&self.data[1].to_le_bytes());
+        hash.extend_from_slice(&self.data[2].to_le_bytes()[..4]);
+
+        // The length of the content is xored with the last 8 bytes.
+        let start = WIDTH_IN_BITS / 8 - 8;
+        for (i, byte) in self.length.to_le_bytes().iter().enumerate() {
+            hash[start + i] ^= byte;
+        }
+        hash
+    }
+
+    /// The base64 encoded hash which is the format used by the
+    /// quickXorHash of a drive item.
+    pub fn base64(&self) -> String {
+        base64::encode(&self.finalize())
+    }
+}
+
+// Computes the hashes of a downloaded file that the drive item has
+// so they can be compared after the download completes.
+pub(crate) struct DownloadHasher {
+    hashes: Hashes,
+    sha1: Option<Sha1>,
+    sha256: Option<ring::digest::Context>,
+    quick_xor: Option<QuickXorHash>,
+}
+
+impl DownloadHasher {
+    pub fn new(hashes: Hashes) -> DownloadHasher {
+        DownloadHasher {
+            sha1: hashes.sha1_hash().as_ref().map(|_| Sha1::new()),
+            sha256: hashes
+                .sha256_hash()
+                .as_ref()
+                .map(|_| ring::digest::Context::new(&ring::digest::SHA256)),
+            quick_xor: hashes
+                .quick_xor_hash()
+                .as_ref()
+                .map(|_| QuickXorHash::new()),
+            hashes,
+        }
+    }
+
+    pub fn update(&mut self, bytes: &[u8]) {
+        if let Some(sha1) = self.sha1.as_mut() {
+            sha1.update(bytes);
+        }
+        if let Some(sha256) = self.sha256.as_mut() {
+            sha256.update(bytes);
+        }
+        if let Some(quick_xor) = self.quick_xor.as_mut() {
+            quick_xor.update(bytes);
+        }
+    }
+
+    pub fn verify(self) -> GraphResult<()> {
+        if let (Some(expected), Some(sha1)) = (self.hashes.sha1_hash(), self.sha1) {
+            DownloadHasher::compare("sha1Hash", expected, &hex(&sha1.finalize()))?;
+        }
+        if let (Some(expected), Some(sha256)) = (self.hashes.sha256_hash(), self.sha256) {
+            DownloadHasher::compare("sha256Hash", expected, &hex(sha256.finish().as_ref()))?;
+        }
+        if let (Some(expected), Some(quick_xor)) = (self.hashes.quick_xor_hash(), self.quick_xor) {
+            if expected.ne(&quick_xor.base64()) {
+                return GraphRsError::DownloadHashMismatch {
+                    hash: "quickXorHash".into(),
+                    expected: expected.to_string(),
+                    found: quick_xor.base64(),
+                }
+                .as_err_res();
+            }
+        }
+        Ok(())
+    }
+
+    // The sha1 and sha256 hashes of a drive item are upper case hex.
+    fn compare(hash: &str, expected: &str, found: &str) -> GraphResult<()> {
+        if !expected.eq_ignore_ascii_case(found) {
+            return GraphRsError::DownloadHashMismatch {
+                hash: hash.into(),
+                expected: expected.to_string(),
+                found: found.to_string(),
+            }
+            .as_err_res();
+        }
+        Ok(())
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02X}", byte)).collect()
+}
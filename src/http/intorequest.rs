@@ -1,16 +1,16 @@
 use crate::http::{
-    AsyncHttpClient, AsyncTryFrom, BlockingHttpClient, GraphResponse, UploadSessionClient,
+    AsyncHttpClient, AsyncTryFrom, BlockingHttpClient, GraphResponse, UploadContent,
+    UploadSessionClient,
 };
 use crate::types::content::Content;
 use graph_error::{ErrorMessage, GraphError, GraphFailure, GraphResult};
 use std::convert::TryFrom;
 use std::marker::PhantomData;
-use std::path::PathBuf;
 
 pub struct IntoRequest<T, Builder> {
     client: Builder,
     ident: PhantomData<T>,
-    file: Option<PathBuf>,
+    content: Option<UploadContent>,
     error: Option<GraphFailure>,
 }
 
@@ -20,13 +20,13 @@ pub type IntoReqAsync<T> = IntoRequest<T, reqwest::RequestBuilder>;
 impl<T> IntoReqBlocking<T> {
     pub fn new(
         req: reqwest::blocking::RequestBuilder,
-        file: Option<PathBuf>,
+        content: Option<UploadContent>,
         error: Option<GraphFailure>,
     ) -> IntoReqBlocking<T> {
         IntoReqBlocking {
             client: req,
             ident: Default::default(),
-            file,
+            content,
             error,
         }
     }
@@ -74,9 +74,9 @@ impl IntoReqBlocking<UploadSessionClient<BlockingHttpClient>> {
             return Err(self.error.unwrap_or_default());
         }
 
-        let file = self
-            .file
-            .ok_or_else(|| GraphFailure::invalid("file for upload session"))?;
+        let content = self
+            .content
+            .ok_or_else(|| GraphFailure::invalid("content for upload session"))?;
 
         let response = self.client.send()?;
         if let Ok(mut error) = GraphError::try_from(&response) {
@@ -90,7 +90,7 @@ impl IntoReqBlocking<UploadSessionClient<BlockingHttpClient>> {
 
         let upload_session: serde_json::Value = response.json()?;
         let mut session = UploadSessionClient::new(upload_session)?;
-        session.set_file(file)?;
+        session.set_content(content)?;
         Ok(session)
     }
 }
@@ -100,13 +100,13 @@ impl IntoReqBlocking<UploadSessionClient<BlockingHttpClient>> {
 impl<T> IntoReqAsync<T> {
     pub fn new(
         req: reqwest::RequestBuilder,
-        file: Option<PathBuf>,
+        content: Option<UploadContent>,
         error: Option<GraphFailure>,
     ) -> IntoReqAsync<T> {
         IntoReqAsync {
             client: req,
             ident: Default::default(),
-            file,
+            content,
             error,
         }
     }
@@ -154,9 +154,9 @@ impl IntoReqAsync<UploadSessionClient<AsyncHttpClient>> {
             return Err(self.error.unwrap_or_default());
         }
 
-        let file = self
-            .file
-            .ok_or_else(|| GraphFailure::invalid("file for upload session"))?;
+        let content = self
+            .content
+            .ok_or_else(|| GraphFailure::invalid("content for upload session"))?;
 
         let response = self.client.send().await?;
         if let Ok(mut error) = GraphError::try_from(&response) {
@@ -170,7 +170,7 @@ impl IntoReqAsync<UploadSessionClient<AsyncHttpClient>> {
 
         let upload_session: serde_json::Value = response.json().await?;
         let mut session = UploadSessionClient::new_async(upload_session)?;
-        session.set_file_async(file).await?;
+        session.set_content_async(content).await?;
         Ok(session)
     }
 }
@@ -1,14 +1,17 @@
 use crate::client::*;
 use crate::http::{
-    AsyncHttpClient, AsyncTryFrom, BlockingHttpClient, GraphResponse, IntoDeltaRequest,
-    IntoReqAsync, IntoReqBlocking, RequestClient, UploadSessionClient,
+    AsyncHttpClient, AsyncTryFrom, BatchStep, BlockingHttpClient, DeltaStore, DeltaSync,
+    GraphResponse, IntoDeltaRequest, IntoReqAsync, IntoReqBlocking, Paged, RequestClient,
+    UploadSessionClient,
 };
 use crate::types::delta::{Delta, NextLink};
-use crate::types::{content::Content, delta::DeltaRequest};
+use crate::types::upload::UploadRequest;
+use crate::types::{collection::Collection, content::Content, delta::DeltaRequest};
 use graph_error::{GraphFailure, GraphResult};
 use reqwest::header::{HeaderValue, IntoHeaderName};
 use std::marker::PhantomData;
 use std::sync::mpsc::Receiver;
+use std::sync::Arc;
 
 pub struct IntoResponse<'a, T, Client>
 where
@@ -112,6 +115,53 @@ where
         self.client.request().header(name, value);
         self
     }
+
+    pub(crate) fn into_batch_step(self, id: &str) -> GraphResult<BatchStep> {
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+        BatchStep::from_request(id, self.client.request())
+    }
+}
+
+impl<'a, T, Client> IntoResponse<'a, Collection<T>, Client>
+where
+    Client: RequestClient,
+{
+    /// Get the items of the collection and each page after it by
+    /// following the @odata.nextLink of each page.
+    /// See [Paged](struct.Paged.html).
+    pub fn paged(self) -> Paged<'a, T, Client> {
+        Paged::new(self.client, self.error)
+    }
+}
+
+impl<'a, T, Client> IntoResponse<'a, DeltaRequest<Collection<T>>, Client>
+where
+    Client: RequestClient,
+{
+    /// Sync the resource using the delta link stored under the key
+    /// and write the latest delta link back to the store.
+    /// See [DeltaSync](struct.DeltaSync.html).
+    pub fn sync(self, key: &str, store: Arc<dyn DeltaStore>) -> DeltaSync<'a, T, Client> {
+        DeltaSync::new(self.client, key, store, self.error)
+    }
+}
+
+// Requests that are built are sent by the caller instead of the client so
+// the token is set from the token provider before the request is built.
+fn set_provider_token(client: &BlockingHttpClient) -> GraphResult<()> {
+    if let Some(token_provider) = client.token_provider() {
+        client.set_token(token_provider.bearer_token()?.as_str());
+    }
+    Ok(())
+}
+
+async fn set_provider_token_async(client: &AsyncHttpClient) -> GraphResult<()> {
+    if let Some(token_provider) = client.token_provider() {
+        client.set_token(token_provider.bearer_token_async().await?.as_str());
+    }
+    Ok(())
 }
 
 impl<'a, T> IntoResBlocking<'a, T> {
@@ -132,8 +182,9 @@ where
     for<'de> T: serde::Deserialize<'de>,
 {
     pub fn build(self) -> IntoReqBlocking<T> {
-        let builder = self.client.request().build();
-        IntoReqBlocking::new(builder, None, self.error)
+        let client = self.client.request();
+        let error = self.error.or_else(|| set_provider_token(client).err());
+        IntoReqBlocking::new(client.build(), None, error)
     }
 
     pub fn send(self) -> GraphResult<GraphResponse<T>> {
@@ -146,8 +197,10 @@ where
 
 impl<'a> IntoResBlocking<'a, UploadSessionClient<BlockingHttpClient>> {
     pub fn build(self) -> IntoReqBlocking<UploadSessionClient<BlockingHttpClient>> {
-        let (file, builder) = self.client.request().build_upload_session();
-        IntoReqBlocking::new(builder, file, self.error)
+        let client = self.client.request();
+        let error = self.error.or_else(|| set_provider_token(client).err());
+        let (content, builder) = client.build_upload_session();
+        IntoReqBlocking::new(builder, content, error)
     }
 
     pub fn send(self) -> GraphResult<UploadSessionClient<BlockingHttpClient>> {
@@ -158,10 +211,23 @@ impl<'a> IntoResBlocking<'a, UploadSessionClient<BlockingHttpClient>> {
     }
 }
 
+impl<'a, T> IntoResBlocking<'a, UploadRequest<T>>
+where
+    for<'de> T: serde::Deserialize<'de>,
+{
+    pub fn send(self) -> GraphResult<GraphResponse<T>> {
+        if self.error.is_some() {
+            return Err(self.error.unwrap_or_default());
+        }
+        self.client.request().upload()
+    }
+}
+
 impl<'a> IntoResBlocking<'a, GraphResponse<Content>> {
     pub fn build(self) -> IntoReqBlocking<GraphResponse<Content>> {
-        let builder = self.client.request().build();
-        IntoReqBlocking::new(builder, None, self.error)
+        let client = self.client.request();
+        let error = self.error.or_else(|| set_provider_token(client).err());
+        IntoReqBlocking::new(client.build(), None, error)
     }
 
     pub fn send(self) -> GraphResult<GraphResponse<Content>> {
@@ -177,11 +243,18 @@ impl<'a, T: 'static + Send + NextLink + Clone> IntoResBlocking<'a, DeltaRequest<
 where
     for<'de> T: serde::Deserialize<'de>,
 {
-    pub fn build(self) -> IntoDeltaRequest<T, reqwest::blocking::RequestBuilder> {
+    pub fn build(
+        self,
+    ) -> IntoDeltaRequest<T, reqwest::blocking::RequestBuilder, reqwest::blocking::Client> {
         let client = self.client.request();
-        let builder = client.build();
+        let token_provider = client.token_provider();
+        let builder = client.build_request();
         let token = client.token();
-        IntoDeltaRequest::<T, reqwest::blocking::RequestBuilder>::new(token, builder, self.error)
+        IntoDeltaRequest::new(token, builder, self.error)
+            .retry_policy(client.retry_policy())
+            .pipeline(client.pipeline())
+            .token_provider(token_provider)
+            .http_client(client.http_client())
     }
 
     pub fn send(self) -> Receiver<Delta<T>> {
@@ -200,8 +273,7 @@ impl<'a, T> IntoResAsync<'a, T> {
         if self.error.is_some() {
             return Err(self.error.unwrap_or_default());
         }
-        let request = self.client.request().build().await;
-        let response = request.send().await?;
+        let response = self.client.request().response().await?;
         response.json().await.map_err(GraphFailure::from)
     }
 }
@@ -211,8 +283,12 @@ where
     for<'de> T: serde::Deserialize<'de>,
 {
     pub async fn build(self) -> IntoReqAsync<T> {
-        let builder = self.client.request().build().await;
-        IntoReqAsync::new(builder, None, self.error)
+        let client = self.client.request();
+        let error = match self.error {
+            Some(err) => Some(err),
+            None => set_provider_token_async(client).await.err(),
+        };
+        IntoReqAsync::new(client.build().await, None, error)
     }
 
     pub async fn send(self) -> GraphResult<GraphResponse<T>> {
@@ -226,8 +302,12 @@ where
 
 impl<'a> IntoResAsync<'a, GraphResponse<Content>> {
     pub async fn build(self) -> IntoReqAsync<GraphResponse<Content>> {
-        let builder = self.client.request().build().await;
-        IntoReqAsync::new(builder, None, self.error)
+        let client = self.client.request();
+        let error = match self.error {
+            Some(err) => Some(err),
+            None => set_provider_token_async(client).await.err(),
+        };
+        IntoReqAsync::new(client.build().await, None, error)
     }
 
     pub async fn send(self) -> GraphResult<GraphResponse<Content>> {
@@ -241,8 +321,13 @@ impl<'a> IntoResAsync<'a, GraphResponse<Content>> {
 
 impl<'a> IntoResAsync<'a, UploadSessionClient<AsyncHttpClient>> {
     pub async fn build(self) -> IntoReqAsync<UploadSessionClient<AsyncHttpClient>> {
-        let (file, builder) = self.client.request().build_upload_session().await;
-        IntoReqAsync::new(builder, file, self.error)
+        let client = self.client.request();
+        let error = match self.error {
+            Some(err) => Some(err),
+            None => set_provider_token_async(client).await.err(),
+        };
+        let (content, builder) = client.build_upload_session().await;
+        IntoReqAsync::new(builder, content, error)
     }
 
     pub async fn send(self) -> GraphResult<UploadSessionClient<AsyncHttpClient>> {
@@ -253,15 +338,32 @@ impl<'a> IntoResAsync<'a, UploadSessionClient<AsyncHttpClient>> {
     }
 }
 
+impl<'a, T> IntoResAsync<'a, UploadRequest<T>>
+where
+    for<'de> T: serde::Deserialize<'de>,
+{
+    pub async fn send(self) -> GraphResult<GraphResponse<T>> {
+        if self.error.is_some() {
+            return Err(self.error.unwrap_or_default());
+        }
+        self.client.request().upload().await
+    }
+}
+
 impl<'a, T: 'static + Send + NextLink + Clone> IntoResAsync<'a, DeltaRequest<T>>
 where
     for<'de> T: serde::Deserialize<'de>,
 {
-    pub async fn build(self) -> IntoDeltaRequest<T, reqwest::RequestBuilder> {
+    pub async fn build(self) -> IntoDeltaRequest<T, reqwest::RequestBuilder, reqwest::Client> {
         let client = self.client.request();
-        let builder = client.build().await;
+        let token_provider = client.token_provider();
+        let builder = client.build_request().await;
         let token = client.token();
-        IntoDeltaRequest::<T, reqwest::RequestBuilder>::new(token, builder, self.error)
+        IntoDeltaRequest::new(token, builder, self.error)
+            .retry_policy(client.retry_policy())
+            .pipeline(client.pipeline())
+            .token_provider(token_provider)
+            .http_client(client.http_client().await)
     }
 
     pub async fn send(self) -> tokio::sync::mpsc::Receiver<Delta<T>> {
@@ -1,10 +1,9 @@
 use async_std::prelude::*;
-use graph_error::{GraphFailure, GraphResult};
+use graph_error::GraphResult;
+use std::fs;
 use std::fs::OpenOptions;
-use std::io::copy;
-use std::path::{Path, PathBuf};
-use std::sync::mpsc;
-use std::{fs, thread};
+use std::io::{Read, Write};
+use std::path::Path;
 use tokio::prelude::*;
 
 pub struct IoTools;
@@ -24,39 +23,86 @@ impl IoTools {
         Ok(())
     }
 
-    pub fn copy(path: PathBuf, mut response: reqwest::blocking::Response) -> GraphResult<PathBuf> {
-        let (sender, receiver) = mpsc::channel();
-        let handle = thread::spawn(move || {
-            let mut file_writer = OpenOptions::new()
-                .create(true)
-                .write(true)
-                .read(true)
-                .open(&path)
-                .expect("Error creating file");
-            copy(&mut response, &mut file_writer).expect("Error copying file contents");
-            sender.send(Some(path)).unwrap();
-        });
-
-        handle.join().expect("Thread could not be joined");
-        match receiver.recv() {
-            Ok(t) => {
-                Ok(t.ok_or_else(|| GraphFailure::not_found("Unknown error downloading file"))?)
-            },
-            Err(e) => Err(GraphFailure::from(e)),
-        }
+    /// Write the response to the file at path calling on_write with the
+    /// bytes after each write. The response is appended to the file if
+    /// append is true, otherwise the file is truncated.
+    pub fn copy_to<F>(
+        path: &Path,
+        response: reqwest::blocking::Response,
+        append: bool,
+        on_write: F,
+    ) -> GraphResult<()>
+    where
+        F: FnMut(&[u8]),
+    {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .append(append)
+            .truncate(!append)
+            .open(path)?;
+        IoTools::write_to(&mut file, response, on_write)
     }
 
-    pub async fn copy_async(path: PathBuf, response: reqwest::Response) -> GraphResult<PathBuf> {
+    pub async fn copy_to_async<F>(
+        path: &Path,
+        response: reqwest::Response,
+        append: bool,
+        on_write: F,
+    ) -> GraphResult<()>
+    where
+        F: FnMut(&[u8]),
+    {
         let mut file = tokio::fs::OpenOptions::new()
             .create(true)
             .write(true)
-            .read(true)
-            .open(&path)
+            .append(append)
+            .truncate(!append)
+            .open(path)
             .await?;
+        IoTools::write_to_async(&mut file, response, on_write).await
+    }
+
+    /// Write the response to the writer calling on_write with the
+    /// bytes after each write.
+    pub fn write_to<W, F>(
+        writer: &mut W,
+        mut response: reqwest::blocking::Response,
+        mut on_write: F,
+    ) -> GraphResult<()>
+    where
+        W: Write,
+        F: FnMut(&[u8]),
+    {
+        let mut buf = vec![0; 64 * 1024];
+        loop {
+            let len = response.read(&mut buf)?;
+            if len == 0 {
+                break;
+            }
+            writer.write_all(&buf[..len])?;
+            on_write(&buf[..len]);
+        }
+        writer.flush()?;
+        Ok(())
+    }
+
+    pub async fn write_to_async<W, F>(
+        writer: &mut W,
+        response: reqwest::Response,
+        mut on_write: F,
+    ) -> GraphResult<()>
+    where
+        W: AsyncWrite + Unpin,
+        F: FnMut(&[u8]),
+    {
         let mut stream = response.bytes_stream();
         while let Some(item) = stream.next().await {
-            file.write_all(&item?).await?;
+            let bytes = item?;
+            writer.write_all(&bytes).await?;
+            on_write(&bytes);
         }
-        Ok(path)
+        writer.flush().await?;
+        Ok(())
     }
 }
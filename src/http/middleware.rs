@@ -0,0 +1,301 @@
+use graph_error::{GraphFailure, GraphResult};
+use reqwest::header::{HeaderMap, CONTENT_LENGTH};
+use reqwest::{Method, StatusCode, Url};
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+/// The parts of a request that are given to each middleware before the
+/// request is sent. Changes to the method, url, headers, and body are
+/// applied to the request that is sent.
+#[derive(Debug, Clone)]
+pub struct MiddlewareRequest {
+    pub method: Method,
+    pub url: Url,
+    pub headers: HeaderMap,
+    body: Option<Vec<u8>>,
+    body_changed: bool,
+}
+
+impl MiddlewareRequest {
+    fn new(request: &reqwest::Request) -> MiddlewareRequest {
+        MiddlewareRequest {
+            method: request.method().clone(),
+            url: request.url().clone(),
+            headers: request.headers().clone(),
+            body: request
+                .body()
+                .and_then(|body| body.as_bytes())
+                .map(|bytes| bytes.to_vec()),
+            body_changed: false,
+        }
+    }
+
+    fn new_blocking(request: &reqwest::blocking::Request) -> MiddlewareRequest {
+        MiddlewareRequest {
+            method: request.method().clone(),
+            url: request.url().clone(),
+            headers: request.headers().clone(),
+            body: request
+                .body()
+                .and_then(|body| body.as_bytes())
+                .map(|bytes| bytes.to_vec()),
+            body_changed: false,
+        }
+    }
+
+    /// The request body when the body is not a stream such as a file
+    /// being uploaded in a multipart request.
+    pub fn body(&self) -> Option<&[u8]> {
+        self.body.as_ref().map(|body| body.as_slice())
+    }
+
+    /// Replace the request body. The Content-Length header is removed
+    /// so that it is set from the new body when the request is sent.
+    pub fn set_body(&mut self, body: Vec<u8>) {
+        self.headers.remove(CONTENT_LENGTH);
+        self.body = Some(body);
+        self.body_changed = true;
+    }
+
+    // The body set by a middleware. A body that was not changed is left
+    // as it is so that streamed bodies are still sent.
+    fn take_changed_body(&mut self) -> Option<Vec<u8>> {
+        if self.body_changed {
+            self.body.take()
+        } else {
+            None
+        }
+    }
+}
+
+/// The status, url, and headers of a response given to each middleware after
+/// the response is received. The body of the response is not read so that
+/// downloads can still be streamed.
+///
+/// A middleware can replace the response by changing the status or setting
+/// a body. When only the status is changed the body of the response that
+/// was received is kept. A response returned from [Middleware::before_send]
+/// short circuits the request and is used in place of sending the request.
+#[derive(Debug, Clone, Default)]
+pub struct MiddlewareResponse {
+    pub url: Option<Url>,
+    pub status: u16,
+    pub headers: HeaderMap,
+    pub body: Option<Vec<u8>>,
+}
+
+impl MiddlewareResponse {
+    pub fn new(status: u16, headers: HeaderMap, body: Vec<u8>) -> MiddlewareResponse {
+        MiddlewareResponse {
+            url: None,
+            status,
+            headers,
+            body: Some(body),
+        }
+    }
+
+    pub fn json<T: serde::Serialize>(status: u16, value: &T) -> GraphResult<MiddlewareResponse> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            reqwest::header::CONTENT_TYPE,
+            reqwest::header::HeaderValue::from_static("application/json"),
+        );
+        Ok(MiddlewareResponse::new(
+            status,
+            headers,
+            serde_json::to_vec(value)?,
+        ))
+    }
+
+    fn into_http_response(self) -> GraphResult<http::Response<Vec<u8>>> {
+        let status = StatusCode::from_u16(self.status)
+            .map_err(|_| GraphFailure::invalid("middleware response status code"))?;
+        let mut response = http::Response::new(self.body.unwrap_or_default());
+        *response.status_mut() = status;
+        *response.headers_mut() = self.headers;
+        Ok(response)
+    }
+}
+
+/// A handler that runs before each request is sent and after each
+/// response is received.
+///
+/// Middleware registered on the client runs in the order it was added
+/// before the request is sent and in reverse order after the response
+/// is received.
+pub trait Middleware: Send + Sync {
+    /// Inspect or change the request before it is sent. Returning
+    /// a response skips sending the request and any middleware
+    /// registered after this one.
+    fn before_send(
+        &self,
+        _request: &mut MiddlewareRequest,
+    ) -> GraphResult<Option<MiddlewareResponse>> {
+        Ok(None)
+    }
+
+    /// Inspect or change the response after it is received.
+    fn after_receive(&self, _response: &mut MiddlewareResponse) -> GraphResult<()> {
+        Ok(())
+    }
+}
+
+/// An ordered list of middleware that every request is sent through.
+#[derive(Clone, Default)]
+pub struct Pipeline {
+    middleware: Vec<Arc<dyn Middleware>>,
+}
+
+impl Pipeline {
+    pub fn new() -> Pipeline {
+        Pipeline::default()
+    }
+
+    pub fn push<M: Middleware + 'static>(&mut self, middleware: M) {
+        self.middleware.push(Arc::new(middleware));
+    }
+
+    pub fn push_arc(&mut self, middleware: Arc<dyn Middleware>) {
+        self.middleware.push(middleware);
+    }
+
+    pub fn len(&self) -> usize {
+        self.middleware.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.middleware.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.middleware.clear();
+    }
+
+    // Runs each before_send handler and returns the number of handlers
+    // that ran along with the response of the handler that short circuited
+    // the request, if any.
+    fn before_send(
+        &self,
+        request: &mut MiddlewareRequest,
+    ) -> GraphResult<(usize, Option<MiddlewareResponse>)> {
+        for (i, middleware) in self.middleware.iter().enumerate() {
+            if let Some(response) = middleware.before_send(request)? {
+                return Ok((i, Some(response)));
+            }
+        }
+        Ok((self.middleware.len(), None))
+    }
+
+    fn after_receive(&self, ran: usize, response: &mut MiddlewareResponse) -> GraphResult<()> {
+        for middleware in self.middleware[..ran].iter().rev() {
+            middleware.after_receive(response)?;
+        }
+        Ok(())
+    }
+
+    pub fn send(
+        &self,
+        client: &reqwest::blocking::Client,
+        builder: reqwest::blocking::RequestBuilder,
+    ) -> GraphResult<reqwest::blocking::Response> {
+        if self.is_empty() {
+            return Ok(builder.send()?);
+        }
+
+        let mut request = builder.build()?;
+        let mut parts = MiddlewareRequest::new_blocking(&request);
+
+        let (ran, short_circuit) = self.before_send(&mut parts)?;
+        if let Some(mut response) = short_circuit {
+            self.after_receive(ran, &mut response)?;
+            return Ok(reqwest::blocking::Response::from(
+                response.into_http_response()?,
+            ));
+        }
+
+        if let Some(body) = parts.take_changed_body() {
+            *request.body_mut() = Some(body.into());
+        }
+        *request.method_mut() = parts.method;
+        *request.url_mut() = parts.url;
+        *request.headers_mut() = parts.headers;
+
+        let mut response = client.execute(request)?;
+        let status = response.status().as_u16();
+        let mut parts = MiddlewareResponse {
+            url: Some(response.url().clone()),
+            status,
+            headers: response.headers().clone(),
+            body: None,
+        };
+        self.after_receive(ran, &mut parts)?;
+
+        if parts.body.is_some() {
+            return Ok(reqwest::blocking::Response::from(
+                parts.into_http_response()?,
+            ));
+        }
+        if parts.status != status {
+            parts.body = Some(response.bytes()?.to_vec());
+            return Ok(reqwest::blocking::Response::from(
+                parts.into_http_response()?,
+            ));
+        }
+        *response.headers_mut() = parts.headers;
+        Ok(response)
+    }
+
+    pub async fn send_async(
+        &self,
+        client: &reqwest::Client,
+        builder: reqwest::RequestBuilder,
+    ) -> GraphResult<reqwest::Response> {
+        if self.is_empty() {
+            return Ok(builder.send().await?);
+        }
+
+        let mut request = builder.build()?;
+        let mut parts = MiddlewareRequest::new(&request);
+
+        let (ran, short_circuit) = self.before_send(&mut parts)?;
+        if let Some(mut response) = short_circuit {
+            self.after_receive(ran, &mut response)?;
+            return Ok(reqwest::Response::from(response.into_http_response()?));
+        }
+
+        if let Some(body) = parts.take_changed_body() {
+            *request.body_mut() = Some(body.into());
+        }
+        *request.method_mut() = parts.method;
+        *request.url_mut() = parts.url;
+        *request.headers_mut() = parts.headers;
+
+        let mut response = client.execute(request).await?;
+        let status = response.status().as_u16();
+        let mut parts = MiddlewareResponse {
+            url: Some(response.url().clone()),
+            status,
+            headers: response.headers().clone(),
+            body: None,
+        };
+        self.after_receive(ran, &mut parts)?;
+
+        if parts.body.is_some() {
+            return Ok(reqwest::Response::from(parts.into_http_response()?));
+        }
+        if parts.status != status {
+            parts.body = Some(response.bytes().await?.to_vec());
+            return Ok(reqwest::Response::from(parts.into_http_response()?));
+        }
+        *response.headers_mut() = parts.headers;
+        Ok(response)
+    }
+}
+
+impl Debug for Pipeline {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Pipeline")
+            .field("middleware", &self.middleware.len())
+            .finish()
+    }
+}
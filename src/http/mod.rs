@@ -1,23 +1,37 @@
 mod asynciterator;
 mod asynctryfrom;
+mod batch;
 mod byterange;
 mod delta;
+mod deltasync;
 mod download;
 mod graphresponse;
+mod hashes;
 mod intorequest;
 mod intoresponse;
 mod iotools;
+mod middleware;
+mod paged;
 mod request;
+mod retry;
+mod tokenprovider;
 mod uploadsession;
 
 pub use asynciterator::*;
 pub(crate) use asynctryfrom::*;
+pub use batch::*;
 pub use byterange::*;
 pub use delta::*;
+pub use deltasync::*;
 pub use download::*;
 pub use graphresponse::*;
+pub use hashes::*;
 pub use intorequest::*;
 pub use intoresponse::*;
 pub use iotools::*;
+pub use middleware::*;
+pub use paged::*;
 pub use request::*;
+pub use retry::*;
+pub use tokenprovider::*;
 pub use uploadsession::*;
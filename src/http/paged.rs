@@ -0,0 +1,220 @@
+use crate::client::Graph;
+use crate::http::{AsyncHttpClient, BlockingHttpClient, GraphResponse, RequestClient};
+use crate::types::collection::Collection;
+use crate::url::GraphUrl;
+use futures::Stream;
+use graph_error::{GraphFailure, GraphResult};
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
+use reqwest::Method;
+use std::collections::VecDeque;
+
+/// Returns the items of a collection one at a time and requests the
+/// next page using the @odata.nextLink of the previous page.
+///
+/// Each page is requested using the client the request was built with
+/// so the retry policy, middleware and token provider of the client are
+/// used for every page. The headers of the request are sent with the
+/// request for each page.
+///
+/// The blocking client returns an iterator and the async client returns
+/// a stream using `stream()`. Iteration stops after the first error.
+///
+/// # Example
+/// ```rust,ignore
+/// # use graph_rs::prelude::*;
+/// # let client = Graph::new("ACCESS_TOKEN");
+/// let users = client.v1()
+///     .users("")
+///     .list()
+///     .paged()
+///     .max_items(100);
+///
+/// for user in users {
+///     println!("{:#?}", user?);
+/// }
+///
+/// // Async
+/// # use futures::StreamExt;
+/// # let client = Graph::new_async("ACCESS_TOKEN");
+/// let mut stream = client.v1()
+///     .users("")
+///     .list()
+///     .paged()
+///     .max_pages(5)
+///     .stream();
+///
+/// while let Some(user) = stream.next().await {
+///     println!("{:#?}", user?);
+/// }
+/// ```
+pub struct Paged<'a, T, Client>
+where
+    Client: RequestClient,
+{
+    client: &'a Graph<Client>,
+    next: Option<GraphUrl>,
+    method: Method,
+    headers: HeaderMap,
+    body: Option<Vec<u8>>,
+    items: VecDeque<T>,
+    max_pages: Option<usize>,
+    max_items: Option<usize>,
+    pages: usize,
+    count: usize,
+    error: Option<GraphFailure>,
+}
+
+impl<'a, T, Client> Paged<'a, T, Client>
+where
+    Client: RequestClient,
+{
+    // The request the request builder set on the client is taken so that
+    // the client can be used for other requests between pages.
+    pub(crate) fn new(client: &'a Graph<Client>, error: Option<GraphFailure>) -> Self {
+        let request = client.request();
+        let paged = Paged {
+            client,
+            next: Some(request.url()),
+            method: request.method(),
+            headers: request.header_map(),
+            body: request.take_body(),
+            items: VecDeque::new(),
+            max_pages: None,
+            max_items: None,
+            pages: 0,
+            count: 0,
+            error,
+        };
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        request.set_header_map(headers);
+        paged
+    }
+
+    /// The max number of pages to request.
+    pub fn max_pages(mut self, max_pages: usize) -> Self {
+        self.max_pages = Some(max_pages);
+        self
+    }
+
+    /// The max number of items to return.
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    /// The number of pages that have been requested.
+    pub fn pages(&self) -> usize {
+        self.pages
+    }
+
+    fn next_item(&mut self) -> Option<T> {
+        if let Some(max_items) = self.max_items {
+            if self.count >= max_items {
+                self.items.clear();
+                self.next = None;
+                return None;
+            }
+        }
+
+        let item = self.items.pop_front()?;
+        self.count += 1;
+        Some(item)
+    }
+
+    // Sets the request for the next page on the client. Returns
+    // false if there are no more pages or the max pages was reached.
+    fn set_next_request(&mut self) -> bool {
+        if let Some(max_pages) = self.max_pages {
+            if self.pages >= max_pages {
+                self.next = None;
+            }
+        }
+        let url = match self.next.take() {
+            Some(url) => url,
+            None => return false,
+        };
+
+        let request = self.client.request();
+        request.set_url(url);
+        request.set_method(self.method.clone());
+        request.set_header_map(self.headers.clone());
+        if let Some(body) = self.body.take() {
+            request.set_body(body);
+        }
+        // Each next link is requested with a GET.
+        self.method = Method::GET;
+        self.pages += 1;
+        true
+    }
+
+    fn add_page(&mut self, response: GraphResult<GraphResponse<Collection<T>>>) -> GraphResult<()> {
+        let collection = response?.into_body();
+        if let Some(next_link) = collection.odata_next_link() {
+            self.next = Some(GraphUrl::parse(next_link)?);
+        }
+        self.items.extend(collection.into_inner());
+        Ok(())
+    }
+}
+
+impl<'a, T> Iterator for Paged<'a, T, BlockingHttpClient>
+where
+    for<'de> T: serde::Deserialize<'de>,
+{
+    type Item = GraphResult<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(err) = self.error.take() {
+            self.next = None;
+            return Some(Err(err));
+        }
+
+        loop {
+            if let Some(item) = self.next_item() {
+                return Some(Ok(item));
+            }
+            if !self.set_next_request() {
+                return None;
+            }
+            let response = self.client.request().execute();
+            if let Err(err) = self.add_page(response) {
+                return Some(Err(err));
+            }
+        }
+    }
+}
+
+impl<'a, T> Paged<'a, T, AsyncHttpClient>
+where
+    T: 'a,
+    for<'de> T: serde::Deserialize<'de>,
+{
+    /// A stream of the items in each page.
+    pub fn stream(self) -> impl Stream<Item = GraphResult<T>> + 'a {
+        futures::stream::unfold(self, |mut paged| async move {
+            let item = paged.next_async().await?;
+            Some((item, paged))
+        })
+    }
+
+    async fn next_async(&mut self) -> Option<GraphResult<T>> {
+        if let Some(err) = self.error.take() {
+            self.next = None;
+            return Some(Err(err));
+        }
+
+        loop {
+            if let Some(item) = self.next_item() {
+                return Some(Ok(item));
+            }
+            if !self.set_next_request() {
+                return None;
+            }
+            let response = self.client.request().execute().await;
+            if let Err(err) = self.add_page(response) {
+                return Some(Err(err));
+            }
+        }
+    }
+}
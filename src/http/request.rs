@@ -1,11 +1,13 @@
 use crate::client::Ident;
 use crate::http::{
-    AsyncDownload, AsyncTryFrom, BlockingDownload, DownloadClient, GraphResponse,
-    UploadSessionClient,
+    send_with_token_provider, send_with_token_provider_async, AsyncDownload, AsyncIterator,
+    AsyncTryFrom, BlockingDownload, DownloadClient, GraphResponse, NextSession, Pipeline,
+    RetryPolicy, TokenProvider, UploadContent, UploadSessionClient, SIMPLE_UPLOAD_MAX_SIZE,
 };
 use crate::url::GraphUrl;
 use crate::GRAPH_URL;
 use graph_error::{ErrorMessage, GraphError, GraphFailure, GraphResult};
+use graph_oauth::oauth::AzureCloud;
 use handlebars::Handlebars;
 use reqwest::header::{HeaderMap, HeaderValue, IntoHeaderName, CONTENT_TYPE};
 use reqwest::{redirect::Policy, Method};
@@ -16,6 +18,7 @@ use std::fmt::{Debug, Formatter};
 use std::fs::File;
 use std::io::Read;
 use std::path::PathBuf;
+use std::sync::Arc;
 use url::Url;
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
@@ -63,12 +66,25 @@ pub trait RequestClient {
     fn set_body_with_file(&self, path: PathBuf) -> GraphResult<()>;
     fn header<T: IntoHeaderName>(&self, name: T, value: HeaderValue);
     fn set_header_map(&self, header_map: HeaderMap);
+    fn header_map(&self) -> HeaderMap;
     fn clear_headers(&self);
+    fn take_body(&self) -> Option<Vec<u8>>;
     fn set_download_dir(&self, dir: PathBuf);
     fn set_upload_session(&self, file: PathBuf);
+    fn set_upload_session_content(&self, content: UploadContent);
     fn set_form(&self, form: Self::Form);
     fn set_request_type(&self, req_type: GraphRequestType);
     fn request_type(&self) -> GraphRequestType;
+    fn set_retry_policy(&self, retry_policy: RetryPolicy);
+    fn retry_policy(&self) -> RetryPolicy;
+    fn set_azure_cloud(&self, azure_cloud: AzureCloud);
+    fn azure_cloud(&self) -> AzureCloud;
+    fn set_custom_endpoint(&self, endpoint: Option<String>);
+    fn custom_endpoint(&self) -> Option<String>;
+    fn set_pipeline(&self, pipeline: Pipeline);
+    fn pipeline(&self) -> Pipeline;
+    fn set_token_provider(&self, token_provider: Option<Arc<dyn TokenProvider>>);
+    fn token_provider(&self) -> Option<Arc<dyn TokenProvider>>;
     fn url_ref<F>(&self, f: F)
     where
         F: Fn(&GraphUrl) + Sync;
@@ -94,10 +110,15 @@ pub struct GraphRequest<Client, Body, Form> {
     pub method: Method,
     pub body: Option<Body>,
     pub headers: HeaderMap<HeaderValue>,
-    pub upload_session_file: Option<PathBuf>,
+    pub upload_session_content: Option<UploadContent>,
     pub download_dir: Option<PathBuf>,
     pub form: Option<Form>,
     pub req_type: GraphRequestType,
+    pub retry_policy: RetryPolicy,
+    pub azure_cloud: AzureCloud,
+    pub custom_endpoint: Option<String>,
+    pub pipeline: Pipeline,
+    pub token_provider: Option<Arc<dyn TokenProvider>>,
     pub registry: Handlebars,
 }
 
@@ -109,9 +130,14 @@ impl<Client, Body, Form> Debug for GraphRequest<Client, Body, Form> {
             .field("url", &self.url)
             .field("method", &self.method)
             .field("headers", &self.headers)
-            .field("upload_session_file", &self.upload_session_file)
+            .field("upload_session_content", &self.upload_session_content)
             .field("download_dir", &self.download_dir)
             .field("req_type", &self.req_type)
+            .field("retry_policy", &self.retry_policy)
+            .field("azure_cloud", &self.azure_cloud)
+            .field("custom_endpoint", &self.custom_endpoint)
+            .field("pipeline", &self.pipeline)
+            .field("token_provider", &self.token_provider.is_some())
             .finish()
     }
 }
@@ -154,10 +180,15 @@ impl BlockingClient {
             method: Default::default(),
             body: None,
             headers,
-            upload_session_file: None,
+            upload_session_content: None,
             download_dir: None,
             form: None,
             req_type: Default::default(),
+            retry_policy: Default::default(),
+            azure_cloud: Default::default(),
+            custom_endpoint: None,
+            pipeline: Default::default(),
+            token_provider: None,
             registry: Handlebars::new(),
         }
     }
@@ -172,10 +203,10 @@ impl BlockingClient {
     }
 
     pub fn upload_session(&mut self) -> GraphResult<UploadSessionClient<BlockingHttpClient>> {
-        let file = self
-            .upload_session_file
+        let content = self
+            .upload_session_content
             .take()
-            .ok_or_else(|| GraphFailure::invalid("file for upload session"))?;
+            .ok_or_else(|| GraphFailure::invalid("content for upload session"))?;
 
         let response = self.response()?;
         if let Ok(mut error) = GraphError::try_from(&response) {
@@ -189,17 +220,63 @@ impl BlockingClient {
 
         let upload_session: serde_json::Value = response.json()?;
         let mut session = UploadSessionClient::new(upload_session)?;
-        session.set_file(file)?;
+        session.set_pipeline(self.pipeline.clone());
+        session.set_retry_policy(self.retry_policy);
+        session.set_content(content)?;
         Ok(session)
     }
 
-    pub fn build_upload_session(&mut self) -> (Option<PathBuf>, reqwest::blocking::RequestBuilder) {
-        let file = self.upload_session_file.take();
+    /// Upload the upload session content to the drive item of the url.
+    /// Content smaller than 4 MiB is uploaded using a single PUT request
+    /// to the content of the item. Larger content is uploaded using an
+    /// upload session.
+    pub fn upload<T>(&mut self) -> GraphResult<GraphResponse<T>>
+    where
+        for<'de> T: serde::Deserialize<'de>,
+    {
+        let content = self
+            .upload_session_content
+            .take()
+            .ok_or_else(|| GraphFailure::invalid("content to upload"))?;
+
+        if content.content_length()? < SIMPLE_UPLOAD_MAX_SIZE {
+            self.url.extend_path(&["content"]);
+            self.method = Method::PUT;
+            self.body = Some(content.read_to_vec()?.into());
+            return self.execute();
+        }
+
+        self.url.extend_path(&["createUploadSession"]);
+        self.method = Method::POST;
+        self.body = Some("{}".into());
+        self.upload_session_content = Some(content);
+        let session = self.upload_session()?;
+        for next in session {
+            if let NextSession::Done(response) = next? {
+                return response.deserialize_body();
+            }
+        }
+        Err(GraphFailure::invalid(
+            "upload session. The upload did not complete",
+        ))
+    }
+
+    pub fn build_upload_session(
+        &mut self,
+    ) -> (Option<UploadContent>, reqwest::blocking::RequestBuilder) {
+        let content = self.upload_session_content.take();
         let builder = self.build();
-        (file, builder)
+        (content, builder)
     }
 
     pub fn build(&mut self) -> reqwest::blocking::RequestBuilder {
+        let token = self.token.clone();
+        self.build_request().bearer_auth(token.as_str())
+    }
+
+    // Builds the request without the Authorization header so that the
+    // request can be sent again after the token provider refreshes the token.
+    pub(crate) fn build_request(&mut self) -> reqwest::blocking::RequestBuilder {
         let headers = self.headers.clone();
         self.headers.clear();
         self.headers
@@ -208,8 +285,7 @@ impl BlockingClient {
         let builder = self
             .client
             .request(self.method.clone(), self.url.as_str())
-            .headers(headers)
-            .bearer_auth(self.token.as_str());
+            .headers(headers);
 
         match self.req_type {
             GraphRequestType::Basic | GraphRequestType::Redirect => {
@@ -224,9 +300,33 @@ impl BlockingClient {
     }
 
     pub fn response(&mut self) -> GraphResult<reqwest::blocking::Response> {
-        let builder = self.build();
-        let response = builder.send()?;
-        Ok(response)
+        let token_provider = match self.token_provider.clone() {
+            Some(token_provider) => token_provider,
+            None => {
+                let builder = self.build();
+                return self.send(builder);
+            },
+        };
+
+        let builder = self.build_request();
+        let mut token = std::mem::take(&mut self.token);
+        let this = &*self;
+        let response =
+            send_with_token_provider(token_provider.as_ref(), builder, &mut token, |builder| {
+                this.send(builder)
+            });
+        self.token = token;
+        response
+    }
+
+    fn send(
+        &self,
+        builder: reqwest::blocking::RequestBuilder,
+    ) -> GraphResult<reqwest::blocking::Response> {
+        let client = &self.client;
+        let pipeline = &self.pipeline;
+        self.retry_policy
+            .send_with(builder, |builder| pipeline.send(client, builder))
     }
 
     pub fn execute<T>(&mut self) -> GraphResult<GraphResponse<T>>
@@ -249,10 +349,15 @@ impl BlockingClient {
             method: self.method.clone(),
             body: self.body.take(),
             headers: self.headers.clone(),
-            upload_session_file: self.upload_session_file.take(),
+            upload_session_content: self.upload_session_content.take(),
             download_dir: self.download_dir.take(),
             form: self.form.take(),
             req_type: self.req_type,
+            retry_policy: self.retry_policy,
+            azure_cloud: self.azure_cloud,
+            custom_endpoint: self.custom_endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+            token_provider: self.token_provider.clone(),
             registry: Handlebars::new(),
         }
     }
@@ -287,10 +392,15 @@ impl AsyncClient {
             method: Default::default(),
             body: None,
             headers,
-            upload_session_file: None,
+            upload_session_content: None,
             download_dir: None,
             form: None,
             req_type: Default::default(),
+            retry_policy: Default::default(),
+            azure_cloud: Default::default(),
+            custom_endpoint: None,
+            pipeline: Default::default(),
+            token_provider: None,
             registry: Handlebars::new(),
         }
     }
@@ -305,10 +415,10 @@ impl AsyncClient {
     }
 
     pub async fn upload_session(&mut self) -> GraphResult<UploadSessionClient<AsyncHttpClient>> {
-        let file = self
-            .upload_session_file
+        let content = self
+            .upload_session_content
             .take()
-            .ok_or_else(|| GraphFailure::invalid("file for upload session"))?;
+            .ok_or_else(|| GraphFailure::invalid("content for upload session"))?;
 
         let response = self.response().await?;
         if let Ok(mut error) = GraphError::try_from(&response) {
@@ -322,17 +432,61 @@ impl AsyncClient {
 
         let upload_session: serde_json::Value = response.json().await?;
         let mut session = UploadSessionClient::new_async(upload_session)?;
-        session.set_file_async(file).await?;
+        session.set_pipeline(self.pipeline.clone());
+        session.set_retry_policy(self.retry_policy);
+        session.set_content_async(content).await?;
         Ok(session)
     }
 
-    pub fn build_upload_session(&mut self) -> (Option<PathBuf>, reqwest::RequestBuilder) {
-        let file = self.upload_session_file.take();
+    /// Upload the upload session content to the drive item of the url.
+    /// Content smaller than 4 MiB is uploaded using a single PUT request
+    /// to the content of the item. Larger content is uploaded using an
+    /// upload session.
+    pub async fn upload<T>(&mut self) -> GraphResult<GraphResponse<T>>
+    where
+        for<'de> T: serde::Deserialize<'de>,
+    {
+        let content = self
+            .upload_session_content
+            .take()
+            .ok_or_else(|| GraphFailure::invalid("content to upload"))?;
+
+        if content.content_length()? < SIMPLE_UPLOAD_MAX_SIZE {
+            self.url.extend_path(&["content"]);
+            self.method = Method::PUT;
+            self.body = Some(content.read_to_vec_async().await?.into());
+            return self.execute().await;
+        }
+
+        self.url.extend_path(&["createUploadSession"]);
+        self.method = Method::POST;
+        self.body = Some("{}".into());
+        self.upload_session_content = Some(content);
+        let mut session = self.upload_session().await?;
+        while let Some(next) = session.next().await {
+            if let NextSession::Done(response) = next? {
+                return response.deserialize_body();
+            }
+        }
+        Err(GraphFailure::invalid(
+            "upload session. The upload did not complete",
+        ))
+    }
+
+    pub fn build_upload_session(&mut self) -> (Option<UploadContent>, reqwest::RequestBuilder) {
+        let content = self.upload_session_content.take();
         let builder = self.build();
-        (file, builder)
+        (content, builder)
     }
 
     pub fn build(&mut self) -> reqwest::RequestBuilder {
+        let token = self.token.clone();
+        self.build_request().bearer_auth(token.as_str())
+    }
+
+    // Builds the request without the Authorization header so that the
+    // request can be sent again after the token provider refreshes the token.
+    pub(crate) fn build_request(&mut self) -> reqwest::RequestBuilder {
         let headers = self.headers.clone();
         self.headers.clear();
         self.headers
@@ -341,8 +495,7 @@ impl AsyncClient {
         let builder = self
             .client
             .request(self.method.clone(), self.url.as_str())
-            .headers(headers)
-            .bearer_auth(self.token.as_str());
+            .headers(headers);
 
         match self.req_type {
             GraphRequestType::Basic | GraphRequestType::Redirect => {
@@ -357,9 +510,34 @@ impl AsyncClient {
     }
 
     pub async fn response(&mut self) -> GraphResult<reqwest::Response> {
-        let builder = self.build();
-        let response = builder.send().await?;
-        Ok(response)
+        let token_provider = match self.token_provider.clone() {
+            Some(token_provider) => token_provider,
+            None => {
+                let builder = self.build();
+                return self.send(builder).await;
+            },
+        };
+
+        let builder = self.build_request();
+        let mut token = std::mem::take(&mut self.token);
+        let this = &*self;
+        let response = send_with_token_provider_async(
+            token_provider.as_ref(),
+            builder,
+            &mut token,
+            |builder| this.send(builder),
+        )
+        .await;
+        self.token = token;
+        response
+    }
+
+    async fn send(&self, builder: reqwest::RequestBuilder) -> GraphResult<reqwest::Response> {
+        let client = &self.client;
+        let pipeline = &self.pipeline;
+        self.retry_policy
+            .send_with_async(builder, |builder| pipeline.send_async(client, builder))
+            .await
     }
 
     pub async fn execute<T>(&mut self) -> GraphResult<GraphResponse<T>>
@@ -383,10 +561,15 @@ impl AsyncClient {
             method: self.method.clone(),
             body: self.body.take(),
             headers: self.headers.clone(),
-            upload_session_file: self.upload_session_file.take(),
+            upload_session_content: self.upload_session_content.take(),
             download_dir: self.download_dir.take(),
             form: self.form.take(),
             req_type: self.req_type,
+            retry_policy: self.retry_policy,
+            azure_cloud: self.azure_cloud,
+            custom_endpoint: self.custom_endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+            token_provider: self.token_provider.clone(),
             registry: Handlebars::new(),
         }
     }
@@ -426,7 +609,16 @@ impl HttpClient<RefCell<BlockingClient>> {
         self.client.borrow_mut().upload_session()
     }
 
-    pub fn build_upload_session(&self) -> (Option<PathBuf>, reqwest::blocking::RequestBuilder) {
+    pub fn upload<T>(&self) -> GraphResult<GraphResponse<T>>
+    where
+        for<'de> T: serde::Deserialize<'de>,
+    {
+        self.client.borrow_mut().upload()
+    }
+
+    pub fn build_upload_session(
+        &self,
+    ) -> (Option<UploadContent>, reqwest::blocking::RequestBuilder) {
         self.client.borrow_mut().build_upload_session()
     }
 
@@ -434,6 +626,17 @@ impl HttpClient<RefCell<BlockingClient>> {
         self.client.borrow_mut().build()
     }
 
+    // Builds the request without the Authorization header.
+    pub(crate) fn build_request(&self) -> reqwest::blocking::RequestBuilder {
+        self.client.borrow_mut().build_request()
+    }
+
+    /// The client that requests are built with. Clones of the
+    /// client share the same connection pool.
+    pub fn http_client(&self) -> reqwest::blocking::Client {
+        self.client.borrow().client.clone()
+    }
+
     pub fn response(&self) -> GraphResult<reqwest::blocking::Response> {
         self.client.borrow_mut().response()
     }
@@ -513,16 +716,29 @@ impl RequestClient for HttpClient<RefCell<BlockingClient>> {
         self.client.borrow_mut().headers = header_map;
     }
 
+    fn header_map(&self) -> HeaderMap {
+        self.client.borrow().headers.clone()
+    }
+
     fn clear_headers(&self) {
         self.client.borrow_mut().headers.clear();
     }
 
+    fn take_body(&self) -> Option<Vec<u8>> {
+        let body = self.client.borrow_mut().body.take()?;
+        body.as_bytes().map(|bytes| bytes.to_vec())
+    }
+
     fn set_download_dir(&self, dir: PathBuf) {
         self.client.borrow_mut().download_dir = Some(dir);
     }
 
     fn set_upload_session(&self, file: PathBuf) {
-        self.client.borrow_mut().upload_session_file = Some(file);
+        self.set_upload_session_content(UploadContent::File(file));
+    }
+
+    fn set_upload_session_content(&self, content: UploadContent) {
+        self.client.borrow_mut().upload_session_content = Some(content);
     }
 
     fn set_form(&self, form: Self::Form) {
@@ -539,6 +755,46 @@ impl RequestClient for HttpClient<RefCell<BlockingClient>> {
         self.client.borrow().req_type
     }
 
+    fn set_retry_policy(&self, retry_policy: RetryPolicy) {
+        self.client.borrow_mut().retry_policy = retry_policy;
+    }
+
+    fn retry_policy(&self) -> RetryPolicy {
+        self.client.borrow().retry_policy
+    }
+
+    fn set_azure_cloud(&self, azure_cloud: AzureCloud) {
+        self.client.borrow_mut().azure_cloud = azure_cloud;
+    }
+
+    fn azure_cloud(&self) -> AzureCloud {
+        self.client.borrow().azure_cloud
+    }
+
+    fn set_custom_endpoint(&self, endpoint: Option<String>) {
+        self.client.borrow_mut().custom_endpoint = endpoint;
+    }
+
+    fn custom_endpoint(&self) -> Option<String> {
+        self.client.borrow().custom_endpoint.clone()
+    }
+
+    fn set_pipeline(&self, pipeline: Pipeline) {
+        self.client.borrow_mut().pipeline = pipeline;
+    }
+
+    fn pipeline(&self) -> Pipeline {
+        self.client.borrow().pipeline.clone()
+    }
+
+    fn set_token_provider(&self, token_provider: Option<Arc<dyn TokenProvider>>) {
+        self.client.borrow_mut().token_provider = token_provider;
+    }
+
+    fn token_provider(&self) -> Option<Arc<dyn TokenProvider>> {
+        self.client.borrow().token_provider.clone()
+    }
+
     fn url_ref<F>(&self, f: F)
     where
         F: Fn(&GraphUrl) + Sync,
@@ -594,7 +850,9 @@ impl RequestClient for HttpClient<RefCell<BlockingClient>> {
                 RequestAttribute::Headers(headers) => client.headers = headers,
                 RequestAttribute::ClearHeaders => client.headers.clear(),
                 RequestAttribute::Download(path) => client.download_dir = Some(path),
-                RequestAttribute::Upload(path) => client.upload_session_file = Some(path),
+                RequestAttribute::Upload(path) => {
+                    client.upload_session_content = Some(UploadContent::File(path))
+                },
                 RequestAttribute::Form(form) => client.form = Some(form),
                 RequestAttribute::RequestType(req_type) => client.req_type = req_type,
             }
@@ -678,16 +936,25 @@ impl HttpClient<std::sync::Arc<tokio::sync::Mutex<AsyncClient>>> {
         self.client.lock().await.headers = header_map;
     }
 
+    async fn inner_header_map(&self) -> HeaderMap {
+        self.client.lock().await.headers.clone()
+    }
+
     async fn inner_clear_headers(&self) {
         self.client.lock().await.headers.clear();
     }
 
+    async fn inner_take_body(&self) -> Option<Vec<u8>> {
+        let body = self.client.lock().await.body.take()?;
+        body.as_bytes().map(|bytes| bytes.to_vec())
+    }
+
     async fn inner_set_download_dir(&self, dir: PathBuf) {
         self.client.lock().await.download_dir = Some(dir);
     }
 
-    async fn inner_set_upload_session(&self, file: PathBuf) {
-        self.client.lock().await.upload_session_file = Some(file);
+    async fn inner_set_upload_session(&self, content: UploadContent) {
+        self.client.lock().await.upload_session_content = Some(content);
     }
 
     async fn inner_set_form(&self, form: reqwest::multipart::Form) {
@@ -704,6 +971,46 @@ impl HttpClient<std::sync::Arc<tokio::sync::Mutex<AsyncClient>>> {
         self.client.lock().await.req_type
     }
 
+    async fn inner_set_retry_policy(&self, retry_policy: RetryPolicy) {
+        self.client.lock().await.retry_policy = retry_policy;
+    }
+
+    async fn inner_retry_policy(&self) -> RetryPolicy {
+        self.client.lock().await.retry_policy
+    }
+
+    async fn inner_set_azure_cloud(&self, azure_cloud: AzureCloud) {
+        self.client.lock().await.azure_cloud = azure_cloud;
+    }
+
+    async fn inner_azure_cloud(&self) -> AzureCloud {
+        self.client.lock().await.azure_cloud
+    }
+
+    async fn inner_set_custom_endpoint(&self, endpoint: Option<String>) {
+        self.client.lock().await.custom_endpoint = endpoint;
+    }
+
+    async fn inner_custom_endpoint(&self) -> Option<String> {
+        self.client.lock().await.custom_endpoint.clone()
+    }
+
+    async fn inner_set_pipeline(&self, pipeline: Pipeline) {
+        self.client.lock().await.pipeline = pipeline;
+    }
+
+    async fn inner_pipeline(&self) -> Pipeline {
+        self.client.lock().await.pipeline.clone()
+    }
+
+    async fn inner_set_token_provider(&self, token_provider: Option<Arc<dyn TokenProvider>>) {
+        self.client.lock().await.token_provider = token_provider;
+    }
+
+    async fn inner_token_provider(&self) -> Option<Arc<dyn TokenProvider>> {
+        self.client.lock().await.token_provider.clone()
+    }
+
     async fn inner_url_ref<F>(&self, f: F)
     where
         F: Fn(&GraphUrl) + Sync,
@@ -762,7 +1069,9 @@ impl HttpClient<std::sync::Arc<tokio::sync::Mutex<AsyncClient>>> {
                 RequestAttribute::Headers(headers) => client.headers = headers,
                 RequestAttribute::ClearHeaders => client.headers.clear(),
                 RequestAttribute::Download(path) => client.download_dir = Some(path),
-                RequestAttribute::Upload(path) => client.upload_session_file = Some(path),
+                RequestAttribute::Upload(path) => {
+                    client.upload_session_content = Some(UploadContent::File(path))
+                },
                 RequestAttribute::Form(form) => client.form = Some(form),
                 RequestAttribute::RequestType(req_type) => client.req_type = req_type,
             }
@@ -778,7 +1087,14 @@ impl HttpClient<std::sync::Arc<tokio::sync::Mutex<AsyncClient>>> {
         self.client.lock().await.upload_session().await
     }
 
-    pub async fn build_upload_session(&self) -> (Option<PathBuf>, reqwest::RequestBuilder) {
+    pub async fn upload<T>(&self) -> GraphResult<GraphResponse<T>>
+    where
+        for<'de> T: serde::Deserialize<'de>,
+    {
+        self.client.lock().await.upload().await
+    }
+
+    pub async fn build_upload_session(&self) -> (Option<UploadContent>, reqwest::RequestBuilder) {
         self.client.lock().await.build_upload_session()
     }
 
@@ -786,6 +1102,17 @@ impl HttpClient<std::sync::Arc<tokio::sync::Mutex<AsyncClient>>> {
         self.client.lock().await.build()
     }
 
+    // Builds the request without the Authorization header.
+    pub(crate) async fn build_request(&self) -> reqwest::RequestBuilder {
+        self.client.lock().await.build_request()
+    }
+
+    /// The client that requests are built with. Clones of the
+    /// client share the same connection pool.
+    pub async fn http_client(&self) -> reqwest::Client {
+        self.client.lock().await.client.clone()
+    }
+
     pub async fn response(&self) -> GraphResult<reqwest::Response> {
         self.client.lock().await.response().await
     }
@@ -854,16 +1181,28 @@ impl RequestClient for HttpClient<std::sync::Arc<tokio::sync::Mutex<AsyncClient>
         futures::executor::block_on(self.inner_set_header_map(header_map));
     }
 
+    fn header_map(&self) -> HeaderMap {
+        futures::executor::block_on(self.inner_header_map())
+    }
+
     fn clear_headers(&self) {
         futures::executor::block_on(self.inner_clear_headers());
     }
 
+    fn take_body(&self) -> Option<Vec<u8>> {
+        futures::executor::block_on(self.inner_take_body())
+    }
+
     fn set_download_dir(&self, dir: PathBuf) {
         futures::executor::block_on(self.inner_set_download_dir(dir));
     }
 
     fn set_upload_session(&self, file: PathBuf) {
-        futures::executor::block_on(self.inner_set_upload_session(file));
+        self.set_upload_session_content(UploadContent::File(file));
+    }
+
+    fn set_upload_session_content(&self, content: UploadContent) {
+        futures::executor::block_on(self.inner_set_upload_session(content));
     }
 
     fn set_form(&self, form: Self::Form) {
@@ -878,6 +1217,46 @@ impl RequestClient for HttpClient<std::sync::Arc<tokio::sync::Mutex<AsyncClient>
         futures::executor::block_on(self.inner_request_type())
     }
 
+    fn set_retry_policy(&self, retry_policy: RetryPolicy) {
+        futures::executor::block_on(self.inner_set_retry_policy(retry_policy));
+    }
+
+    fn retry_policy(&self) -> RetryPolicy {
+        futures::executor::block_on(self.inner_retry_policy())
+    }
+
+    fn set_azure_cloud(&self, azure_cloud: AzureCloud) {
+        futures::executor::block_on(self.inner_set_azure_cloud(azure_cloud));
+    }
+
+    fn azure_cloud(&self) -> AzureCloud {
+        futures::executor::block_on(self.inner_azure_cloud())
+    }
+
+    fn set_custom_endpoint(&self, endpoint: Option<String>) {
+        futures::executor::block_on(self.inner_set_custom_endpoint(endpoint));
+    }
+
+    fn custom_endpoint(&self) -> Option<String> {
+        futures::executor::block_on(self.inner_custom_endpoint())
+    }
+
+    fn set_pipeline(&self, pipeline: Pipeline) {
+        futures::executor::block_on(self.inner_set_pipeline(pipeline));
+    }
+
+    fn pipeline(&self) -> Pipeline {
+        futures::executor::block_on(self.inner_pipeline())
+    }
+
+    fn set_token_provider(&self, token_provider: Option<Arc<dyn TokenProvider>>) {
+        futures::executor::block_on(self.inner_set_token_provider(token_provider));
+    }
+
+    fn token_provider(&self) -> Option<Arc<dyn TokenProvider>> {
+        futures::executor::block_on(self.inner_token_provider())
+    }
+
     fn url_ref<F>(&self, f: F)
     where
         F: Fn(&GraphUrl) + Sync,
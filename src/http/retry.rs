@@ -0,0 +1,206 @@
+use graph_error::{GraphFailure, GraphHeaders, GraphResult};
+use std::future::Future;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// Status codes that Graph uses to signal that a request was throttled
+// or that the service could not handle the request at the time.
+static RETRY_STATUS_CODES: [u16; 3] = [429, 503, 504];
+
+/// Policy for retrying requests that were throttled (429) or failed because
+/// the service was unavailable (503) or timed out (504).
+///
+/// When the response includes a Retry-After header the delay given by the
+/// service is used. Otherwise the delay grows exponentially from the base delay
+/// with a random jitter added. Either delay is capped at the max delay.
+///
+/// The default policy does not retry requests.
+///
+/// # Example
+/// ```
+/// use graph_rs::client::Graph;
+/// use graph_rs::http::RetryPolicy;
+/// use std::time::Duration;
+///
+/// let client = Graph::new("ACCESS_TOKEN");
+/// client.set_retry_policy(
+///     RetryPolicy::new(5)
+///         .base_delay(Duration::from_millis(500))
+///         .max_delay(Duration::from_secs(30)),
+/// );
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    jitter: bool,
+}
+
+impl RetryPolicy {
+    /// Create a retry policy that sends a request at most `max_attempts` times.
+    pub fn new(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: max_attempts.max(1),
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(60),
+            jitter: true,
+        }
+    }
+
+    /// A policy that sends each request once.
+    pub fn none() -> RetryPolicy {
+        RetryPolicy::new(1)
+    }
+
+    pub fn base_delay(mut self, delay: Duration) -> RetryPolicy {
+        self.base_delay = delay;
+        self
+    }
+
+    pub fn max_delay(mut self, delay: Duration) -> RetryPolicy {
+        self.max_delay = delay;
+        self
+    }
+
+    pub fn jitter(mut self, value: bool) -> RetryPolicy {
+        self.jitter = value;
+        self
+    }
+
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    pub fn is_retry_status(status: u16) -> bool {
+        RETRY_STATUS_CODES.contains(&status)
+    }
+
+    /// The delay before the next attempt. The attempt starts at 1 for the
+    /// first request that was sent.
+    pub fn delay(&self, attempt: u32, headers: &GraphHeaders) -> Duration {
+        if let Some(seconds) = headers.retry_after() {
+            return Duration::from_secs(seconds).min(self.max_delay);
+        }
+
+        let exponent = attempt.saturating_sub(1).min(16);
+        let mut delay = self
+            .base_delay
+            .checked_mul(2u32.pow(exponent))
+            .unwrap_or(self.max_delay);
+
+        if self.jitter {
+            delay += self.random_jitter();
+        }
+
+        delay.min(self.max_delay)
+    }
+
+    // A value between zero and the base delay. This only needs to spread
+    // out requests from the same process so the sub second nanos of the
+    // current time are random enough.
+    fn random_jitter(&self) -> Duration {
+        let base = self.base_delay.as_millis() as u64;
+        if base == 0 {
+            return Duration::from_millis(0);
+        }
+
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.subsec_nanos())
+            .unwrap_or_default();
+        Duration::from_millis(u64::from(nanos) % base)
+    }
+
+    pub fn send(
+        &self,
+        builder: reqwest::blocking::RequestBuilder,
+    ) -> GraphResult<reqwest::blocking::Response> {
+        self.send_with(builder, |builder| {
+            builder.send().map_err(GraphFailure::from)
+        })
+    }
+
+    /// Send the request using the given function for each attempt.
+    pub fn send_with<F>(
+        &self,
+        builder: reqwest::blocking::RequestBuilder,
+        send: F,
+    ) -> GraphResult<reqwest::blocking::Response>
+    where
+        F: Fn(reqwest::blocking::RequestBuilder) -> GraphResult<reqwest::blocking::Response>,
+    {
+        let mut builder = builder;
+        let mut attempt = 1;
+
+        loop {
+            // Requests with a streaming body cannot be cloned and
+            // therefore are only sent once.
+            let next = if attempt < self.max_attempts {
+                builder.try_clone()
+            } else {
+                None
+            };
+
+            let response = send(builder)?;
+            match next {
+                Some(next) if RetryPolicy::is_retry_status(response.status().as_u16()) => {
+                    let delay = self.delay(attempt, &GraphHeaders::from(&response));
+                    thread::sleep(delay);
+                    builder = next;
+                    attempt += 1;
+                },
+                _ => return Ok(response),
+            }
+        }
+    }
+
+    pub async fn send_async(
+        &self,
+        builder: reqwest::RequestBuilder,
+    ) -> GraphResult<reqwest::Response> {
+        self.send_with_async(builder, |builder| async move {
+            builder.send().await.map_err(GraphFailure::from)
+        })
+        .await
+    }
+
+    /// Send the request using the given function for each attempt.
+    pub async fn send_with_async<F, Fut>(
+        &self,
+        builder: reqwest::RequestBuilder,
+        send: F,
+    ) -> GraphResult<reqwest::Response>
+    where
+        F: Fn(reqwest::RequestBuilder) -> Fut,
+        Fut: Future<Output = GraphResult<reqwest::Response>>,
+    {
+        let mut builder = builder;
+        let mut attempt = 1;
+
+        loop {
+            let next = if attempt < self.max_attempts {
+                builder.try_clone()
+            } else {
+                None
+            };
+
+            let response = send(builder).await?;
+            match next {
+                Some(next) if RetryPolicy::is_retry_status(response.status().as_u16()) => {
+                    let delay = self.delay(attempt, &GraphHeaders::from(&response));
+                    tokio::time::delay_for(delay).await;
+                    builder = next;
+                    attempt += 1;
+                },
+                _ => return Ok(response),
+            }
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy::none()
+    }
+}
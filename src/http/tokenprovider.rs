@@ -0,0 +1,297 @@
+use async_trait::async_trait;
+use graph_error::{GraphFailure, GraphHeaders, GraphResult};
+use graph_oauth::oauth::{AccessToken, GrantType, OAuth};
+use std::fmt::{Debug, Formatter};
+use std::future::Future;
+use std::sync::Mutex;
+
+/// Provides the bearer token used for each request.
+///
+/// The client asks the provider for a token before each request is sent
+/// and asks it to refresh the token when a request fails with a 401 and
+/// an invalid_token error. This allows long running clients to keep
+/// working after the original access token has expired.
+#[async_trait]
+pub trait TokenProvider: Send + Sync {
+    /// The bearer token to use for the next request. Implementations
+    /// should refresh the token if it has expired.
+    fn bearer_token(&self) -> GraphResult<String>;
+
+    /// Refresh the token even if it has not expired and return
+    /// the new bearer token.
+    fn refresh(&self) -> GraphResult<String>;
+
+    /// The bearer token to use for the next request from an async client.
+    async fn bearer_token_async(&self) -> GraphResult<String>;
+
+    /// Refresh the token from an async client.
+    async fn refresh_async(&self) -> GraphResult<String>;
+}
+
+/// A token provider that refreshes the access token stored in OAuth.
+///
+/// When the stored access token has a refresh token it is used to request
+/// a new access token. Otherwise the client credentials and resource owner
+/// password credentials grants request a new access token with the stored
+/// credentials. The new access token replaces the stored access token.
+///
+/// # Example
+/// ```rust,ignore
+/// use graph_rs::client::Graph;
+/// use graph_rs::http::OAuthTokenProvider;
+/// use graph_rs::oauth::{GrantType, OAuth};
+///
+/// let mut oauth = OAuth::new();
+/// oauth
+///     .client_id("<CLIENT_ID>")
+///     .client_secret("<CLIENT_SECRET>")
+///     .refresh_token_url("https://login.microsoftonline.com/common/oauth2/v2.0/token");
+/// oauth.access_token(access_token);
+///
+/// let client = Graph::from(OAuthTokenProvider::new(oauth, GrantType::AuthorizationCode));
+/// ```
+pub struct OAuthTokenProvider {
+    oauth: Mutex<OAuth>,
+    grant: GrantType,
+    refresh_lock: tokio::sync::Mutex<()>,
+}
+
+impl OAuthTokenProvider {
+    pub fn new(oauth: OAuth, grant: GrantType) -> OAuthTokenProvider {
+        OAuthTokenProvider {
+            oauth: Mutex::new(oauth),
+            grant,
+            refresh_lock: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    pub fn grant(&self) -> GrantType {
+        self.grant
+    }
+
+    /// A copy of the OAuth instance including the current access token.
+    /// This can be used to store the refreshed access token.
+    pub fn oauth(&self) -> GraphResult<OAuth> {
+        Ok(self.lock()?.clone())
+    }
+
+    fn lock(&self) -> GraphResult<std::sync::MutexGuard<OAuth>> {
+        self.oauth
+            .lock()
+            .map_err(|_| GraphFailure::invalid("token provider lock"))
+    }
+
+    // Returns true if the access token should be requested using the refresh
+    // token and false if a new access token should be requested.
+    fn use_refresh_token(&self, oauth: &OAuth) -> GraphResult<bool> {
+        if oauth.get_refresh_token().is_ok() {
+            return Ok(true);
+        }
+
+        match self.grant {
+            GrantType::ClientCredentials | GrantType::ResourceOwnerPasswordCredentials => Ok(false),
+            _ => Err(GraphFailure::invalid(
+                "refresh token or grant type that can request a new access token",
+            )),
+        }
+    }
+
+    // The bearer token of the stored access token if it has not expired.
+    fn current_token(oauth: &OAuth) -> Option<String> {
+        oauth
+            .get_access_token()
+            .filter(|access_token| !access_token.is_expired())
+            .map(|access_token| access_token.bearer_token().to_string())
+    }
+
+    // Refresh tokens are not always returned when refreshing an access token
+    // so the previous refresh token is kept when the new one is missing.
+    fn store(oauth: &mut OAuth, mut access_token: AccessToken) -> String {
+        if access_token.clone().refresh_token().is_none() {
+            if let Ok(refresh_token) = oauth.get_refresh_token() {
+                access_token.set_refresh_token(refresh_token.as_str());
+            }
+        }
+        let bearer_token = access_token.bearer_token().to_string();
+        oauth.access_token(access_token);
+        bearer_token
+    }
+
+    // The token cache is only read when the stored access token has expired. A
+    // refresh after a request failed with an invalid token skips the cache
+    // because the cache could return the same token.
+    fn request_access_token(
+        &self,
+        oauth: &mut OAuth,
+        read_cache: bool,
+    ) -> GraphResult<AccessToken> {
+        let use_refresh_token = self.use_refresh_token(oauth)?;
+        let selector = oauth.build();
+        let mut grant = match self.grant {
+            GrantType::CodeFlow => selector.code_flow(),
+            GrantType::AuthorizationCode => selector.authorization_code_grant(),
+            GrantType::OpenId => selector.open_id_connect(),
+            GrantType::ClientCredentials => selector.client_credentials(),
+            GrantType::ResourceOwnerPasswordCredentials => {
+                selector.resource_owner_password_credentials()
+            },
+            // The device code and on behalf of grants need the user to sign
+            // in again so only the refresh token can be used.
+            GrantType::DeviceCode => return selector.device_code().refresh_token().send(),
+            GrantType::OnBehalfOf => return selector.on_behalf_of().refresh_token()?.send(),
+            GrantType::TokenFlow | GrantType::Implicit => {
+                return Err(GraphFailure::invalid(
+                    "grant type that can refresh access tokens",
+                ));
+            },
+        };
+
+        if use_refresh_token {
+            grant.refresh_token().send()
+        } else {
+            grant.access_token().read_cache(read_cache).send()
+        }
+    }
+
+    async fn request_access_token_async(
+        &self,
+        mut oauth: OAuth,
+        read_cache: bool,
+    ) -> GraphResult<AccessToken> {
+        let use_refresh_token = self.use_refresh_token(&oauth)?;
+        let selector = oauth.build_async();
+        let mut grant = match self.grant {
+            GrantType::CodeFlow => selector.code_flow(),
+            GrantType::AuthorizationCode => selector.authorization_code_grant(),
+            GrantType::OpenId => selector.open_id_connect(),
+            GrantType::ClientCredentials => selector.client_credentials(),
+            GrantType::ResourceOwnerPasswordCredentials => {
+                selector.resource_owner_password_credentials()
+            },
+            GrantType::DeviceCode => {
+                return selector.device_code().refresh_token().send().await;
+            },
+            GrantType::OnBehalfOf => {
+                return selector.on_behalf_of().refresh_token()?.send().await;
+            },
+            GrantType::TokenFlow | GrantType::Implicit => {
+                return Err(GraphFailure::invalid(
+                    "grant type that can refresh access tokens",
+                ));
+            },
+        };
+
+        if use_refresh_token {
+            grant.refresh_token().send().await
+        } else {
+            grant.access_token().read_cache(read_cache).send().await
+        }
+    }
+}
+
+#[async_trait]
+impl TokenProvider for OAuthTokenProvider {
+    fn bearer_token(&self) -> GraphResult<String> {
+        let mut oauth = self.lock()?;
+        if let Some(bearer_token) = OAuthTokenProvider::current_token(&oauth) {
+            return Ok(bearer_token);
+        }
+        let access_token = self.request_access_token(&mut oauth, true)?;
+        Ok(OAuthTokenProvider::store(&mut oauth, access_token))
+    }
+
+    fn refresh(&self) -> GraphResult<String> {
+        let mut oauth = self.lock()?;
+        let access_token = self.request_access_token(&mut oauth, false)?;
+        Ok(OAuthTokenProvider::store(&mut oauth, access_token))
+    }
+
+    // Only one async request refreshes the token at a time. The OAuth lock
+    // is not held while the request is sent so that it is never held across
+    // an await. Requests that waited for the refresh use the new token.
+    async fn bearer_token_async(&self) -> GraphResult<String> {
+        if let Some(bearer_token) = OAuthTokenProvider::current_token(&self.oauth()?) {
+            return Ok(bearer_token);
+        }
+
+        let _refresh = self.refresh_lock.lock().await;
+        let oauth = self.oauth()?;
+        if let Some(bearer_token) = OAuthTokenProvider::current_token(&oauth) {
+            return Ok(bearer_token);
+        }
+        let access_token = self.request_access_token_async(oauth, true).await?;
+        let mut oauth = self.lock()?;
+        Ok(OAuthTokenProvider::store(&mut oauth, access_token))
+    }
+
+    async fn refresh_async(&self) -> GraphResult<String> {
+        let stale_token = OAuthTokenProvider::current_token(&self.oauth()?);
+
+        let _refresh = self.refresh_lock.lock().await;
+        let oauth = self.oauth()?;
+        if let Some(bearer_token) = OAuthTokenProvider::current_token(&oauth) {
+            if stale_token.as_ref() != Some(&bearer_token) {
+                return Ok(bearer_token);
+            }
+        }
+        let access_token = self.request_access_token_async(oauth, false).await?;
+        let mut oauth = self.lock()?;
+        Ok(OAuthTokenProvider::store(&mut oauth, access_token))
+    }
+}
+
+impl Debug for OAuthTokenProvider {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OAuthTokenProvider")
+            .field("grant", &self.grant)
+            .finish()
+    }
+}
+
+// Sends the request with the bearer token of the token provider. If the
+// request fails with an invalid_token error the token is refreshed and
+// the request is sent once more. The token that was used is set on token.
+pub(crate) fn send_with_token_provider<F>(
+    token_provider: &dyn TokenProvider,
+    builder: reqwest::blocking::RequestBuilder,
+    token: &mut String,
+    send: F,
+) -> GraphResult<reqwest::blocking::Response>
+where
+    F: Fn(reqwest::blocking::RequestBuilder) -> GraphResult<reqwest::blocking::Response>,
+{
+    *token = token_provider.bearer_token()?;
+    let next = builder.try_clone();
+    let response = send(builder.bearer_auth(token.as_str()))?;
+
+    match next {
+        Some(next) if GraphHeaders::from(&response).is_invalid_token() => {
+            *token = token_provider.refresh()?;
+            send(next.bearer_auth(token.as_str()))
+        },
+        _ => Ok(response),
+    }
+}
+
+pub(crate) async fn send_with_token_provider_async<F, Fut>(
+    token_provider: &dyn TokenProvider,
+    builder: reqwest::RequestBuilder,
+    token: &mut String,
+    send: F,
+) -> GraphResult<reqwest::Response>
+where
+    F: Fn(reqwest::RequestBuilder) -> Fut,
+    Fut: Future<Output = GraphResult<reqwest::Response>>,
+{
+    *token = token_provider.bearer_token_async().await?;
+    let next = builder.try_clone();
+    let response = send(builder.bearer_auth(token.as_str())).await?;
+
+    match next {
+        Some(next) if GraphHeaders::from(&response).is_invalid_token() => {
+            *token = token_provider.refresh_async().await?;
+            send(next.bearer_auth(token.as_str())).await
+        },
+        _ => Ok(response),
+    }
+}
@@ -1,15 +1,19 @@
 use crate::http::{
     AsyncClient, AsyncHttpClient, AsyncIterator, AsyncTryFrom, BlockingClient, BlockingHttpClient,
-    GraphResponse, HttpByteRange, RequestAttribute, RequestClient,
+    ChunkedFile, GraphResponse, Pipeline, RequestAttribute, RequestClient, RetryPolicy,
+    UploadContent,
 };
 use crate::url::GraphUrl;
 use async_trait::async_trait;
-use graph_error::{ErrorMessage, GraphError, GraphFailure, GraphResult};
+use graph_error::{ErrorMessage, GraphError, GraphFailure, GraphHeaders, GraphResult};
 use reqwest::header::{HeaderMap, HeaderValue, CONTENT_LENGTH, CONTENT_RANGE, CONTENT_TYPE};
 use serde::export::Formatter;
 use std::convert::TryFrom;
 use std::fmt::Debug;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
 
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct Session {
@@ -29,6 +33,29 @@ pub trait StartUploadSession<C> {
     fn start_upload_session(&mut self) -> GraphResult<UploadSessionClient<C>>;
 }
 
+/// The state of an upload session that can be saved and used to
+/// resume the upload after the process exits.
+///
+/// # Example
+/// ```rust,ignore
+/// # use graph_rs::http::{UploadSessionClient, UploadSessionState};
+/// // Save the state before uploading.
+/// let state = session.state();
+/// std::fs::write("./upload_session.json", serde_json::to_vec(&state)?)?;
+///
+/// // Resume the upload from the nextExpectedRanges of the session.
+/// let state: UploadSessionState = serde_json::from_slice(&std::fs::read("./upload_session.json")?)?;
+/// let session = UploadSessionClient::resume(state)?;
+/// ```
+#[derive(Default, Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct UploadSessionState {
+    pub upload_url: String,
+    pub file: PathBuf,
+    pub chunk_size: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration_date_time: Option<String>,
+}
+
 pub enum NextSession {
     Next(GraphResponse<serde_json::Value>),
     Done(GraphResponse<serde_json::Value>),
@@ -36,47 +63,208 @@ pub enum NextSession {
 
 impl NextSession {
     fn from_response(
-        res: (u16, GraphResult<GraphResponse<serde_json::Value>>),
-    ) -> Option<GraphResult<NextSession>> {
-        if let Ok(value) = res.1 {
-            return if res.0.eq(&200) || res.0.eq(&201) {
-                Some(Ok(NextSession::Done(value)))
-            } else {
-                Some(Ok(NextSession::Next(value)))
-            };
-        } else if let Err(e) = res.1 {
-            return Some(Err(e));
+        status: u16,
+        response: GraphResult<GraphResponse<serde_json::Value>>,
+    ) -> GraphResult<NextSession> {
+        let value = response?;
+        if status.eq(&200) || status.eq(&201) {
+            Ok(NextSession::Done(value))
+        } else {
+            Ok(NextSession::Next(value))
+        }
+    }
+
+    fn from_blocking(response: reqwest::blocking::Response) -> GraphResult<NextSession> {
+        if let Ok(mut error) = GraphError::try_from(&response) {
+            let error_message: GraphResult<ErrorMessage> =
+                response.json().map_err(GraphFailure::from);
+            if let Ok(message) = error_message {
+                error.set_error_message(message);
+            }
+            return Err(GraphFailure::from(error));
+        }
+
+        let status = response.status().as_u16();
+        NextSession::from_response(status, std::convert::TryFrom::try_from(response))
+    }
+
+    async fn from_async(response: reqwest::Response) -> GraphResult<NextSession> {
+        if let Ok(mut error) = GraphError::try_from(&response) {
+            let error_message: GraphResult<ErrorMessage> =
+                response.json().await.map_err(GraphFailure::from);
+            if let Ok(message) = error_message {
+                error.set_error_message(message);
+            }
+            return Err(GraphFailure::from(error));
         }
-        None
+
+        let status = response.status().as_u16();
+        NextSession::from_response(
+            status,
+            AsyncTryFrom::<reqwest::Response>::try_from(response).await,
+        )
     }
 }
 
+/// Uploads a file to an upload session one chunk at a time.
+///
+/// The file is read as each chunk is uploaded so only one chunk is kept
+/// in memory. The size of each chunk can be set using `set_chunk_size`
+/// and must be a multiple of 320 KiB.
+///
+/// A chunk that fails because of a network error or a server error is
+/// retried up to the number of chunk retries. Before retrying, the status
+/// of the upload session is requested and the upload continues from the
+/// nextExpectedRanges of the session. Iteration stops after a chunk fails.
+///
+/// Graph requires the chunks of an upload session to be uploaded in
+/// order, so chunks are uploaded one at a time and never in parallel.
+/// When a response has nextExpectedRanges the upload continues from
+/// those ranges instead of the end of the chunk that was uploaded.
+///
+/// An upload can be resumed after the process exits by saving the
+/// [UploadSessionState](struct.UploadSessionState.html) of the session.
 pub struct UploadSessionClient<C> {
     upload_session_url: String,
-    byte_ranges: HttpByteRange,
+    expiration_date_time: Option<String>,
+    chunks: ChunkedFile,
+    chunk_retries: u32,
+    progress: Option<Arc<dyn Fn(u64, u64) + Send + Sync>>,
     client: C,
 }
 
 impl<C> UploadSessionClient<C> {
+    /// Upload the bytes of the file from start to end, inclusive.
     pub fn from_range<P: AsRef<Path>>(&mut self, start: u64, end: u64, file: P) -> GraphResult<()> {
-        self.byte_ranges = HttpByteRange::from_range(start, end, file)?;
+        let mut chunks = ChunkedFile::new(file)?;
+        chunks.set_chunk_size(self.chunks.chunk_size())?;
+        chunks.set_range(start, end)?;
+        self.chunks = chunks;
         Ok(())
     }
 
     pub fn has_next(&self) -> bool {
-        !self.byte_ranges.is_empty()
+        !self.chunks.is_empty()
     }
 
     pub fn set_file(&mut self, file: PathBuf) -> GraphResult<()> {
-        self.byte_ranges = HttpByteRange::try_from(file)?;
+        self.set_content(UploadContent::File(file))
+    }
+
+    /// Upload bytes in memory or a reader instead of a file. A reader is
+    /// read as each chunk is uploaded.
+    pub fn set_content(&mut self, content: UploadContent) -> GraphResult<()> {
+        let mut chunks = ChunkedFile::from_content(content)?;
+        chunks.set_chunk_size(self.chunks.chunk_size())?;
+        self.chunks = chunks;
         Ok(())
     }
+
+    /// Set the size of each chunk. The size must be a multiple of
+    /// 320 KiB (327,680 bytes) and less than 60 MiB.
+    pub fn set_chunk_size(&mut self, chunk_size: u64) -> GraphResult<()> {
+        self.chunks.set_chunk_size(chunk_size)
+    }
+
+    /// Set the number of times a chunk that failed is retried.
+    pub fn set_chunk_retries(&mut self, chunk_retries: u32) {
+        self.chunk_retries = chunk_retries;
+    }
+
+    /// Set a callback that is called after each chunk is uploaded with
+    /// the number of bytes uploaded and the size of the file.
+    pub fn on_progress<F>(&mut self, progress: F)
+    where
+        F: Fn(u64, u64) + Send + Sync + 'static,
+    {
+        self.progress = Some(Arc::new(progress));
+    }
+
+    pub fn upload_url(&self) -> &str {
+        self.upload_session_url.as_str()
+    }
+
+    pub fn expiration_date_time(&self) -> Option<&String> {
+        self.expiration_date_time.as_ref()
+    }
+
+    /// The number of bytes of the file that have not been uploaded.
+    pub fn bytes_remaining(&self) -> u64 {
+        self.chunks.bytes_remaining()
+    }
+
+    /// The state of the upload session. The file of the state is empty
+    /// if the content is not a file.
+    pub fn state(&self) -> UploadSessionState {
+        UploadSessionState {
+            upload_url: self.upload_session_url.clone(),
+            file: self.chunks.file().to_path_buf(),
+            chunk_size: self.chunks.chunk_size(),
+            expiration_date_time: self.expiration_date_time.clone(),
+        }
+    }
+
+    // The upload continues from the nextExpectedRanges of the response
+    // when they are given. Otherwise the next chunk follows this chunk.
+    fn chunk_uploaded(
+        &mut self,
+        next: NextSession,
+        content_length: u64,
+    ) -> GraphResult<NextSession> {
+        let updated = match &next {
+            NextSession::Next(response) if response.body()["nextExpectedRanges"].is_array() => {
+                self.set_upload_status(response.body())
+            },
+            NextSession::Next(_) => {
+                self.chunks.advance(content_length);
+                Ok(())
+            },
+            NextSession::Done(_) => {
+                self.chunks.clear();
+                Ok(())
+            },
+        };
+        if let Err(err) = updated {
+            return self.stop(err);
+        }
+        if let Some(progress) = self.progress.as_ref() {
+            let file_size = self.chunks.file_size();
+            progress(file_size - self.chunks.bytes_remaining(), file_size);
+        }
+        Ok(next)
+    }
+
+    fn stop(&mut self, err: GraphFailure) -> GraphResult<NextSession> {
+        self.chunks.clear();
+        Err(err)
+    }
+
+    fn set_upload_status(&mut self, status: &serde_json::Value) -> GraphResult<()> {
+        if let Some(expiration_date_time) = status["expirationDateTime"].as_str() {
+            self.expiration_date_time = Some(expiration_date_time.to_string());
+        }
+        let ranges: Vec<&str> = status["nextExpectedRanges"]
+            .as_array()
+            .map(|ranges| ranges.iter().filter_map(|range| range.as_str()).collect())
+            .unwrap_or_default();
+        self.chunks.set_next_expected_ranges(&ranges)
+    }
 }
 
 impl<C> UploadSessionClient<C>
 where
     C: RequestClient,
 {
+    /// Set the middleware that each upload request is sent through.
+    pub fn set_pipeline(&self, pipeline: Pipeline) {
+        self.client.set_pipeline(pipeline);
+    }
+
+    /// Set the retry policy used for each upload request.
+    pub fn set_retry_policy(&self, retry_policy: RetryPolicy) {
+        self.client.set_retry_policy(retry_policy);
+    }
+
     // The Authorization header and bearer token should only be sent
     // when issuing the POST during the first step.
     fn build_next_request(&self, body: Vec<u8>, content_length: u64, content_range: String) {
@@ -99,13 +287,47 @@ where
             ])
             .unwrap();
     }
+
+    fn build_status_request(&self) {
+        let mut header_map = HeaderMap::new();
+        header_map.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        self.client
+            .set_request(vec![
+                RequestAttribute::Headers(header_map),
+                RequestAttribute::Method(reqwest::Method::GET),
+            ])
+            .unwrap();
+    }
+
+    // Network errors, throttling and server errors may succeed
+    // when the chunk is sent again.
+    fn is_chunk_retry(&self, err: &GraphFailure, attempt: u32) -> bool {
+        if attempt >= self.chunk_retries {
+            return false;
+        }
+        match err {
+            GraphFailure::ReqwestError(_) | GraphFailure::HyperError(_) => true,
+            GraphFailure::GraphError(error) => error.code == 429 || error.code >= 500,
+            _ => false,
+        }
+    }
+
+    fn chunk_retry_delay(&self, err: &GraphFailure, attempt: u32) -> Duration {
+        let headers = match err {
+            GraphFailure::GraphError(error) => error.headers.clone().unwrap_or_default(),
+            _ => GraphHeaders::default(),
+        };
+        self.client.retry_policy().delay(attempt, &headers)
+    }
 }
 
 impl<C> Debug for UploadSessionClient<C> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("UploadSessionClient")
             .field("upload_session_url", &self.upload_session_url)
-            .field("byte_ranges", &self.byte_ranges)
+            .field("expiration_date_time", &self.expiration_date_time)
+            .field("chunks", &self.chunks)
+            .field("chunk_retries", &self.chunk_retries)
             .finish()
     }
 }
@@ -117,11 +339,39 @@ impl UploadSessionClient<BlockingHttpClient> {
         let url = upload_session["uploadUrl"].as_str()?;
         Ok(UploadSessionClient {
             upload_session_url: url.to_string(),
-            byte_ranges: Default::default(),
+            expiration_date_time: upload_session["expirationDateTime"]
+                .as_str()
+                .map(|s| s.to_string()),
+            chunks: Default::default(),
+            chunk_retries: 0,
+            progress: None,
             client: BlockingHttpClient::from(BlockingClient::new_blocking(GraphUrl::parse(url)?)),
         })
     }
 
+    /// Resume an upload session using the state saved from the session.
+    /// The upload continues from the nextExpectedRanges of the session.
+    pub fn resume(
+        state: UploadSessionState,
+    ) -> GraphResult<UploadSessionClient<BlockingHttpClient>> {
+        let mut session = UploadSessionClient::new(serde_json::json!({
+            "uploadUrl": state.upload_url,
+            "expirationDateTime": state.expiration_date_time,
+        }))?;
+        session.set_file(state.file)?;
+        session.set_chunk_size(state.chunk_size)?;
+        session.update_ranges()?;
+        Ok(session)
+    }
+
+    /// Get the status of the upload session and continue the upload
+    /// from the nextExpectedRanges of the session.
+    pub fn update_ranges(&mut self) -> GraphResult<()> {
+        self.build_status_request();
+        let response: GraphResponse<serde_json::Value> = self.client.execute()?;
+        self.set_upload_status(response.body())
+    }
+
     pub fn cancel(&mut self) -> reqwest::blocking::RequestBuilder {
         self.client.set_method(reqwest::Method::DELETE);
         self.client.build()
@@ -136,27 +386,28 @@ impl Iterator for UploadSessionClient<BlockingHttpClient> {
     type Item = GraphResult<NextSession>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let (body, content_length, content_range) = self.byte_ranges.pop_front()?;
-        self.build_next_request(body, content_length, content_range);
-        let response = self.client.response();
-
-        if let Ok(response) = response {
-            if let Ok(mut error) = GraphError::try_from(&response) {
-                let error_message: GraphResult<ErrorMessage> =
-                    response.json().map_err(GraphFailure::from);
-                if let Ok(message) = error_message {
-                    error.set_error_message(message);
-                }
-                return Some(Err(GraphFailure::from(error)));
-            }
+        let mut attempt = 0;
+        loop {
+            let (body, content_length, content_range) = match self.chunks.next_chunk() {
+                Ok(chunk) => chunk?,
+                Err(err) => return Some(self.stop(err)),
+            };
+            self.build_next_request(body, content_length, content_range);
+
+            let delay = match self.client.response().and_then(NextSession::from_blocking) {
+                Ok(next) => return Some(self.chunk_uploaded(next, content_length)),
+                Err(err) if self.is_chunk_retry(&err, attempt) => {
+                    self.chunk_retry_delay(&err, attempt + 1)
+                },
+                Err(err) => return Some(self.stop(err)),
+            };
 
-            let status = response.status().as_u16();
-            let result = std::convert::TryFrom::try_from(response);
-            return NextSession::from_response((status, result));
-        } else if let Err(e) = response {
-            return Some(Err(e));
+            attempt += 1;
+            thread::sleep(delay);
+            if let Err(err) = self.update_ranges() {
+                return Some(self.stop(err));
+            }
         }
-        None
     }
 }
 
@@ -167,14 +418,52 @@ impl UploadSessionClient<AsyncHttpClient> {
         let url = upload_session["uploadUrl"].as_str()?;
         Ok(UploadSessionClient {
             upload_session_url: url.to_string(),
-            byte_ranges: Default::default(),
+            expiration_date_time: upload_session["expirationDateTime"]
+                .as_str()
+                .map(|s| s.to_string()),
+            chunks: Default::default(),
+            chunk_retries: 0,
+            progress: None,
             client: AsyncHttpClient::from(AsyncClient::new_async(GraphUrl::parse(url)?)),
         })
     }
 
+    /// Resume an upload session using the state saved from the session.
+    /// The upload continues from the nextExpectedRanges of the session.
+    pub async fn resume_async(
+        state: UploadSessionState,
+    ) -> GraphResult<UploadSessionClient<AsyncHttpClient>> {
+        let mut session = UploadSessionClient::new_async(serde_json::json!({
+            "uploadUrl": state.upload_url,
+            "expirationDateTime": state.expiration_date_time,
+        }))?;
+        session.set_file_async(state.file).await?;
+        session.set_chunk_size(state.chunk_size)?;
+        session.update_ranges().await?;
+        Ok(session)
+    }
+
     pub async fn set_file_async(&mut self, file: PathBuf) -> GraphResult<()> {
-        self.byte_ranges = HttpByteRange::try_from_async(file).await?;
-        Ok(())
+        let metadata = tokio::fs::metadata(file.as_path()).await?;
+        if !metadata.is_file() {
+            return Err(GraphFailure::invalid("file for upload session"));
+        }
+        self.set_file(file)
+    }
+
+    pub async fn set_content_async(&mut self, content: UploadContent) -> GraphResult<()> {
+        match content {
+            UploadContent::File(file) => self.set_file_async(file).await,
+            content => self.set_content(content),
+        }
+    }
+
+    /// Get the status of the upload session and continue the upload
+    /// from the nextExpectedRanges of the session.
+    pub async fn update_ranges(&mut self) -> GraphResult<()> {
+        self.build_status_request();
+        let response: GraphResponse<serde_json::Value> = self.client.execute().await?;
+        self.set_upload_status(response.body())
     }
 
     pub async fn cancel(&mut self) -> reqwest::RequestBuilder {
@@ -192,26 +481,31 @@ impl AsyncIterator for UploadSessionClient<AsyncHttpClient> {
     type Item = GraphResult<NextSession>;
 
     async fn next(&mut self) -> Option<Self::Item> {
-        let (body, content_length, content_range) = self.byte_ranges.pop_front()?;
-        self.build_next_request(body, content_length, content_range);
-        let response = self.client.response().await;
-
-        if let Err(e) = response {
-            return Some(Err(e));
-        } else if let Ok(response) = response {
-            if let Ok(mut error) = GraphError::try_from(&response) {
-                let error_message: GraphResult<ErrorMessage> =
-                    response.json().await.map_err(GraphFailure::from);
-                if let Ok(message) = error_message {
-                    error.set_error_message(message);
-                }
-                return Some(Err(GraphFailure::from(error)));
-            }
+        let mut attempt = 0;
+        loop {
+            let (body, content_length, content_range) = match self.chunks.next_chunk_async().await {
+                Ok(chunk) => chunk?,
+                Err(err) => return Some(self.stop(err)),
+            };
+            self.build_next_request(body, content_length, content_range);
 
-            let status = response.status().as_u16();
-            let result = AsyncTryFrom::<reqwest::Response>::try_from(response).await;
-            return NextSession::from_response((status, result));
+            let next = match self.client.response().await {
+                Ok(response) => NextSession::from_async(response).await,
+                Err(err) => Err(err),
+            };
+            let delay = match next {
+                Ok(next) => return Some(self.chunk_uploaded(next, content_length)),
+                Err(err) if self.is_chunk_retry(&err, attempt) => {
+                    self.chunk_retry_delay(&err, attempt + 1)
+                },
+                Err(err) => return Some(self.stop(err)),
+            };
+
+            attempt += 1;
+            tokio::time::delay_for(delay).await;
+            if let Err(err) = self.update_ranges().await {
+                return Some(self.stop(err));
+            }
         }
-        None
     }
 }
@@ -86,6 +86,8 @@ pub mod attachments;
 pub mod calendar;
 /// Contacts request client.
 pub mod contacts;
+/// Directory roles and directory objects request client.
+pub mod directory;
 /// OneDrive request client.
 pub mod drive;
 /// Education request client.
@@ -96,15 +98,25 @@ pub mod groups;
 pub mod http;
 /// Mail request client.
 pub mod mail;
+/// Typed models of Microsoft Graph resources.
+pub mod models;
 /// OneNote request client.
 pub mod onenote;
 /// Planner request client.
 pub mod planner;
+/// Subscriptions request client and change notifications.
+pub mod subscriptions;
+/// Teams and chats request client.
+pub mod teams;
+/// Microsoft To Do request client.
+pub mod todo;
 /// Types used crate wide.
 pub mod types;
 /// Url type for graph-rs.
 pub mod url;
 
+/// The v1.0 and beta urls of Microsoft Graph in the global cloud. Use
+/// `Graph::set_azure_cloud` to send requests to a national cloud.
 pub static GRAPH_URL: &str = "https://graph.microsoft.com/v1.0";
 pub static GRAPH_URL_BETA: &str = "https://graph.microsoft.com/beta";
 
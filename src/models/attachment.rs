@@ -0,0 +1,126 @@
+use serde_json::Value;
+use std::collections::HashMap;
+
+odata_type!(
+    /// A file, item or link attached to a message, event or post.
+    /// [attachment](https://docs.microsoft.com/en-us/graph/api/resources/attachment?view=graph-rest-1.0)
+    Attachment,
+    "#microsoft.graph.fileAttachment" => File(FileAttachment),
+    "#microsoft.graph.itemAttachment" => Item(ItemAttachment),
+    "#microsoft.graph.referenceAttachment" => Reference(ReferenceAttachment),
+);
+
+impl Attachment {
+    pub fn id(&self) -> Option<&str> {
+        match self {
+            Attachment::File(attachment) => attachment.id.as_deref(),
+            Attachment::Item(attachment) => attachment.id.as_deref(),
+            Attachment::Reference(attachment) => attachment.id.as_deref(),
+            Attachment::Other(value) => value["id"].as_str(),
+        }
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            Attachment::File(attachment) => attachment.name.as_deref(),
+            Attachment::Item(attachment) => attachment.name.as_deref(),
+            Attachment::Reference(attachment) => attachment.name.as_deref(),
+            Attachment::Other(value) => value["name"].as_str(),
+        }
+    }
+}
+
+/// A file attached to a message, event or post.
+/// [fileAttachment](https://docs.microsoft.com/en-us/graph/api/resources/fileattachment?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct FileAttachment {
+    #[serde(rename = "@odata.type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    odata_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    size: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_inline: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_modified_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content_location: Option<String>,
+    /// The base64 encoded contents of the file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content_bytes: Option<String>,
+    #[serde(flatten)]
+    additional_data: HashMap<String, Value>,
+}
+
+/// A message, event or contact attached to a message, event or post.
+/// [itemAttachment](https://docs.microsoft.com/en-us/graph/api/resources/itemattachment?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct ItemAttachment {
+    #[serde(rename = "@odata.type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    odata_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    size: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_inline: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_modified_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    item: Option<Value>,
+    #[serde(flatten)]
+    additional_data: HashMap<String, Value>,
+}
+
+/// A link to a file or folder attached to a message, event or post.
+/// [referenceAttachment](https://docs.microsoft.com/en-us/graph/api/resources/referenceattachment?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct ReferenceAttachment {
+    #[serde(rename = "@odata.type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    odata_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    size: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_inline: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_modified_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    provider_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    permission: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_folder: Option<bool>,
+    #[serde(flatten)]
+    additional_data: HashMap<String, Value>,
+}
@@ -0,0 +1,162 @@
+use crate::models::{DateTimeTimeZone, EmailAddress, ItemBody, PhysicalAddress, Recipient};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// An event in a calendar.
+/// [event](https://docs.microsoft.com/en-us/graph/api/resources/event?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct Event {
+    #[serde(rename = "@odata.type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    odata_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subject: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body: Option<ItemBody>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body_preview: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start: Option<DateTimeTimeZone>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end: Option<DateTimeTimeZone>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location: Option<Location>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    locations: Option<Vec<Location>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attendees: Option<Vec<Attendee>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    organizer: Option<Recipient>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_all_day: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_cancelled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_online_meeting: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    online_meeting_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    show_as: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    importance: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sensitivity: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    categories: Option<Vec<String>>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    event_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    series_master_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    web_link: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    created_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_modified_date_time: Option<String>,
+    #[serde(flatten)]
+    additional_data: HashMap<String, Value>,
+}
+
+/// The location of an event.
+/// [location](https://docs.microsoft.com/en-us/graph/api/resources/location?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct Location {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location_email_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location_uri: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    address: Option<PhysicalAddress>,
+}
+
+/// An attendee of an event and their response.
+/// [attendee](https://docs.microsoft.com/en-us/graph/api/resources/attendee?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct Attendee {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    email_address: Option<EmailAddress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<ResponseStatus>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attendee_type: Option<String>,
+}
+
+/// [responseStatus](https://docs.microsoft.com/en-us/graph/api/resources/responsestatus?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[set = "pub"]
+#[get = "pub"]
+pub struct ResponseStatus {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    response: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    time: Option<String>,
+}
+
+/// A container of events.
+/// [calendar](https://docs.microsoft.com/en-us/graph/api/resources/calendar?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct Calendar {
+    #[serde(rename = "@odata.type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    odata_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    change_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    can_edit: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    can_share: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    can_view_private_items: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<EmailAddress>,
+    #[serde(flatten)]
+    additional_data: HashMap<String, Value>,
+}
+
+/// A group of calendars.
+/// [calendarGroup](https://docs.microsoft.com/en-us/graph/api/resources/calendargroup?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct CalendarGroup {
+    #[serde(rename = "@odata.type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    odata_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    class_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    change_key: Option<String>,
+    #[serde(flatten)]
+    additional_data: HashMap<String, Value>,
+}
@@ -0,0 +1,101 @@
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// An identity of a user, device or application.
+/// [identity](https://docs.microsoft.com/en-us/graph/api/resources/identity?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct Identity {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    display_name: Option<String>,
+    #[serde(flatten)]
+    additional_data: HashMap<String, Value>,
+}
+
+/// The identities of an actor such as the user that created an item.
+/// [identitySet](https://docs.microsoft.com/en-us/graph/api/resources/identityset?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[set = "pub"]
+#[get = "pub"]
+pub struct IdentitySet {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    application: Option<Identity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    device: Option<Identity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user: Option<Identity>,
+    #[serde(flatten)]
+    additional_data: HashMap<String, Value>,
+}
+
+/// The body of a message or event.
+/// [itemBody](https://docs.microsoft.com/en-us/graph/api/resources/itembody?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct ItemBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content: Option<String>,
+}
+
+/// The name and email address of a person or entity.
+/// [emailAddress](https://docs.microsoft.com/en-us/graph/api/resources/emailaddress?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[set = "pub"]
+#[get = "pub"]
+pub struct EmailAddress {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    address: Option<String>,
+}
+
+/// The sender or a recipient of a message or event.
+/// [recipient](https://docs.microsoft.com/en-us/graph/api/resources/recipient?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct Recipient {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    email_address: Option<EmailAddress>,
+}
+
+/// A date and time with the time zone it is in.
+/// [dateTimeTimeZone](https://docs.microsoft.com/en-us/graph/api/resources/datetimetimezone?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct DateTimeTimeZone {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    time_zone: Option<String>,
+}
+
+/// A street address.
+/// [physicalAddress](https://docs.microsoft.com/en-us/graph/api/resources/physicaladdress?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct PhysicalAddress {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    street: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    city: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    country_or_region: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    postal_code: Option<String>,
+}
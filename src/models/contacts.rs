@@ -0,0 +1,79 @@
+use crate::models::{EmailAddress, PhysicalAddress};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// A contact in a contact folder.
+/// [contact](https://docs.microsoft.com/en-us/graph/api/resources/contact?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct Contact {
+    #[serde(rename = "@odata.type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    odata_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    given_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    middle_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    surname: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nick_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    company_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    department: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    job_title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    email_addresses: Option<Vec<EmailAddress>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    business_phones: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    home_phones: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mobile_phone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    business_address: Option<PhysicalAddress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    home_address: Option<PhysicalAddress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    birthday: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    categories: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parent_folder_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    created_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_modified_date_time: Option<String>,
+    #[serde(flatten)]
+    additional_data: HashMap<String, Value>,
+}
+
+/// A folder of contacts.
+/// [contactFolder](https://docs.microsoft.com/en-us/graph/api/resources/contactfolder?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct ContactFolder {
+    #[serde(rename = "@odata.type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    odata_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parent_folder_id: Option<String>,
+    #[serde(flatten)]
+    additional_data: HashMap<String, Value>,
+}
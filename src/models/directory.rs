@@ -0,0 +1,158 @@
+use serde_json::Value;
+use std::collections::HashMap;
+
+odata_type!(
+    /// An object in Azure Active Directory such as the members and
+    /// owners of a group.
+    /// [directoryObject](https://docs.microsoft.com/en-us/graph/api/resources/directoryobject?view=graph-rest-1.0)
+    DirectoryObject,
+    "#microsoft.graph.user" => User(User),
+    "#microsoft.graph.group" => Group(Group),
+    "#microsoft.graph.directoryRole" => DirectoryRole(DirectoryRole),
+);
+
+impl DirectoryObject {
+    pub fn id(&self) -> Option<&str> {
+        match self {
+            DirectoryObject::User(user) => user.id.as_deref(),
+            DirectoryObject::Group(group) => group.id.as_deref(),
+            DirectoryObject::DirectoryRole(role) => role.id.as_deref(),
+            DirectoryObject::Other(value) => value["id"].as_str(),
+        }
+    }
+}
+
+/// A user in Azure Active Directory.
+/// [user](https://docs.microsoft.com/en-us/graph/api/resources/user?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct User {
+    #[serde(rename = "@odata.type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    odata_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    given_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    surname: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user_principal_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mail: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mail_nickname: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    other_mails: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    job_title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    department: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    office_location: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    business_phones: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mobile_phone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    preferred_language: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    account_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user_type: Option<String>,
+    #[serde(flatten)]
+    additional_data: HashMap<String, Value>,
+}
+
+/// An Office 365 group, security group or distribution group.
+/// [group](https://docs.microsoft.com/en-us/graph/api/resources/group?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct Group {
+    #[serde(rename = "@odata.type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    odata_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mail: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mail_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mail_nickname: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    security_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group_types: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visibility: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    created_date_time: Option<String>,
+    #[serde(flatten)]
+    additional_data: HashMap<String, Value>,
+}
+
+/// A role in Azure Active Directory that has been activated in the tenant.
+/// [directoryRole](https://docs.microsoft.com/en-us/graph/api/resources/directoryrole?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct DirectoryRole {
+    #[serde(rename = "@odata.type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    odata_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    role_template_id: Option<String>,
+    #[serde(flatten)]
+    additional_data: HashMap<String, Value>,
+}
+
+/// The body of a request that adds a reference to a directory object
+/// using `$ref`, such as adding a member or owner to a group.
+///
+/// # Example
+/// ```
+/// # use graph_rs::models::DirectoryObjectReference;
+/// let reference = DirectoryObjectReference::new("https://graph.microsoft.com/v1.0", "ID");
+/// assert_eq!(
+///     reference.odata_id(),
+///     "https://graph.microsoft.com/v1.0/directoryObjects/ID"
+/// );
+/// ```
+#[derive(Default, Debug, Clone, Eq, PartialEq, Serialize, Deserialize, Getters)]
+#[get = "pub"]
+pub struct DirectoryObjectReference {
+    #[serde(rename = "@odata.id")]
+    odata_id: String,
+}
+
+impl DirectoryObjectReference {
+    /// Create a reference to the directory object with the given id
+    /// using the v1.0 or beta url of Microsoft Graph.
+    pub fn new(graph_url: &str, id: &str) -> DirectoryObjectReference {
+        DirectoryObjectReference {
+            odata_id: format!(
+                "{}/directoryObjects/{}",
+                graph_url.trim_end_matches('/'),
+                id
+            ),
+        }
+    }
+}
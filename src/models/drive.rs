@@ -0,0 +1,317 @@
+use crate::models::{Identity, IdentitySet};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// A OneDrive or SharePoint document library.
+/// [drive](https://docs.microsoft.com/en-us/graph/api/resources/drive?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct Drive {
+    #[serde(rename = "@odata.type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    odata_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    drive_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    web_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    created_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_modified_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    created_by: Option<IdentitySet>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_modified_by: Option<IdentitySet>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<IdentitySet>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    quota: Option<Quota>,
+    #[serde(flatten)]
+    additional_data: HashMap<String, Value>,
+}
+
+/// The storage space of a drive in bytes.
+/// [quota](https://docs.microsoft.com/en-us/graph/api/resources/quota?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct Quota {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    deleted: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    remaining: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    total: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    used: Option<i64>,
+}
+
+/// A file, folder or other item stored in a drive.
+/// [driveItem](https://docs.microsoft.com/en-us/graph/api/resources/driveitem?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct DriveItem {
+    #[serde(rename = "@odata.type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    odata_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    e_tag: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    c_tag: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    size: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    web_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    created_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_modified_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    created_by: Option<IdentitySet>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_modified_by: Option<IdentitySet>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parent_reference: Option<ItemReference>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    file: Option<File>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    folder: Option<Folder>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    root: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    deleted: Option<Value>,
+    #[serde(rename = "@microsoft.graph.downloadUrl")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    download_url: Option<String>,
+    #[serde(flatten)]
+    additional_data: HashMap<String, Value>,
+}
+
+impl DriveItem {
+    pub fn is_file(&self) -> bool {
+        self.file.is_some()
+    }
+
+    pub fn is_folder(&self) -> bool {
+        self.folder.is_some()
+    }
+
+    /// Returns true if the item is in a delta response for an item
+    /// that was deleted.
+    pub fn is_deleted(&self) -> bool {
+        self.deleted.is_some()
+    }
+}
+
+/// A reference to a drive item by its drive and id or path.
+/// [itemReference](https://docs.microsoft.com/en-us/graph/api/resources/itemreference?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct ItemReference {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    drive_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    drive_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    share_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    site_id: Option<String>,
+}
+
+/// [file](https://docs.microsoft.com/en-us/graph/api/resources/file?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct File {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mime_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hashes: Option<Hashes>,
+}
+
+/// The hashes of the content of a file. Which hashes are available
+/// depends on the type of drive.
+/// [hashes](https://docs.microsoft.com/en-us/graph/api/resources/hashes?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct Hashes {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crc32_hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sha1_hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sha256_hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    quick_xor_hash: Option<String>,
+}
+
+/// [folder](https://docs.microsoft.com/en-us/graph/api/resources/folder?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct Folder {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    child_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    view: Option<Value>,
+}
+
+/// A previous version of a drive item.
+/// [driveItemVersion](https://docs.microsoft.com/en-us/graph/api/resources/driveitemversion?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct DriveItemVersion {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    size: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_modified_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_modified_by: Option<IdentitySet>,
+    #[serde(flatten)]
+    additional_data: HashMap<String, Value>,
+}
+
+/// The thumbnails of a drive item in each size.
+/// [thumbnailSet](https://docs.microsoft.com/en-us/graph/api/resources/thumbnailset?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[set = "pub"]
+#[get = "pub"]
+pub struct ThumbnailSet {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    large: Option<Thumbnail>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    medium: Option<Thumbnail>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    small: Option<Thumbnail>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source: Option<Thumbnail>,
+}
+
+/// [thumbnail](https://docs.microsoft.com/en-us/graph/api/resources/thumbnail?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct Thumbnail {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    height: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    width: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source_item_id: Option<String>,
+}
+
+/// A sharing permission of a drive item such as a sharing link
+/// or access granted to a user or group.
+/// [permission](https://docs.microsoft.com/en-us/graph/api/resources/permission?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct Permission {
+    #[serde(rename = "@odata.type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    odata_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    roles: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    link: Option<SharingLink>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    granted_to: Option<IdentitySet>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    granted_to_identities: Option<Vec<IdentitySet>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inherited_from: Option<ItemReference>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    invitation: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    share_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    has_password: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expiration_date_time: Option<String>,
+    #[serde(flatten)]
+    additional_data: HashMap<String, Value>,
+}
+
+/// The link of a sharing permission.
+/// [sharingLink](https://docs.microsoft.com/en-us/graph/api/resources/sharinglink?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct SharingLink {
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    link_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scope: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    web_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    web_html: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    prevents_download: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    application: Option<Identity>,
+}
+
+/// The shared item a share id or an encoded sharing url resolves to.
+/// [sharedDriveItem](https://docs.microsoft.com/en-us/graph/api/resources/shareddriveitem?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct SharedDriveItem {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<IdentitySet>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    drive_item: Option<DriveItem>,
+    #[serde(flatten)]
+    additional_data: HashMap<String, Value>,
+}
@@ -0,0 +1,91 @@
+use crate::models::{Attachment, ItemBody, Recipient};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// A message in a mail folder.
+/// [message](https://docs.microsoft.com/en-us/graph/api/resources/message?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct Message {
+    #[serde(rename = "@odata.type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    odata_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subject: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body: Option<ItemBody>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body_preview: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    importance: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_read: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_draft: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    has_attachments: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    from: Option<Recipient>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sender: Option<Recipient>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    to_recipients: Option<Vec<Recipient>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cc_recipients: Option<Vec<Recipient>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bcc_recipients: Option<Vec<Recipient>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_to: Option<Vec<Recipient>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    conversation_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parent_folder_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    internet_message_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    categories: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    web_link: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    received_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sent_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    created_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_modified_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attachments: Option<Vec<Attachment>>,
+    #[serde(flatten)]
+    additional_data: HashMap<String, Value>,
+}
+
+/// A mail folder such as the inbox or drafts.
+/// [mailFolder](https://docs.microsoft.com/en-us/graph/api/resources/mailfolder?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct MailFolder {
+    #[serde(rename = "@odata.type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    odata_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parent_folder_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    child_folder_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unread_item_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    total_item_count: Option<i32>,
+    #[serde(flatten)]
+    additional_data: HashMap<String, Value>,
+}
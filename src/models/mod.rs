@@ -0,0 +1,98 @@
+//! Typed models of the resources returned by Microsoft Graph.
+//!
+//! The request builders deserialize responses into these models. Each
+//! model keeps any properties it does not define in `additional_data`,
+//! and `json::<serde_json::Value>()` can be used instead of `send()` to
+//! get the untyped response body.
+//!
+//! # Example
+//! ```rust,ignore
+//! # use graph_rs::prelude::*;
+//! # let client = Graph::new("ACCESS_TOKEN");
+//! let response = client.v1()
+//!     .me()
+//!     .drive()
+//!     .get_item("ITEM_ID")
+//!     .send()?;
+//! println!("{:#?}", response.body().name());
+//!
+//! // Get the response body as a serde_json::Value.
+//! let value: serde_json::Value = client.v1()
+//!     .me()
+//!     .drive()
+//!     .get_item("ITEM_ID")
+//!     .json()?;
+//! ```
+
+// Creates an enum over resources that share a base type and are told
+// apart by their @odata.type. Values with an unknown or missing
+// @odata.type deserialize to the Other variant.
+macro_rules! odata_type {
+    (
+        $(#[$attr:meta])*
+        $name:ident,
+        $( $odata_type:literal => $variant:ident($ty:ty), )*
+    ) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, PartialEq, Serialize)]
+        #[serde(untagged)]
+        pub enum $name {
+            $( $variant($ty), )*
+            Other(serde_json::Value),
+        }
+
+        impl $name {
+            /// The @odata.type of the resource.
+            pub fn odata_type(&self) -> Option<&str> {
+                match self {
+                    $( $name::$variant(_) => Some($odata_type), )*
+                    $name::Other(value) => value["@odata.type"].as_str(),
+                }
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                let value = serde_json::Value::deserialize(deserializer)?;
+                let odata_type = value["@odata.type"].as_str().map(|s| s.to_string());
+                match odata_type.as_deref() {
+                    $(
+                        Some($odata_type) => serde_json::from_value(value)
+                            .map($name::$variant)
+                            .map_err(serde::de::Error::custom),
+                    )*
+                    _ => Ok($name::Other(value)),
+                }
+            }
+        }
+    };
+}
+
+mod attachment;
+mod calendar;
+mod common;
+mod contacts;
+mod directory;
+mod drive;
+mod mail;
+mod onenote;
+mod planner;
+mod subscription;
+mod teams;
+mod todo;
+
+pub use attachment::*;
+pub use calendar::*;
+pub use common::*;
+pub use contacts::*;
+pub use directory::*;
+pub use drive::*;
+pub use mail::*;
+pub use onenote::*;
+pub use planner::*;
+pub use subscription::*;
+pub use teams::*;
+pub use todo::*;
@@ -0,0 +1,163 @@
+use crate::models::IdentitySet;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// A OneNote notebook.
+/// [notebook](https://docs.microsoft.com/en-us/graph/api/resources/notebook?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct Notebook {
+    #[serde(rename = "@odata.type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    odata_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_default: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_shared: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user_role: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    links: Option<OnenoteLinks>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sections_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    section_groups_url: Option<String>,
+    #[serde(rename = "self")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    self_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    created_by: Option<IdentitySet>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_modified_by: Option<IdentitySet>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    created_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_modified_date_time: Option<String>,
+    #[serde(flatten)]
+    additional_data: HashMap<String, Value>,
+}
+
+/// A section in a notebook or section group.
+/// [onenoteSection](https://docs.microsoft.com/en-us/graph/api/resources/section?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct OnenoteSection {
+    #[serde(rename = "@odata.type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    odata_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_default: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    links: Option<OnenoteLinks>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pages_url: Option<String>,
+    #[serde(rename = "self")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    self_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parent_notebook: Option<Notebook>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    created_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_modified_date_time: Option<String>,
+    #[serde(flatten)]
+    additional_data: HashMap<String, Value>,
+}
+
+/// A group of sections in a notebook.
+/// [sectionGroup](https://docs.microsoft.com/en-us/graph/api/resources/sectiongroup?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct SectionGroup {
+    #[serde(rename = "@odata.type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    odata_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sections_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    section_groups_url: Option<String>,
+    #[serde(rename = "self")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    self_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parent_notebook: Option<Notebook>,
+    #[serde(flatten)]
+    additional_data: HashMap<String, Value>,
+}
+
+/// A page in a section.
+/// [onenotePage](https://docs.microsoft.com/en-us/graph/api/resources/page?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct OnenotePage {
+    #[serde(rename = "@odata.type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    odata_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    created_by_app_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    level: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    order: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    links: Option<OnenoteLinks>,
+    #[serde(rename = "self")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    self_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parent_section: Option<OnenoteSection>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    created_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_modified_date_time: Option<String>,
+    #[serde(flatten)]
+    additional_data: HashMap<String, Value>,
+}
+
+/// The links that open a notebook, section or page in the OneNote
+/// clients.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct OnenoteLinks {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    one_note_client_url: Option<ExternalLink>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    one_note_web_url: Option<ExternalLink>,
+}
+
+/// [externalLink](https://docs.microsoft.com/en-us/graph/api/resources/externallink?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[set = "pub"]
+#[get = "pub"]
+pub struct ExternalLink {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    href: Option<String>,
+}
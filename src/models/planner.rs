@@ -0,0 +1,122 @@
+use crate::models::IdentitySet;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// A plan owned by a group that contains buckets and tasks.
+/// [plannerPlan](https://docs.microsoft.com/en-us/graph/api/resources/plannerplan?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct PlannerPlan {
+    #[serde(rename = "@odata.type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    odata_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    created_by: Option<IdentitySet>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    created_date_time: Option<String>,
+    #[serde(flatten)]
+    additional_data: HashMap<String, Value>,
+}
+
+/// A bucket of tasks in a plan.
+/// [plannerBucket](https://docs.microsoft.com/en-us/graph/api/resources/plannerbucket?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct PlannerBucket {
+    #[serde(rename = "@odata.type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    odata_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    plan_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    order_hint: Option<String>,
+    #[serde(flatten)]
+    additional_data: HashMap<String, Value>,
+}
+
+/// A task in a plan.
+/// [plannerTask](https://docs.microsoft.com/en-us/graph/api/resources/plannertask?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct PlannerTask {
+    #[serde(rename = "@odata.type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    odata_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    plan_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bucket_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    percent_complete: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    order_hint: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    assignee_priority: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    has_description: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    preview_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    conversation_thread_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reference_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    checklist_item_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    active_checklist_item_count: Option<i32>,
+    /// The users the task is assigned to keyed by user id.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    assignments: Option<HashMap<String, PlannerAssignment>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    due_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    completed_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    created_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    created_by: Option<IdentitySet>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    completed_by: Option<IdentitySet>,
+    #[serde(flatten)]
+    additional_data: HashMap<String, Value>,
+}
+
+/// The assignment of a task to a user.
+/// [plannerAssignment](https://docs.microsoft.com/en-us/graph/api/resources/plannerassignment?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct PlannerAssignment {
+    #[serde(rename = "@odata.type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    odata_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    assigned_by: Option<IdentitySet>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    assigned_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    order_hint: Option<String>,
+}
@@ -0,0 +1,113 @@
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// A subscription that sends change notifications for a resource to
+/// the notification url.
+/// [subscription](https://docs.microsoft.com/en-us/graph/api/resources/subscription?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct Subscription {
+    #[serde(rename = "@odata.type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    odata_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    resource: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    change_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    client_state: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    notification_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lifecycle_notification_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expiration_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    application_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    creator_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    include_resource_data: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    encryption_certificate: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    encryption_certificate_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    latest_supported_tls_version: Option<String>,
+    #[serde(flatten)]
+    additional_data: HashMap<String, Value>,
+}
+
+/// A notification sent to the notification url of a subscription. Lifecycle
+/// notifications have a lifecycle event and no change type.
+/// [changeNotification](https://docs.microsoft.com/en-us/graph/api/resources/changenotification?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct ChangeNotification {
+    #[serde(rename = "@odata.type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    odata_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subscription_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subscription_expiration_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    client_state: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    change_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lifecycle_event: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    resource: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    resource_data: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    encrypted_content: Option<ChangeNotificationEncryptedContent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tenant_id: Option<String>,
+    #[serde(flatten)]
+    additional_data: HashMap<String, Value>,
+}
+
+/// The notifications in the body of a request to the notification url.
+/// [changeNotificationCollection](https://docs.microsoft.com/en-us/graph/api/resources/changenotificationcollection?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct ChangeNotificationCollection {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<Vec<ChangeNotification>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    validation_tokens: Option<Vec<String>>,
+    #[serde(flatten)]
+    additional_data: HashMap<String, Value>,
+}
+
+/// The encrypted resource data of a notification that includes
+/// resource data.
+/// [changeNotificationEncryptedContent](https://docs.microsoft.com/en-us/graph/api/resources/changenotificationencryptedcontent?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct ChangeNotificationEncryptedContent {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data_signature: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    encryption_certificate_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    encryption_certificate_thumbprint: Option<String>,
+}
@@ -3,6 +3,7 @@ use crate::http::{
     AsyncDownload, AsyncHttpClient, BlockingDownload, BlockingHttpClient, GraphRequestType,
     GraphResponse, IntoResponse, RequestClient,
 };
+use crate::models::{Notebook, OnenotePage, OnenoteSection, SectionGroup};
 use crate::types::collection::Collection;
 use crate::types::content::Content;
 use graph_error::{AsRes, GraphRsError};
@@ -24,9 +25,9 @@ impl<'a, Client> OnenoteRequest<'a, Client>
 where
     Client: crate::http::RequestClient,
 {
-    get!( list_sections, Collection<serde_json::Value> => "{{section}}" );
-    get!( list_section_groups, Collection<serde_json::Value> => "{{section_group}}" );
-    get!( list_pages, Collection<serde_json::Value> => "{{pages}}" );
+    get!( list_sections, Collection<OnenoteSection> => "{{section}}" );
+    get!( list_section_groups, Collection<SectionGroup> => "{{section_group}}" );
+    get!( list_pages, Collection<OnenotePage> => "{{pages}}" );
 
     pub fn notebooks(&self) -> OnenoteNotebookRequest<'a, Client> {
         OnenoteNotebookRequest::new(self.client)
@@ -44,10 +45,7 @@ where
         OnenotePageRequest::new(self.client)
     }
 
-    pub fn create_page<P: AsRef<Path>>(
-        &self,
-        file: P,
-    ) -> IntoResponse<'a, serde_json::Value, Client> {
+    pub fn create_page<P: AsRef<Path>>(&self, file: P) -> IntoResponse<'a, OnenotePage, Client> {
         render_path!(self.client, "{{pages}}");
 
         if !file.as_ref().extension().eq(&Some(OsStr::new("html"))) {
@@ -86,12 +84,12 @@ impl<'a, Client> OnenoteNotebookRequest<'a, Client>
 where
     Client: crate::http::RequestClient,
 {
-    get!( list, Collection<serde_json::Value> => "{{notebook}}" );
-    get!( | list_sections, Collection<serde_json::Value> => "{{notebook}}/{{id}}/sections" );
-    get!( | get, serde_json::Value => "{{notebook}}/{{id}}" );
-    post!( [ create, serde_json::Value => "{{notebook}}" ]);
+    get!( list, Collection<Notebook> => "{{notebook}}" );
+    get!( | list_sections, Collection<OnenoteSection> => "{{notebook}}/{{id}}/sections" );
+    get!( | get, Notebook => "{{notebook}}/{{id}}" );
+    post!( [ create, Notebook => "{{notebook}}" ]);
     post!( [ | copy, serde_json::Value => "{{notebook}}/{{id}}/copyNotebook" ] );
-    post!( [ | create_section, serde_json::Value => "{{notebook}}/{{id}}/sections" ] );
+    post!( [ | create_section, OnenoteSection => "{{notebook}}/{{id}}/sections" ] );
 
     pub fn recent(
         &self,
@@ -115,9 +113,9 @@ impl<'a, Client> OnenoteSectionRequest<'a, Client>
 where
     Client: crate::http::RequestClient,
 {
-    get!( list, Collection<serde_json::Value> => "{{section}}" );
-    get!( | list_pages, Collection<serde_json::Value> => "{{section}}/{{id}}/pages" );
-    get!( | get, serde_json::Value => "{{section}}/{{id}}" );
+    get!( list, Collection<OnenoteSection> => "{{section}}" );
+    get!( | list_pages, Collection<OnenotePage> => "{{section}}/{{id}}/pages" );
+    get!( | get, OnenoteSection => "{{section}}/{{id}}" );
     post!( [ | copy_to_notebook, GraphResponse<Content> => "{{section}}/{{id}}/copyToNotebook" ] );
     post!( [ | copy_to_section_group, GraphResponse<Content> => "{{section}}/{{id}}/copyToSectionGroup" ] );
 
@@ -125,7 +123,7 @@ where
         &self,
         id: S,
         file: P,
-    ) -> IntoResponse<'a, serde_json::Value, Client> {
+    ) -> IntoResponse<'a, OnenotePage, Client> {
         render_path!(
             self.client,
             "{{section}}/{{id}}/pages",
@@ -168,11 +166,11 @@ impl<'a, Client> OnenoteSectionGroupRequest<'a, Client>
 where
     Client: crate::http::RequestClient,
 {
-    get!( list, Collection<serde_json::Value> => "{{section_group}}" );
-    get!( | list_sections, Collection<serde_json::Value> => "{{section_group}}/{{id}}/sections" );
-    get!( | get, serde_json::Value => "{{section_group}}/{{id}}" );
-    post!( [ | create, serde_json::Value => "{{section_group}}/{{id}}/sectionGroups" ] );
-    post!( [ | create_section, serde_json::Value => "{{section_group}}/{{id}}/sections" ] );
+    get!( list, Collection<SectionGroup> => "{{section_group}}" );
+    get!( | list_sections, Collection<OnenoteSection> => "{{section_group}}/{{id}}/sections" );
+    get!( | get, SectionGroup => "{{section_group}}/{{id}}" );
+    post!( [ | create, SectionGroup => "{{section_group}}/{{id}}/sectionGroups" ] );
+    post!( [ | create_section, OnenoteSection => "{{section_group}}/{{id}}/sections" ] );
 }
 
 register_client!(OnenotePageRequest,);
@@ -181,8 +179,8 @@ impl<'a, Client> OnenotePageRequest<'a, Client>
 where
     Client: crate::http::RequestClient,
 {
-    get!( list, Collection<serde_json::Value> => "{{pages}}" );
-    get!( | get, serde_json::Value => "{{pages}}/{{id}}" );
+    get!( list, Collection<OnenotePage> => "{{pages}}" );
+    get!( | get, OnenotePage => "{{pages}}/{{id}}" );
     get!( | content, GraphResponse<Content> => "{{pages}}/{{id}}/content" );
     patch!( [ | update, serde_json::Value => "{{pages}}/{{id}}/content" ] );
     post!( [ | copy_to_section, GraphResponse<Content> => "{{pages}}/{{id}}/copyToSection" ] );
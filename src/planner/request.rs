@@ -1,5 +1,6 @@
 use crate::client::Graph;
 use crate::http::{GraphResponse, IntoResponse};
+use crate::models::{PlannerBucket, PlannerPlan, PlannerTask};
 use crate::types::{collection::Collection, content::Content};
 use reqwest::Method;
 
@@ -32,26 +33,26 @@ impl<'a, Client> PlannerPlansRequest<'a, Client>
 where
     Client: crate::http::RequestClient,
 {
-    get!( list_plans, Collection<serde_json::Value> => "planner/plans" );
-    post!( [ create_plans, serde_json::Value => "planner/plans" ] );
-    get!( | get_plans, serde_json::Value => "planner/plans/{{id}}" );
-    patch!( [| update_plans, serde_json::Value => "planner/plans/{{id}}" ] );
-    get!( | list_buckets, Collection<serde_json::Value> => "planner/plans/{{id}}/buckets" );
-    get!( || get_buckets, serde_json::Value => "planner/plans/{{id}}/buckets/{{id2}}" );
+    get!( list_plans, Collection<PlannerPlan> => "planner/plans" );
+    post!( [ create_plans, PlannerPlan => "planner/plans" ] );
+    get!( | get_plans, PlannerPlan => "planner/plans/{{id}}" );
+    patch!( [| update_plans, PlannerPlan => "planner/plans/{{id}}" ] );
+    get!( | list_buckets, Collection<PlannerBucket> => "planner/plans/{{id}}/buckets" );
+    get!( || get_buckets, PlannerBucket => "planner/plans/{{id}}/buckets/{{id2}}" );
     get!( | get_details, serde_json::Value => "planner/plans/{{id}}/details" );
     patch!( [| update_details, serde_json::Value => "planner/plans/{{id}}/details" ] );
-    get!( | list_tasks, Collection<serde_json::Value> => "planner/plans/{{id}}/tasks" );
-    get!( || get_tasks, serde_json::Value => "planner/plans/{{id}}/tasks/{{id2}}" );
+    get!( | list_tasks, Collection<PlannerTask> => "planner/plans/{{id}}/tasks" );
+    get!( || get_tasks, PlannerTask => "planner/plans/{{id}}/tasks/{{id2}}" );
 }
 
 impl<'a, Client> PlannerTasksRequest<'a, Client>
 where
     Client: crate::http::RequestClient,
 {
-    get!( list_tasks, Collection<serde_json::Value> => "planner/tasks" );
-    post!( [ create_tasks, serde_json::Value => "planner/tasks" ] );
-    get!( | get_tasks, Collection<serde_json::Value> => "planner/tasks/{{id}}" );
-    patch!( [| update_tasks, serde_json::Value => "planner/tasks/{{id}}" ] );
+    get!( list_tasks, Collection<PlannerTask> => "planner/tasks" );
+    post!( [ create_tasks, PlannerTask => "planner/tasks" ] );
+    get!( | get_tasks, PlannerTask => "planner/tasks/{{id}}" );
+    patch!( [| update_tasks, PlannerTask => "planner/tasks/{{id}}" ] );
     delete!( | delete_tasks, GraphResponse<Content> => "planner/tasks/{id}" );
     get!( | get_assigned_to_task_board_format, serde_json::Value => "planner/tasks/{{id}}/assignedToTaskBoardFormat" );
     patch!( [| update_assigned_to_task_board_format, serde_json::Value => "planner/tasks/{{id}}/assignedToTaskBoardFormat" ] );
@@ -67,11 +68,11 @@ impl<'a, Client> PlannerBucketsRequest<'a, Client>
 where
     Client: crate::http::RequestClient,
 {
-    get!( list_buckets, Collection<serde_json::Value> => "planner/buckets" );
-    post!( [ create_buckets, serde_json::Value => "planner/buckets" ] );
-    get!( | get_buckets, serde_json::Value => "planner/buckets/{{id}}" );
-    patch!( [| update_buckets, serde_json::Value => "planner/buckets/{{id}}" ] );
+    get!( list_buckets, Collection<PlannerBucket> => "planner/buckets" );
+    post!( [ create_buckets, PlannerBucket => "planner/buckets" ] );
+    get!( | get_buckets, PlannerBucket => "planner/buckets/{{id}}" );
+    patch!( [| update_buckets, PlannerBucket => "planner/buckets/{{id}}" ] );
     delete!( | delete_buckets, GraphResponse<Content> => "planner/buckets/{id}" );
-    get!( | list_tasks, Collection<serde_json::Value> => "planner/buckets/{{id}}/tasks" );
-    get!( || get_tasks, serde_json::Value => "planner/buckets/{{id}}/tasks/{{id2}}" );
+    get!( | list_tasks, Collection<PlannerTask> => "planner/buckets/{{id}}/tasks" );
+    get!( || get_tasks, PlannerTask => "planner/buckets/{{id}}/tasks/{{id2}}" );
 }
@@ -0,0 +1,5 @@
+mod notification;
+mod request;
+
+pub use notification::*;
+pub use request::*;
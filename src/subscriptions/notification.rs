@@ -0,0 +1,301 @@
+use crate::models::{
+    ChangeNotification, ChangeNotificationCollection, ChangeNotificationEncryptedContent,
+};
+use aes::Aes256;
+use block_modes::block_padding::Pkcs7;
+use block_modes::{BlockMode, Cbc};
+use graph_error::{GraphFailure, GraphResult};
+use graph_oauth::oauth::pem_to_der;
+use rsa::{PaddingScheme, RSAPrivateKey};
+
+/// The lifecycle event of a lifecycle notification.
+/// [Reduce missing subscriptions and change notifications](https://docs.microsoft.com/en-us/graph/webhooks-lifecycle)
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum LifecycleEvent {
+    /// The subscription was removed and a new subscription
+    /// needs to be created.
+    SubscriptionRemoved,
+    /// The access token of the subscription is about to expire and
+    /// the subscription needs to be reauthorized.
+    ReauthorizationRequired,
+    /// Some notifications were not delivered and the resource
+    /// needs to be synced using delta.
+    Missed,
+    Other(String),
+}
+
+impl From<&str> for LifecycleEvent {
+    fn from(event: &str) -> Self {
+        match event {
+            "subscriptionRemoved" => LifecycleEvent::SubscriptionRemoved,
+            "reauthorizationRequired" => LifecycleEvent::ReauthorizationRequired,
+            "missed" => LifecycleEvent::Missed,
+            _ => LifecycleEvent::Other(event.to_string()),
+        }
+    }
+}
+
+/// A notification received by the notification url or the
+/// lifecycle notification url of a subscription.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Notification {
+    Change(ChangeNotification),
+    Lifecycle(LifecycleEvent, ChangeNotification),
+}
+
+impl From<ChangeNotification> for Notification {
+    fn from(notification: ChangeNotification) -> Self {
+        match notification.lifecycle_event().clone() {
+            Some(event) => {
+                Notification::Lifecycle(LifecycleEvent::from(event.as_str()), notification)
+            },
+            None => Notification::Change(notification),
+        }
+    }
+}
+
+impl Notification {
+    pub fn change_notification(&self) -> &ChangeNotification {
+        match self {
+            Notification::Change(notification) => notification,
+            Notification::Lifecycle(_, notification) => notification,
+        }
+    }
+}
+
+/// Handles the requests Microsoft Graph sends to the notification url
+/// of a subscription. The receiver does not depend on a web framework:
+/// pass it the url and body of the request and send the response the
+/// handshake or notifications need.
+///
+/// When a subscription is created Microsoft Graph validates the notification
+/// url by sending a request with a validationToken query parameter. Respond
+/// with 200 OK, a Content-Type of text/plain and the token as the body.
+/// Notifications are sent in the body of a POST request and should be
+/// answered with 202 Accepted.
+///
+/// # See
+/// [Set up notifications for changes in user data](https://docs.microsoft.com/en-us/graph/webhooks)
+///
+/// # Example
+/// ```rust,ignore
+/// # use graph_rs::subscriptions::{Notification, NotificationReceiver};
+/// let mut receiver = NotificationReceiver::new();
+/// receiver.client_state("secretClientState");
+///
+/// // The url and body of the request to the notification url.
+/// if let Some(token) = NotificationReceiver::validation_token(url) {
+///     // Respond with 200 OK and the token as text/plain.
+/// } else {
+///     for notification in receiver.parse(body)? {
+///         match notification {
+///             Notification::Change(change) => println!("{:#?}", change.resource()),
+///             Notification::Lifecycle(event, _) => println!("{:#?}", event),
+///         }
+///     }
+///     // Respond with 202 Accepted.
+/// }
+/// ```
+#[derive(Default)]
+pub struct NotificationReceiver {
+    client_state: Option<String>,
+    certificate: Option<EncryptionCertificate>,
+}
+
+impl NotificationReceiver {
+    pub fn new() -> NotificationReceiver {
+        NotificationReceiver::default()
+    }
+
+    /// The client state the subscription was created with. Notifications
+    /// that have a different client state are not parsed.
+    pub fn client_state(&mut self, client_state: &str) -> &mut Self {
+        self.client_state = Some(client_state.to_string());
+        self
+    }
+
+    /// The certificate used to decrypt the resource data of
+    /// notifications that include resource data.
+    pub fn encryption_certificate(&mut self, certificate: EncryptionCertificate) -> &mut Self {
+        self.certificate = Some(certificate);
+        self
+    }
+
+    /// The validationToken of a request that validates the notification url.
+    /// The url may be the full url of the request or only the query.
+    pub fn validation_token(url: &str) -> Option<String> {
+        let query = match url.find('?') {
+            Some(index) => &url[index + 1..],
+            None => url,
+        };
+        url::form_urlencoded::parse(query.as_bytes())
+            .find(|(key, _)| key.eq("validationToken"))
+            .map(|(_, value)| value.into_owned())
+    }
+
+    /// Parse the body of a request to the notification url or lifecycle
+    /// notification url. Returns an error if the client state of a
+    /// notification does not match the client state of the receiver.
+    pub fn parse(&self, body: &[u8]) -> GraphResult<Vec<Notification>> {
+        let collection: ChangeNotificationCollection = serde_json::from_slice(body)?;
+        let notifications = collection.value().clone().unwrap_or_default();
+
+        if let Some(client_state) = self.client_state.as_ref() {
+            if notifications
+                .iter()
+                .any(|notification| notification.client_state().as_ref() != Some(client_state))
+            {
+                return Err(GraphFailure::invalid(
+                    "clientState of the change notification does not match",
+                ));
+            }
+        }
+
+        Ok(notifications.into_iter().map(Notification::from).collect())
+    }
+
+    /// Decrypt the resource data of a notification. Returns None if the
+    /// notification does not include encrypted resource data.
+    pub fn decrypt(
+        &self,
+        notification: &ChangeNotification,
+    ) -> GraphResult<Option<serde_json::Value>> {
+        let encrypted_content = match notification.encrypted_content() {
+            Some(encrypted_content) => encrypted_content,
+            None => return Ok(None),
+        };
+        let certificate = self
+            .certificate
+            .as_ref()
+            .ok_or_else(|| GraphFailure::invalid("encryption certificate"))?;
+        certificate.decrypt(encrypted_content).map(Some)
+    }
+}
+
+/// The certificate used to encrypt the resource data of notifications
+/// and the private key of the certificate that decrypts it.
+///
+/// The certificate is set on the subscription using `encryption_certificate()`
+/// and the id is used to find the certificate when a notification is received.
+///
+/// # See
+/// [Set up change notifications that include resource data](https://docs.microsoft.com/en-us/graph/webhooks-with-resource-data)
+///
+/// # Example
+/// ```rust,ignore
+/// # use graph_rs::models::Subscription;
+/// # use graph_rs::subscriptions::EncryptionCertificate;
+/// let certificate = std::fs::read("./certificate.pem")?;
+/// let private_key = std::fs::read("./private_key.pem")?;
+/// let certificate = EncryptionCertificate::from_pem("<CERTIFICATE_ID>", &certificate, &private_key)?;
+///
+/// let mut subscription = Subscription::default();
+/// subscription
+///     .set_include_resource_data(Some(true))
+///     .set_encryption_certificate(Some(certificate.encryption_certificate()))
+///     .set_encryption_certificate_id(Some(certificate.id().to_string()));
+/// ```
+pub struct EncryptionCertificate {
+    id: String,
+    certificate: Vec<u8>,
+    private_key: RSAPrivateKey,
+}
+
+impl EncryptionCertificate {
+    /// Create an encryption certificate from a PEM encoded certificate and
+    /// a PEM encoded PKCS#8 (BEGIN PRIVATE KEY) or PKCS#1 (BEGIN RSA
+    /// PRIVATE KEY) RSA private key.
+    pub fn from_pem(
+        id: &str,
+        certificate: &[u8],
+        private_key: &[u8],
+    ) -> GraphResult<EncryptionCertificate> {
+        let certificate = pem_to_der(certificate, "CERTIFICATE")?;
+        if let Ok(private_key) = pem_to_der(private_key, "PRIVATE KEY") {
+            return EncryptionCertificate::from_der(id, &certificate, &private_key);
+        }
+
+        let private_key = pem_to_der(private_key, "RSA PRIVATE KEY")?;
+        let private_key = RSAPrivateKey::from_pkcs1(&private_key)
+            .map_err(|_| GraphFailure::invalid("RSA private key"))?;
+        Ok(EncryptionCertificate {
+            id: id.to_string(),
+            certificate,
+            private_key,
+        })
+    }
+
+    /// Create an encryption certificate from a DER encoded certificate and
+    /// a DER encoded PKCS#8 RSA private key.
+    pub fn from_der(
+        id: &str,
+        certificate: &[u8],
+        private_key: &[u8],
+    ) -> GraphResult<EncryptionCertificate> {
+        let private_key = RSAPrivateKey::from_pkcs8(private_key)
+            .map_err(|_| GraphFailure::invalid("PKCS#8 RSA private key"))?;
+        Ok(EncryptionCertificate {
+            id: id.to_string(),
+            certificate: certificate.to_vec(),
+            private_key,
+        })
+    }
+
+    pub fn id(&self) -> &str {
+        self.id.as_str()
+    }
+
+    /// The base64 encoded certificate that is set as the encryption
+    /// certificate of a subscription.
+    pub fn encryption_certificate(&self) -> String {
+        base64::encode(&self.certificate)
+    }
+
+    /// Decrypt the resource data of a notification.
+    ///
+    /// The data key is decrypted with the private key and is used to
+    /// check the signature of the data before decrypting the data.
+    pub fn decrypt(
+        &self,
+        encrypted_content: &ChangeNotificationEncryptedContent,
+    ) -> GraphResult<serde_json::Value> {
+        if let Some(id) = encrypted_content.encryption_certificate_id() {
+            if id.ne(&self.id) {
+                return Err(GraphFailure::invalid(&format!(
+                    "encryptionCertificateId: the resource data was encrypted with the certificate {}",
+                    id
+                )));
+            }
+        }
+
+        let data_key = decode(encrypted_content.data_key(), "dataKey")?;
+        let data = decode(encrypted_content.data(), "data")?;
+        let data_signature = decode(encrypted_content.data_signature(), "dataSignature")?;
+
+        let key = self
+            .private_key
+            .decrypt(PaddingScheme::new_oaep::<sha1::Sha1>(), &data_key)
+            .map_err(|_| GraphFailure::CryptoError)?;
+        if key.len() != 32 {
+            return Err(GraphFailure::CryptoError);
+        }
+        let hmac_key = ring::hmac::Key::new(ring::hmac::HMAC_SHA256, &key);
+        ring::hmac::verify(&hmac_key, &data, &data_signature)
+            .map_err(|_| GraphFailure::invalid("dataSignature of the encrypted content"))?;
+
+        // The initialization vector is the first 16 bytes of the key.
+        let cipher = Cbc::<Aes256, Pkcs7>::new_var(&key, &key[..16])
+            .map_err(|_| GraphFailure::CryptoError)?;
+        let decrypted = cipher
+            .decrypt_vec(&data)
+            .map_err(|_| GraphFailure::CryptoError)?;
+        Ok(serde_json::from_slice(&decrypted)?)
+    }
+}
+
+fn decode(value: &Option<String>, name: &str) -> GraphResult<Vec<u8>> {
+    let value = value
+        .as_ref()
+        .ok_or_else(|| GraphFailure::invalid(&format!("{} of the encrypted content", name)))?;
+    Ok(base64::decode(value)?)
+}
@@ -0,0 +1,32 @@
+use crate::client::Graph;
+use crate::http::{GraphResponse, IntoResponse};
+use crate::models::Subscription;
+use crate::types::{collection::Collection, content::Content};
+use reqwest::Method;
+
+register_client!(SubscriptionRequest,);
+
+impl<'a, Client> SubscriptionRequest<'a, Client>
+where
+    Client: crate::http::RequestClient,
+{
+    get!( list, Collection<Subscription> => "subscriptions" );
+    post!( [ create, Subscription => "subscriptions" ] );
+    get!( | get, Subscription => "subscriptions/{{id}}" );
+    patch!( [ | update, Subscription => "subscriptions/{{id}}" ] );
+    delete!( | delete, GraphResponse<Content> => "subscriptions/{{id}}" );
+    post!( | reauthorize, GraphResponse<Content> => "subscriptions/{{id}}/reauthorize" );
+
+    /// Extend the expiration of a subscription. The expiration date time
+    /// is an ISO 8601 date time such as 2020-08-01T11:00:00.0000000Z.
+    pub fn renew<S: AsRef<str>>(
+        &'a self,
+        id: S,
+        expiration_date_time: &str,
+    ) -> IntoResponse<'a, Subscription, Client> {
+        self.update(
+            id,
+            &serde_json::json!({ "expirationDateTime": expiration_date_time }),
+        )
+    }
+}
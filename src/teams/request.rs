@@ -0,0 +1,124 @@
+use crate::client::Graph;
+use crate::http::{GraphResponse, IntoResponse};
+use crate::models::{
+    Channel, Chat, ChatMessage, ConversationMember, Team, TeamsAppInstallation, TeamsTab,
+};
+use crate::types::{collection::Collection, content::Content};
+use reqwest::Method;
+
+register_client!(TeamsRequest,);
+register_client!(TeamChannelsRequest,);
+register_client!(ChannelMessagesRequest,);
+register_client!(ChannelTabsRequest,);
+register_client!(TeamInstalledAppsRequest,);
+register_client!(TeamMembersRequest,);
+register_client!(ChatsRequest,);
+
+impl<'a, Client> TeamsRequest<'a, Client>
+where
+    Client: crate::http::RequestClient,
+{
+    get!( | get_team, Team => "teams/{{id}}" );
+    post!( [ create_team, GraphResponse<Content> => "teams" ] );
+    put!( [| create_team_from_group, Team => "groups/{{id}}/team" ] );
+    patch!( [| update_team, GraphResponse<Content> => "teams/{{id}}" ] );
+    post!( | archive_team, GraphResponse<Content> => "teams/{{id}}/archive" );
+    post!( | unarchive_team, GraphResponse<Content> => "teams/{{id}}/unarchive" );
+    post!( [| clone_team, GraphResponse<Content> => "teams/{{id}}/clone" ] );
+
+    pub fn channels(&self) -> TeamChannelsRequest<'a, Client> {
+        TeamChannelsRequest::new(&self.client)
+    }
+
+    pub fn messages(&self) -> ChannelMessagesRequest<'a, Client> {
+        ChannelMessagesRequest::new(&self.client)
+    }
+
+    pub fn tabs(&self) -> ChannelTabsRequest<'a, Client> {
+        ChannelTabsRequest::new(&self.client)
+    }
+
+    pub fn installed_apps(&self) -> TeamInstalledAppsRequest<'a, Client> {
+        TeamInstalledAppsRequest::new(&self.client)
+    }
+
+    pub fn members(&self) -> TeamMembersRequest<'a, Client> {
+        TeamMembersRequest::new(&self.client)
+    }
+}
+
+impl<'a, Client> TeamChannelsRequest<'a, Client>
+where
+    Client: crate::http::RequestClient,
+{
+    get!( | list_channels, Collection<Channel> => "teams/{{id}}/channels" );
+    post!( [| create_channel, Channel => "teams/{{id}}/channels" ] );
+    get!( || get_channel, Channel => "teams/{{id}}/channels/{{id2}}" );
+    patch!( [|| update_channel, GraphResponse<Content> => "teams/{{id}}/channels/{{id2}}" ] );
+    delete!( || delete_channel, GraphResponse<Content> => "teams/{{id}}/channels/{{id2}}" );
+    get!( | get_primary_channel, Channel => "teams/{{id}}/primaryChannel" );
+    get!( || list_channel_members, Collection<ConversationMember> => "teams/{{id}}/channels/{{id2}}/members" );
+}
+
+impl<'a, Client> ChannelMessagesRequest<'a, Client>
+where
+    Client: crate::http::RequestClient,
+{
+    get!( || list_messages, Collection<ChatMessage> => "teams/{{id}}/channels/{{id2}}/messages" );
+    post!( [|| create_message, ChatMessage => "teams/{{id}}/channels/{{id2}}/messages" ] );
+    get!( ||| get_message, ChatMessage => "teams/{{id}}/channels/{{id2}}/messages/{{id3}}" );
+    get!( ||| list_replies, Collection<ChatMessage> => "teams/{{id}}/channels/{{id2}}/messages/{{id3}}/replies" );
+    post!( [||| create_reply, ChatMessage => "teams/{{id}}/channels/{{id2}}/messages/{{id3}}/replies" ] );
+    get!( |||| get_reply, ChatMessage => "teams/{{id}}/channels/{{id2}}/messages/{{id3}}/replies/{{id4}}" );
+}
+
+impl<'a, Client> ChannelTabsRequest<'a, Client>
+where
+    Client: crate::http::RequestClient,
+{
+    get!( || list_tabs, Collection<TeamsTab> => "teams/{{id}}/channels/{{id2}}/tabs" );
+    post!( [|| create_tab, TeamsTab => "teams/{{id}}/channels/{{id2}}/tabs" ] );
+    get!( ||| get_tab, TeamsTab => "teams/{{id}}/channels/{{id2}}/tabs/{{id3}}" );
+    patch!( [||| update_tab, TeamsTab => "teams/{{id}}/channels/{{id2}}/tabs/{{id3}}" ] );
+    delete!( ||| delete_tab, GraphResponse<Content> => "teams/{{id}}/channels/{{id2}}/tabs/{{id3}}" );
+}
+
+impl<'a, Client> TeamInstalledAppsRequest<'a, Client>
+where
+    Client: crate::http::RequestClient,
+{
+    get!( | list_installed_apps, Collection<TeamsAppInstallation> => "teams/{{id}}/installedApps" );
+    post!( [| add_installed_app, GraphResponse<Content> => "teams/{{id}}/installedApps" ] );
+    get!( || get_installed_app, TeamsAppInstallation => "teams/{{id}}/installedApps/{{id2}}" );
+    post!( || upgrade_installed_app, GraphResponse<Content> => "teams/{{id}}/installedApps/{{id2}}/upgrade" );
+    delete!( || remove_installed_app, GraphResponse<Content> => "teams/{{id}}/installedApps/{{id2}}" );
+}
+
+impl<'a, Client> TeamMembersRequest<'a, Client>
+where
+    Client: crate::http::RequestClient,
+{
+    get!( | list_members, Collection<ConversationMember> => "teams/{{id}}/members" );
+    post!( [| add_member, ConversationMember => "teams/{{id}}/members" ] );
+    get!( || get_member, ConversationMember => "teams/{{id}}/members/{{id2}}" );
+    patch!( [|| update_member, ConversationMember => "teams/{{id}}/members/{{id2}}" ] );
+    delete!( || remove_member, GraphResponse<Content> => "teams/{{id}}/members/{{id2}}" );
+}
+
+impl<'a, Client> ChatsRequest<'a, Client>
+where
+    Client: crate::http::RequestClient,
+{
+    post!( [ create_chat, Chat => "chats" ] );
+    get!( | get_chat, Chat => "chats/{{id}}" );
+    patch!( [| update_chat, Chat => "chats/{{id}}" ] );
+    get!( | list_messages, Collection<ChatMessage> => "chats/{{id}}/messages" );
+    post!( [| create_message, ChatMessage => "chats/{{id}}/messages" ] );
+    get!( || get_message, ChatMessage => "chats/{{id}}/messages/{{id2}}" );
+    get!( | list_members, Collection<ConversationMember> => "chats/{{id}}/members" );
+    post!( [| add_member, ConversationMember => "chats/{{id}}/members" ] );
+    get!( || get_member, ConversationMember => "chats/{{id}}/members/{{id2}}" );
+    delete!( || remove_member, GraphResponse<Content> => "chats/{{id}}/members/{{id2}}" );
+    get!( | list_installed_apps, Collection<TeamsAppInstallation> => "chats/{{id}}/installedApps" );
+    get!( | list_tabs, Collection<TeamsTab> => "chats/{{id}}/tabs" );
+}
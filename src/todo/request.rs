@@ -0,0 +1,83 @@
+use crate::client::Graph;
+use crate::http::{GraphResponse, IntoResponse};
+use crate::models::{ChecklistItem, LinkedResource, TodoTask, TodoTaskList};
+use crate::types::{collection::Collection, content::Content, delta::DeltaRequest};
+use handlebars::*;
+use reqwest::Method;
+
+register_client!(
+    TodoRequest,
+    tl => "todo/lists",
+);
+
+impl<'a, Client> TodoRequest<'a, Client>
+where
+    Client: crate::http::RequestClient,
+{
+    get!( list_lists, Collection<TodoTaskList> => "{{tl}}" );
+    post!( [ create_list, TodoTaskList => "{{tl}}" ] );
+    get!( | get_list, TodoTaskList => "{{tl}}/{{id}}" );
+    patch!( [| update_list, TodoTaskList => "{{tl}}/{{id}}" ] );
+    delete!( | delete_list, GraphResponse<Content> => "{{tl}}/{{id}}" );
+    get!( delta, DeltaRequest<Collection<TodoTaskList>> => "{{tl}}/delta" );
+    post!( [| create_extension, serde_json::Value => "{{tl}}/{{id}}/extensions" ] );
+    get!( || get_extension, serde_json::Value => "{{tl}}/{{id}}/extensions/{{id2}}" );
+    patch!( [|| update_extension, GraphResponse<Content> => "{{tl}}/{{id}}/extensions/{{id2}}" ] );
+    delete!( || delete_extension, GraphResponse<Content> => "{{tl}}/{{id}}/extensions/{{id2}}" );
+
+    pub fn tasks(&'a self) -> TodoTasksRequest<'a, Client> {
+        TodoTasksRequest::new(self.client)
+    }
+
+    pub fn linked_resources(&'a self) -> TodoLinkedResourcesRequest<'a, Client> {
+        TodoLinkedResourcesRequest::new(self.client)
+    }
+
+    pub fn checklist_items(&'a self) -> TodoChecklistItemsRequest<'a, Client> {
+        TodoChecklistItemsRequest::new(self.client)
+    }
+}
+
+register_client!(TodoTasksRequest,);
+
+impl<'a, Client> TodoTasksRequest<'a, Client>
+where
+    Client: crate::http::RequestClient,
+{
+    get!( | list_tasks, Collection<TodoTask> => "{{tl}}/{{id}}/tasks" );
+    post!( [| create_task, TodoTask => "{{tl}}/{{id}}/tasks" ] );
+    get!( || get_task, TodoTask => "{{tl}}/{{id}}/tasks/{{id2}}" );
+    patch!( [|| update_task, TodoTask => "{{tl}}/{{id}}/tasks/{{id2}}" ] );
+    delete!( || delete_task, GraphResponse<Content> => "{{tl}}/{{id}}/tasks/{{id2}}" );
+    get!( | delta, DeltaRequest<Collection<TodoTask>> => "{{tl}}/{{id}}/tasks/delta" );
+    post!( [|| create_extension, serde_json::Value => "{{tl}}/{{id}}/tasks/{{id2}}/extensions" ] );
+    get!( ||| get_extension, serde_json::Value => "{{tl}}/{{id}}/tasks/{{id2}}/extensions/{{id3}}" );
+    patch!( [||| update_extension, GraphResponse<Content> => "{{tl}}/{{id}}/tasks/{{id2}}/extensions/{{id3}}" ] );
+    delete!( ||| delete_extension, GraphResponse<Content> => "{{tl}}/{{id}}/tasks/{{id2}}/extensions/{{id3}}" );
+}
+
+register_client!(TodoLinkedResourcesRequest,);
+
+impl<'a, Client> TodoLinkedResourcesRequest<'a, Client>
+where
+    Client: crate::http::RequestClient,
+{
+    get!( || list_linked_resources, Collection<LinkedResource> => "{{tl}}/{{id}}/tasks/{{id2}}/linkedResources" );
+    post!( [|| create_linked_resource, LinkedResource => "{{tl}}/{{id}}/tasks/{{id2}}/linkedResources" ] );
+    get!( ||| get_linked_resource, LinkedResource => "{{tl}}/{{id}}/tasks/{{id2}}/linkedResources/{{id3}}" );
+    patch!( [||| update_linked_resource, LinkedResource => "{{tl}}/{{id}}/tasks/{{id2}}/linkedResources/{{id3}}" ] );
+    delete!( ||| delete_linked_resource, GraphResponse<Content> => "{{tl}}/{{id}}/tasks/{{id2}}/linkedResources/{{id3}}" );
+}
+
+register_client!(TodoChecklistItemsRequest,);
+
+impl<'a, Client> TodoChecklistItemsRequest<'a, Client>
+where
+    Client: crate::http::RequestClient,
+{
+    get!( || list_checklist_items, Collection<ChecklistItem> => "{{tl}}/{{id}}/tasks/{{id2}}/checklistItems" );
+    post!( [|| create_checklist_item, ChecklistItem => "{{tl}}/{{id}}/tasks/{{id2}}/checklistItems" ] );
+    get!( ||| get_checklist_item, ChecklistItem => "{{tl}}/{{id}}/tasks/{{id2}}/checklistItems/{{id3}}" );
+    patch!( [||| update_checklist_item, ChecklistItem => "{{tl}}/{{id}}/tasks/{{id2}}/checklistItems/{{id3}}" ] );
+    delete!( ||| delete_checklist_item, GraphResponse<Content> => "{{tl}}/{{id}}/tasks/{{id2}}/checklistItems/{{id3}}" );
+}
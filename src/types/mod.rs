@@ -3,3 +3,4 @@ pub mod collection;
 pub mod content;
 pub mod delta;
 pub mod embeddableurl;
+pub mod upload;
@@ -0,0 +1,9 @@
+use std::marker::PhantomData;
+
+/// The response of a request that uploads content to a drive item. The
+/// content is uploaded using a single PUT request when it is smaller than
+/// 4 MiB and using an upload session when it is larger.
+#[derive(Default)]
+pub struct UploadRequest<T> {
+    phantom: PhantomData<T>,
+}
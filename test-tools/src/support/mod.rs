@@ -1 +1,2 @@
 pub mod cleanup;
+pub mod server;
@@ -0,0 +1,86 @@
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::mpsc::{channel, Receiver};
+use std::thread;
+
+/// A minimal HTTP server used in place of the Graph API. Each
+/// connection is answered with the next response in the list and
+/// the raw request that was received is sent to the receiver.
+pub struct LocalServer;
+
+impl LocalServer {
+    pub fn serve(responses: Vec<String>) -> (String, Receiver<String>) {
+        LocalServer::serve_with(|_| responses)
+    }
+
+    /// Same as serve but the responses are created using the base url
+    /// of the server so that they can link back to it.
+    pub fn serve_with<F>(f: F) -> (String, Receiver<String>)
+    where
+        F: FnOnce(&str) -> Vec<String>,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        let responses = f(base.as_str());
+        let (sender, receiver) = channel();
+        thread::spawn(move || {
+            for response in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let request = LocalServer::read_request(&mut stream);
+                let _ = sender.send(String::from_utf8_lossy(&request).to_string());
+                stream.write_all(response.as_bytes()).unwrap();
+                stream.flush().unwrap();
+            }
+        });
+        (base, receiver)
+    }
+
+    // Reads the headers and, if there is a Content-Length header, the body
+    // of the request so that large bodies are received before responding.
+    fn read_request(stream: &mut TcpStream) -> Vec<u8> {
+        let mut request = Vec::new();
+        let mut buf = [0; 8192];
+        let mut expected = None;
+        loop {
+            let len = stream.read(&mut buf).unwrap();
+            if len == 0 {
+                return request;
+            }
+            request.extend_from_slice(&buf[..len]);
+
+            if expected.is_none() {
+                if let Some(end) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+                    let headers = String::from_utf8_lossy(&request[..end]).to_lowercase();
+                    let content_length = headers
+                        .lines()
+                        .filter(|line| line.starts_with("content-length:"))
+                        .find_map(|line| line["content-length:".len()..].trim().parse().ok())
+                        .unwrap_or(0);
+                    expected = Some(end + 4 + content_length);
+                }
+            }
+            if let Some(expected) = expected {
+                if request.len() >= expected {
+                    return request;
+                }
+            }
+        }
+    }
+
+    pub fn response(status: &str, headers: &[(&str, &str)], body: &str) -> String {
+        let mut response = format!("HTTP/1.1 {}\r\n", status);
+        for (name, value) in headers {
+            response.push_str(&format!("{}: {}\r\n", name, value));
+        }
+        response.push_str(&format!(
+            "Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        ));
+        response
+    }
+
+    pub fn json(status: &str, body: &str) -> String {
+        LocalServer::response(status, &[("Content-Type", "application/json")], body)
+    }
+}
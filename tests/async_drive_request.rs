@@ -1,3 +1,4 @@
+use futures::StreamExt;
 use graph_error::GraphError;
 use graph_rs::http::AsyncIterator;
 use graph_rs::http::NextSession;
@@ -36,6 +37,38 @@ async fn async_download() {
     }
 }
 
+#[tokio::test]
+async fn async_download_to_writer() {
+    if Environment::is_travis() || Environment::is_appveyor() {
+        return;
+    }
+
+    let _lock = ASYNC_THROTTLE_MUTEX.lock().await;
+
+    if let Some((id, client)) = OAuthTestClient::ClientCredentials.graph_async().await {
+        let download = client
+            .v1()
+            .users(id.as_str())
+            .drive()
+            .download(":/download_async.txt:", "./test_files");
+
+        let mut buf: Vec<u8> = Vec::new();
+        let len = download.write_to(&mut buf).await.unwrap();
+        assert_eq!(len, buf.len() as u64);
+        assert_eq!(buf, b"ONEDRIVE ASYNC DOWNLOAD TEST".to_vec());
+
+        let download = client
+            .v1()
+            .users(id.as_str())
+            .drive()
+            .download(":/download_async.txt:", "./test_files");
+
+        let stream = download.stream().await.unwrap();
+        let content: Vec<u8> = stream.map(|bytes| bytes.unwrap().to_vec()).concat().await;
+        assert_eq!(content, buf);
+    }
+}
+
 #[tokio::test]
 async fn async_upload_session() {
     if Environment::is_travis() || Environment::is_appveyor() {
@@ -209,7 +242,7 @@ async fn create_delete_folder_async() {
             .await;
 
         if let Ok(response) = create_folder_res {
-            let item_id = response.body()["id"].as_str().unwrap();
+            let item_id = response.body().id().as_deref().unwrap();
             tokio::time::delay_for(Duration::from_secs(2)).await;
 
             let req = client.v1().drives(id).drive().delete(item_id).send().await;
@@ -249,8 +282,8 @@ async fn drive_upload_new_and_replace_and_delete() {
             .await;
 
         if let Ok(value) = upload_res {
-            assert!(value.body()["id"].as_str().is_some());
-            let item_id = value.body()["id"].as_str().unwrap();
+            assert!(value.body().id().as_deref().is_some());
+            let item_id = value.body().id().as_deref().unwrap();
 
             let mut file = OpenOptions::new()
                 .write(true)
@@ -271,7 +304,7 @@ async fn drive_upload_new_and_replace_and_delete() {
                 .await;
 
             if let Ok(value) = upload_replace {
-                let item_id2 = value.body()["id"].as_str().unwrap();
+                let item_id2 = value.body().id().as_deref().unwrap();
                 assert_eq!(item_id, item_id2);
             } else if let Err(e) = upload_replace {
                 panic!(
@@ -1,6 +1,10 @@
+use graph_error::GraphFailure;
+use graph_rs::http::{BatchResponse, BatchStep, RetryPolicy, MAX_BATCH_STEPS};
 use graph_rs::prelude::*;
 use graph_rs::{GRAPH_URL, GRAPH_URL_BETA};
+use std::time::Duration;
 use test_tools::oauthrequest::OAuthTestClient;
+use test_tools::support::server::LocalServer;
 
 #[test]
 pub fn batch_url() {
@@ -107,3 +111,172 @@ pub fn batch_request() {
         assert!(five);
     }
 }
+
+static ID: &str = "32p99453";
+
+#[test]
+pub fn batch_steps() {
+    let client = Graph::new("");
+    let mut batch = client.v1().batch_request();
+    let item = batch.add(client.v1().me().drive().get_item(ID));
+    let update = batch.add(
+        client
+            .v1()
+            .me()
+            .drive()
+            .update(ID, &serde_json::json!({ "name": "file.txt" })),
+    );
+    batch.step(&update).unwrap().depends_on(&item);
+
+    assert_eq!(item.as_str(), "1");
+    assert_eq!(update.as_str(), "2");
+
+    let steps = batch.steps();
+    assert_eq!(steps[0].method(), "GET");
+    assert_eq!(steps[0].url(), &format!("/me/drive/items/{}", ID));
+    assert!(steps[0].headers().is_empty());
+    assert!(steps[0].body().is_none());
+
+    assert_eq!(steps[1].method(), "PATCH");
+    assert_eq!(steps[1].url(), &format!("/me/drive/items/{}", ID));
+    assert_eq!(steps[1].depends_on(), &vec!["1".to_string()]);
+    assert_eq!(
+        steps[1].headers().get("content-type"),
+        Some(&"application/json".to_string())
+    );
+    assert_eq!(
+        steps[1].body(),
+        &Some(serde_json::json!({ "name": "file.txt" }))
+    );
+}
+
+#[test]
+pub fn batch_chunks() {
+    let client = Graph::new("");
+    let mut batch = client.v1().batch_request();
+    for i in 1..26 {
+        batch.add_step(BatchStep::new(
+            i.to_string(),
+            reqwest::Method::GET,
+            format!("/me/drive/items/{}", i),
+        ));
+    }
+    batch.step("25").unwrap().depends_on("1");
+
+    // Steps that depend on each other are sent in the same batch.
+    let chunks = batch.chunks().unwrap();
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(chunks[0].len(), MAX_BATCH_STEPS);
+    assert_eq!(chunks[1].len(), 5);
+    assert!(chunks[0].iter().any(|step| step.id().eq("25")));
+    assert!(chunks[1].iter().all(|step| step.id().ne("1")));
+
+    batch.step("2").unwrap().depends_on("26");
+    assert!(batch.chunks().is_err());
+}
+
+#[test]
+pub fn batch_response() {
+    let client = Graph::new("");
+    let mut batch = client.v1().batch_request();
+    let item = batch.add(client.v1().me().drive().get_item(ID));
+    let children = batch.add(client.v1().me().drive().list_children(ID));
+
+    let response: BatchResponse = serde_json::from_value(serde_json::json!({
+        "responses": [
+            {
+                "id": "1",
+                "status": 200,
+                "headers": { "Content-Type": "application/json" },
+                "body": { "id": ID, "name": "file.txt" }
+            },
+            {
+                "id": "2",
+                "status": 424,
+                "body": {
+                    "error": {
+                        "code": "BadRequest",
+                        "message": "Depended request failed"
+                    }
+                }
+            }
+        ]
+    }))
+    .unwrap();
+
+    assert!(!response.is_success());
+    let drive_item = response.get(&item).unwrap().unwrap();
+    assert_eq!(drive_item.status(), 200);
+    assert_eq!(drive_item.body().name().as_deref(), Some("file.txt"));
+
+    match response.get(&children).unwrap() {
+        Err(GraphFailure::GraphError(err)) => {
+            assert_eq!(err.code, 424);
+            assert_eq!(err.message(), Some("Depended request failed".into()));
+        },
+        _ => panic!("Expected a failed dependency"),
+    }
+}
+
+// The body of the raw request that the local server received.
+fn request_body(request: &str) -> serde_json::Value {
+    serde_json::from_str(request.split("\r\n\r\n").nth(1).unwrap()).unwrap()
+}
+
+#[test]
+pub fn batch_retry_throttled_steps() {
+    let throttled = serde_json::json!({
+        "responses": [
+            { "id": "1", "status": 200, "body": { "id": "1" } },
+            { "id": "2", "status": 429, "headers": { "Retry-After": "0" } },
+            { "id": "3", "status": 424 }
+        ]
+    });
+    let retried = serde_json::json!({
+        "responses": [
+            { "id": "2", "status": 200, "body": { "id": "2" } },
+            { "id": "3", "status": 200, "body": { "id": "3" } }
+        ]
+    });
+    let (base, requests) = LocalServer::serve(vec![
+        LocalServer::json("200 OK", &throttled.to_string()),
+        LocalServer::json("200 OK", &retried.to_string()),
+    ]);
+    let client = Graph::new("ACCESS_TOKEN");
+    client.set_custom_endpoint(&base).unwrap();
+
+    let mut batch = client.v1().batch_request();
+    batch.retry_policy(RetryPolicy::new(3).max_delay(Duration::from_secs(1)));
+    for id in &["1", "2", "3"] {
+        batch.add_step(BatchStep::new(
+            id.to_string(),
+            reqwest::Method::GET,
+            format!("/me/drive/items/{}", id),
+        ));
+    }
+    batch.step("3").unwrap().depends_on("2");
+    let response = batch.send().unwrap();
+
+    assert!(response.is_success());
+    let ids: Vec<&str> = response
+        .responses()
+        .iter()
+        .map(|step| step.id().as_str())
+        .collect();
+    assert_eq!(ids, vec!["1", "2", "3"]);
+    assert_eq!(*response.step("2").unwrap().status(), 200);
+    assert_eq!(*response.step("3").unwrap().status(), 200);
+
+    // Only the throttled step and the step that depends on it are sent again.
+    let first = request_body(&requests.recv().unwrap());
+    assert_eq!(first["requests"].as_array().unwrap().len(), 3);
+    let second = request_body(&requests.recv().unwrap());
+    let steps = second["requests"].as_array().unwrap();
+    let ids: Vec<&str> = steps
+        .iter()
+        .map(|step| step["id"].as_str().unwrap())
+        .collect();
+    assert_eq!(ids, vec!["2", "3"]);
+    assert_eq!(steps[1]["dependsOn"], serde_json::json!(["2"]));
+    assert!(requests.recv().is_err());
+}
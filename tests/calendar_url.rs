@@ -139,3 +139,24 @@ fn calendar_events() {
         .create_event("0", "1", &serde_json::json!({}));
     assert_url_eq(&client, "/me/calendarGroups/0/calendars/1/events");
 }
+
+#[test]
+fn calendar_view_delta() {
+    let client = Graph::new("");
+    client.v1().me().calendar().views().delta("0", "1");
+    assert_url_eq(
+        &client,
+        "/me/calendarView/delta?startDateTime=0&endDateTime=1",
+    );
+
+    client
+        .v1()
+        .users("32p99453")
+        .calendar()
+        .views()
+        .delta("0", "1");
+    assert_url_eq(
+        &client,
+        "/users/32p99453/calendarView/delta?startDateTime=0&endDateTime=1",
+    )
+}
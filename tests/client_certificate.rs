@@ -0,0 +1,119 @@
+use graph_oauth::oauth::{ClientCertificate, OAuth};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs;
+use test_tools::support::server::LocalServer;
+use url::form_urlencoded;
+
+fn client_certificate(private_key: &str) -> ClientCertificate {
+    let certificate = fs::read("./test_files/certificate/certificate.pem").unwrap();
+    let private_key = fs::read(private_key).unwrap();
+    ClientCertificate::from_pem(&certificate, &private_key).unwrap()
+}
+
+fn decode(value: &str) -> Value {
+    let bytes = base64::decode_config(value, base64::URL_SAFE_NO_PAD).unwrap();
+    serde_json::from_slice(&bytes).unwrap()
+}
+
+#[test]
+fn client_certificate_thumbprint() {
+    let certificate = client_certificate("./test_files/certificate/private_key.pem");
+    assert_eq!(certificate.thumbprint(), "0rOrfZpzDMXvVZ-FqqrVJlAEuNc");
+
+    let rsa_certificate = client_certificate("./test_files/certificate/rsa_private_key.pem");
+    assert_eq!(certificate, rsa_certificate);
+}
+
+#[test]
+fn client_certificate_invalid_key() {
+    let certificate = fs::read("./test_files/certificate/certificate.pem").unwrap();
+    assert!(ClientCertificate::from_pem(&certificate, &certificate).is_err());
+    assert!(ClientCertificate::from_pem(b"certificate", b"private key").is_err());
+}
+
+#[test]
+fn client_assertion() {
+    let certificate = client_certificate("./test_files/certificate/private_key.pem");
+    let audience = "https://login.microsoftonline.com/tenant_id/oauth2/v2.0/token";
+    let assertion = certificate.client_assertion("client_id", audience).unwrap();
+
+    let parts: Vec<&str> = assertion.split('.').collect();
+    assert_eq!(parts.len(), 3);
+
+    let header = decode(parts[0]);
+    assert_eq!(header["alg"], "RS256");
+    assert_eq!(header["typ"], "JWT");
+    assert_eq!(header["x5t"], "0rOrfZpzDMXvVZ-FqqrVJlAEuNc");
+
+    let claims = decode(parts[1]);
+    assert_eq!(claims["aud"], audience);
+    assert_eq!(claims["iss"], "client_id");
+    assert_eq!(claims["sub"], "client_id");
+    assert!(claims["jti"].is_string());
+    assert_eq!(
+        claims["exp"].as_i64().unwrap() - claims["nbf"].as_i64().unwrap(),
+        600
+    );
+
+    let signature = base64::decode_config(parts[2], base64::URL_SAFE_NO_PAD).unwrap();
+    assert_eq!(signature.len(), 256);
+
+    // Each assertion has a new jti.
+    let next = certificate.client_assertion("client_id", audience).unwrap();
+    let next_claims = decode(next.split('.').nth(1).unwrap());
+    assert_ne!(claims["jti"], next_claims["jti"]);
+}
+
+#[test]
+fn client_assertion_access_token_form() {
+    let (base, requests) = LocalServer::serve(vec![LocalServer::json(
+        "200 OK",
+        r#"{
+            "token_type": "Bearer",
+            "scope": "Read.Write",
+            "expires_in": 3600,
+            "access_token": "ASODFIUJ34KJ;LADSK"
+        }"#,
+    )]);
+    let token_url = format!("{}/tenant_id/oauth2/v2.0/token", base);
+
+    let mut oauth = OAuth::new();
+    oauth
+        .client_id("bb301aaa-1201-4259-a230923fds32")
+        .client_secret("CLDIE3F")
+        .redirect_uri("http://localhost:8888/redirect")
+        .grant_type("authorization_code")
+        .add_scope("Read.Write")
+        .access_code("ALDSKFJLKERLKJALSDKJF2209LAKJGFL")
+        .access_token_url(&token_url)
+        .client_certificate(client_certificate(
+            "./test_files/certificate/private_key.pem",
+        ));
+
+    let mut request = oauth.build().authorization_code_grant();
+    let access_token = request.access_token().send().unwrap();
+    assert_eq!(access_token.bearer_token(), "ASODFIUJ34KJ;LADSK");
+
+    let token_request = requests.recv().unwrap();
+    assert!(token_request.starts_with("POST /tenant_id/oauth2/v2.0/token"));
+    let body = token_request.split("\r\n\r\n").nth(1).unwrap();
+    let form: HashMap<String, String> = form_urlencoded::parse(body.as_bytes())
+        .into_owned()
+        .collect();
+
+    // The client assertion is sent instead of the client secret.
+    assert!(!form.contains_key("client_secret"));
+    assert_eq!(
+        form["client_assertion_type"],
+        "urn:ietf:params:oauth:client-assertion-type:jwt-bearer"
+    );
+    let claims = decode(form["client_assertion"].split('.').nth(1).unwrap());
+    assert_eq!(claims["aud"], token_url.as_str());
+    assert_eq!(claims["iss"], "bb301aaa-1201-4259-a230923fds32");
+
+    assert_eq!(form["client_id"], "bb301aaa-1201-4259-a230923fds32");
+    assert_eq!(form["grant_type"], "authorization_code");
+    assert_eq!(form["code"], "ALDSKFJLKERLKJALSDKJF2209LAKJGFL");
+    assert!(requests.recv().is_err());
+}
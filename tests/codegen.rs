@@ -0,0 +1,300 @@
+use graph_codegen::{ClientSpec, Generator, ModelParser, OpenApi, Parser};
+use graph_rs::http::BlockingHttpClient;
+use graph_rs::prelude::Graph;
+
+static ID: &str = "b!CbtYWrofwUGBJWnaJkNwoNrBLp_kC3RKklSXPwrdeP3yH8_qmH9xT5Y6RODPNfYI";
+
+fn open_api() -> OpenApi {
+    OpenApi::from_file("./test_files/openapi/openapi.yaml").unwrap()
+}
+
+fn me_url(client: &ClientSpec, name: &str, ids: &[&str]) -> String {
+    let request = client
+        .find(name)
+        .unwrap_or_else(|| panic!("Missing request: {}", name));
+    format!(
+        "https://graph.microsoft.com/v1.0/me/{}",
+        request.render_path(ids)
+    )
+}
+
+// Compares the url of a generated request with the url of the hand
+// written request that was last built using the graph client. The
+// generated request must have the same name as the hand written one.
+fn assert_hand_written(
+    graph: &Graph<BlockingHttpClient>,
+    client: &ClientSpec,
+    name: &str,
+    ids: &[&str],
+) {
+    let generated = me_url(client, name, ids);
+    graph.url_ref(|url| assert_eq!(url.as_str(), generated));
+}
+
+#[test]
+fn contacts_urls() {
+    let open_api = open_api();
+    let parser = Parser::new(&open_api, "/me");
+    let client = parser.client("ContactRequest").unwrap();
+    let graph = Graph::new("");
+    let body = serde_json::json!({});
+
+    let _ = graph.v1().me().contacts().list();
+    assert_hand_written(&graph, &client, "list", &[]);
+    let _ = graph.v1().me().contacts().delta();
+    assert_hand_written(&graph, &client, "delta", &[]);
+    let _ = graph.v1().me().contacts().create(&body);
+    assert_hand_written(&graph, &client, "create", &[]);
+    let _ = graph.v1().me().contacts().get(ID);
+    assert_hand_written(&graph, &client, "get", &[ID]);
+    let _ = graph.v1().me().contacts().update(ID, &body);
+    assert_hand_written(&graph, &client, "update", &[ID]);
+    let _ = graph.v1().me().contacts().delete(ID);
+    assert_hand_written(&graph, &client, "delete", &[ID]);
+}
+
+#[test]
+fn contact_folders_urls() {
+    let open_api = open_api();
+    let parser = Parser::new(&open_api, "/me");
+    let client = parser.client("ContactFolderRequest").unwrap();
+    let graph = Graph::new("");
+
+    // The hand written request builder uses contactfolders in place of
+    // contactFolders. Paths are not case sensitive in Graph.
+    let _ = graph
+        .v1()
+        .me()
+        .contacts()
+        .contacts_folder()
+        .list_child_folders(ID);
+    graph.url_ref(|url| {
+        assert!(url
+            .as_str()
+            .eq_ignore_ascii_case(&me_url(&client, "list_child_folders", &[ID])))
+    });
+    let _ = graph
+        .v1()
+        .me()
+        .contacts()
+        .contacts_folder()
+        .create_child_folder(ID, &serde_json::json!({}));
+    graph.url_ref(|url| {
+        assert!(url
+            .as_str()
+            .eq_ignore_ascii_case(&me_url(&client, "create_child_folder", &[ID])))
+    });
+}
+
+#[test]
+fn drive_urls() {
+    let open_api = open_api();
+    let parser = Parser::new(&open_api, "/me");
+    let client = parser.client("DriveRequest").unwrap();
+    let graph = Graph::new("");
+
+    let _ = graph.v1().me().drive().get_item(ID);
+    assert_hand_written(&graph, &client, "get_item", &[ID]);
+    let _ = graph.v1().me().drive().list_children(ID);
+    assert_hand_written(&graph, &client, "list_children", &[ID]);
+    let _ = graph.v1().me().drive().root_children();
+    assert_hand_written(&graph, &client, "root_children", &[]);
+    let _ = graph.v1().me().drive().list_versions(ID);
+    assert_hand_written(&graph, &client, "list_versions", &[ID]);
+
+    // Functions that take parameters are not supported.
+    assert!(client.find("search").is_none());
+}
+
+#[test]
+fn mail_urls() {
+    let open_api = open_api();
+    let parser = Parser::new(&open_api, "/me");
+    let graph = Graph::new("");
+
+    let client = parser.client("MessageRequest").unwrap();
+    let _ = graph.v1().me().mail().messages().create_reply("1234");
+    assert_hand_written(&graph, &client, "create_reply", &["1234"]);
+
+    // The hand written request builders name these send_message and
+    // mail_folder().messages().get().
+    let _ = graph.v1().me().mail().messages().send_message("1234");
+    graph.url_ref(|url| assert_eq!(url.as_str(), me_url(&client, "send", &["1234"])));
+    let client = parser.client("MailFolderRequest").unwrap();
+    let _ = graph
+        .v1()
+        .me()
+        .mail()
+        .mail_folder()
+        .messages()
+        .get("99453", "1234");
+    graph.url_ref(|url| {
+        assert_eq!(
+            url.as_str(),
+            me_url(&client, "get_message", &["99453", "1234"])
+        )
+    });
+}
+
+#[test]
+fn resource_path() {
+    let open_api = open_api();
+    let parser = Parser::new(&open_api, "/users/{user-id}");
+    let clients = parser.clients();
+
+    assert_eq!(clients.len(), 1);
+    assert_eq!(clients[0].name, "ContactRequest");
+    assert_eq!(clients[0].requests.len(), 1);
+    assert_eq!(clients[0].requests[0].path, "contacts");
+}
+
+#[test]
+fn request_macros() {
+    let open_api = open_api();
+    let parser = Parser::new(&open_api, "/me");
+    let macros: Vec<String> = parser
+        .clients()
+        .iter()
+        .flat_map(|client| client.requests.iter().map(|request| request.to_macro()))
+        .collect();
+
+    let expected = [
+        r#"get!( list, Collection<Contact> => "contacts" );"#,
+        r#"post!( [ create, Contact => "contacts" ] );"#,
+        r#"get!( | get, Contact => "contacts/{{id}}" );"#,
+        r#"patch!( [ | update, GraphResponse<Content> => "contacts/{{id}}" ] );"#,
+        r#"delete!( | delete, GraphResponse<Content> => "contacts/{{id}}" );"#,
+        r#"get!( delta, DeltaRequest<Collection<Contact>> => "contacts/delta" );"#,
+        r#"get!( | list_child_folders, Collection<ContactFolder> => "contactFolders/{{id}}/childFolders" );"#,
+        r#"post!( [ | create_child_folder, ContactFolder => "contactFolders/{{id}}/childFolders" ] );"#,
+        r#"get!( | get_item_content, GraphResponse<Content> => "drive/items/{{id}}/content" );"#,
+        r#"get!( | list_versions, Collection<DriveItemVersion> => "drive/items/{{id}}/versions" );"#,
+        r#"get!( || get_message, Message => "mailFolders/{{id}}/messages/{{id2}}" );"#,
+        r#"post!( [ | create_reply, Message => "messages/{{id}}/createReply" ] );"#,
+        r#"post!( | send, GraphResponse<Content> => "messages/{{id}}/send" );"#,
+    ];
+    for e in expected.iter() {
+        assert!(
+            macros.contains(&e.to_string()),
+            "Missing: {}\nFound: {:#?}",
+            e,
+            macros
+        );
+    }
+}
+
+#[test]
+fn client_module() {
+    let open_api = open_api();
+    let generator = Generator::new(&open_api, "/me");
+    let client = generator
+        .clients()
+        .into_iter()
+        .find(|client| client.name.eq("ContactRequest"))
+        .unwrap();
+    let module = generator.client_module(&client);
+
+    assert!(module.starts_with(
+        "use crate::client::Graph;
+use crate::http::{GraphResponse, IntoResponse};
+use crate::models::Contact;
+use crate::types::{collection::Collection, content::Content, delta::DeltaRequest};
+use handlebars::*;
+use reqwest::Method;
+
+register_client!(ContactRequest,);
+
+impl<'a, Client> ContactRequest<'a, Client>
+where
+    Client: crate::http::RequestClient,
+{
+"
+    ));
+    assert!(module.ends_with("}\n"));
+}
+
+#[test]
+fn models() {
+    let open_api = open_api();
+    let parser = ModelParser::new(&open_api);
+    let models = parser.models();
+
+    // Collection responses and enums are not models.
+    assert!(models.iter().all(|model| !model.name.ends_with("Response")));
+    assert!(parser.model("microsoft.graph.importance").is_none());
+
+    let contact = parser.model("microsoft.graph.contact").unwrap();
+    assert_eq!(contact.name, "Contact");
+    assert_eq!(contact.description.as_deref(), Some("An Outlook contact."));
+    assert_eq!(contact.field("id").unwrap().field_type, "String");
+    assert_eq!(
+        contact.field("email_addresses").unwrap().field_type,
+        "Vec<EmailAddress>"
+    );
+
+    let drive_item = parser.model("microsoft.graph.driveItem").unwrap();
+    assert_eq!(drive_item.field("size").unwrap().field_type, "i64");
+    assert_eq!(drive_item.field("e_tag").unwrap().rename, None);
+    let download_url = drive_item.field("download_url").unwrap();
+    assert_eq!(
+        download_url.rename.as_deref(),
+        Some("@microsoft.graph.downloadUrl")
+    );
+    assert_eq!(
+        drive_item.field("children").unwrap().field_type,
+        "Vec<DriveItem>"
+    );
+    // A drive item and a list item contain each other.
+    assert_eq!(
+        drive_item.field("list_item").unwrap().field_type,
+        "Box<ListItem>"
+    );
+    let list_item = parser.model("microsoft.graph.listItem").unwrap();
+    assert_eq!(
+        list_item.field("drive_item").unwrap().field_type,
+        "Box<DriveItem>"
+    );
+    assert_eq!(
+        list_item.field("content_type").unwrap().field_type,
+        "ContentTypeInfo"
+    );
+
+    let message = parser.model("microsoft.graph.message").unwrap();
+    assert_eq!(message.field("importance").unwrap().field_type, "String");
+    assert_eq!(message.field("from").unwrap().field_type, "EmailAddress");
+
+    let event = parser.model("microsoft.graph.event").unwrap();
+    let event_type = event.field("event_type").unwrap();
+    assert_eq!(event_type.rename.as_deref(), Some("type"));
+    let self_url = event.field("self_url").unwrap();
+    assert_eq!(self_url.rename.as_deref(), Some("self"));
+    assert_eq!(event.field("percent_complete").unwrap().field_type, "f64");
+}
+
+#[test]
+fn model_struct() {
+    let open_api = open_api();
+    let parser = ModelParser::new(&open_api);
+    let model = parser.model("microsoft.graph.emailAddress").unwrap();
+
+    assert_eq!(
+        model.to_struct(),
+        r#"/// [emailAddress](https://docs.microsoft.com/en-us/graph/api/resources/emailaddress?view=graph-rest-1.0)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Setters, Getters)]
+#[serde(rename_all = "camelCase")]
+#[set = "pub"]
+#[get = "pub"]
+pub struct EmailAddress {
+    #[serde(rename = "@odata.type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    odata_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(flatten)]
+    additional_data: HashMap<String, Value>,
+}
+"#
+    );
+}
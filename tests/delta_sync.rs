@@ -0,0 +1,243 @@
+use graph_rs::client::Graph;
+use graph_rs::http::{DeltaChange, DeltaStore, FileDeltaStore, InMemoryDeltaStore};
+use graph_rs::models::DriveItem;
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+use test_tools::oauthrequest::{Environment, OAuthTestClient, THROTTLE_MUTEX};
+use test_tools::support::cleanup::CleanUp;
+use test_tools::support::server::LocalServer;
+
+static DELTA_LINK: &str =
+    "https://graph.microsoft.com/v1.0/me/drive/root/delta?token=1230919asd190410jlka";
+
+#[test]
+fn in_memory_delta_store() {
+    let store = InMemoryDeltaStore::new();
+    assert_eq!(store.get("me/drive").unwrap(), None);
+
+    store.set("me/drive", DELTA_LINK).unwrap();
+    assert_eq!(store.get("me/drive").unwrap(), Some(DELTA_LINK.to_string()));
+    assert_eq!(store.get("me/contacts").unwrap(), None);
+
+    store.remove("me/drive").unwrap();
+    assert_eq!(store.get("me/drive").unwrap(), None);
+}
+
+#[test]
+fn file_delta_store() {
+    let file_location = "./test_files/delta_store.json";
+    let mut clean_up = CleanUp::new(|| {
+        if Path::new(file_location).exists() {
+            fs::remove_file(Path::new(file_location)).unwrap();
+        }
+    });
+    clean_up.rm_files(file_location.into());
+
+    let store = FileDeltaStore::new(file_location);
+    assert_eq!(store.get("me/drive").unwrap(), None);
+    store.set("me/drive", DELTA_LINK).unwrap();
+    store.set("me/contacts", DELTA_LINK).unwrap();
+
+    let store = FileDeltaStore::new(file_location);
+    assert_eq!(store.get("me/drive").unwrap(), Some(DELTA_LINK.to_string()));
+
+    store.remove("me/drive").unwrap();
+    assert_eq!(store.get("me/drive").unwrap(), None);
+    assert_eq!(
+        store.get("me/contacts").unwrap(),
+        Some(DELTA_LINK.to_string())
+    );
+}
+
+#[test]
+fn delta_change_from_value() {
+    let value = serde_json::json!({ "id": "1234", "name": "file.txt" });
+    let change: DeltaChange<DriveItem> = DeltaChange::from_value(value.clone(), true).unwrap();
+    match change {
+        DeltaChange::Added(item) => assert_eq!(item.name(), &Some("file.txt".to_string())),
+        _ => panic!("Expected DeltaChange::Added. Change: {:#?}", change),
+    }
+
+    let change: DeltaChange<DriveItem> = DeltaChange::from_value(value, false).unwrap();
+    match change {
+        DeltaChange::Updated(item) => assert_eq!(item.id(), &Some("1234".to_string())),
+        _ => panic!("Expected DeltaChange::Updated. Change: {:#?}", change),
+    }
+
+    let value = serde_json::json!({ "id": "1234", "@removed": { "reason": "deleted" } });
+    let change: DeltaChange<DriveItem> = DeltaChange::from_value(value, false).unwrap();
+    assert_eq!(
+        change,
+        DeltaChange::Removed {
+            id: "1234".into(),
+            reason: Some("deleted".into())
+        }
+    );
+
+    let value = serde_json::json!({ "id": "1234", "deleted": { "state": "deleted" } });
+    let change: DeltaChange<DriveItem> = DeltaChange::from_value(value, false).unwrap();
+    assert_eq!(
+        change,
+        DeltaChange::Removed {
+            id: "1234".into(),
+            reason: None
+        }
+    );
+
+    let value = serde_json::json!({ "@removed": { "reason": "changed" } });
+    assert!(DeltaChange::<DriveItem>::from_value(value, false).is_err());
+}
+
+fn delta_page() -> String {
+    LocalServer::json(
+        "200 OK",
+        &format!(
+            "{{\"value\":[{{\"id\":\"1\",\"name\":\"a.txt\"}},{{\"id\":\"2\",\"name\":\"b.txt\"}}],\"@odata.deltaLink\":\"{}\"}}",
+            DELTA_LINK
+        ),
+    )
+}
+
+fn resync_required() -> String {
+    LocalServer::json(
+        "410 Gone",
+        "{\"error\":{\"code\":\"resyncRequired\",\"message\":\"Resync required.\"}}",
+    )
+}
+
+fn local_client(base: &str) -> Graph<graph_rs::http::BlockingHttpClient> {
+    let client = Graph::new("ACCESS_TOKEN");
+    client.set_custom_endpoint(base).unwrap();
+    client
+}
+
+#[test]
+fn delta_sync_stores_delta_link_after_last_change() {
+    let (base, requests) = LocalServer::serve(vec![delta_page()]);
+    let client = local_client(&base);
+    let store: Arc<dyn DeltaStore> = Arc::new(InMemoryDeltaStore::new());
+
+    let mut sync = client
+        .v1()
+        .me()
+        .drive()
+        .delta()
+        .sync("me/drive", store.clone());
+
+    assert!(matches!(sync.next(), Some(Ok(DeltaChange::Added(_)))));
+    assert_eq!(store.get("me/drive").unwrap(), None);
+    assert!(matches!(sync.next(), Some(Ok(DeltaChange::Added(_)))));
+    assert_eq!(store.get("me/drive").unwrap(), None);
+
+    assert!(sync.next().is_none());
+    assert_eq!(store.get("me/drive").unwrap(), Some(DELTA_LINK.to_string()));
+    assert_eq!(sync.delta_link(), Some(&DELTA_LINK.to_string()));
+
+    let request = requests.recv().unwrap();
+    assert!(request.starts_with("GET /v1.0/me/drive/root/delta HTTP/1.1"));
+}
+
+#[test]
+fn delta_sync_restarts_once_on_resync() {
+    let (base, requests) = LocalServer::serve(vec![resync_required(), delta_page()]);
+    let client = local_client(&base);
+    let store: Arc<dyn DeltaStore> = Arc::new(InMemoryDeltaStore::new());
+    store
+        .set(
+            "me/drive",
+            &format!("{}/v1.0/me/drive/root/delta?token=expired", base),
+        )
+        .unwrap();
+
+    let changes: Vec<DeltaChange<DriveItem>> = client
+        .v1()
+        .me()
+        .drive()
+        .delta()
+        .sync("me/drive", store.clone())
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+    assert_eq!(changes.len(), 3);
+    assert_eq!(changes[0], DeltaChange::Resync);
+    assert!(matches!(changes[1], DeltaChange::Added(_)));
+    assert!(matches!(changes[2], DeltaChange::Added(_)));
+    assert_eq!(store.get("me/drive").unwrap(), Some(DELTA_LINK.to_string()));
+
+    let first = requests.recv().unwrap();
+    let second = requests.recv().unwrap();
+    assert!(first.starts_with("GET /v1.0/me/drive/root/delta?token=expired HTTP/1.1"));
+    assert!(second.starts_with("GET /v1.0/me/drive/root/delta HTTP/1.1"));
+}
+
+#[test]
+fn delta_sync_does_not_restart_twice() {
+    let (base, requests) = LocalServer::serve(vec![resync_required(), resync_required()]);
+    let client = local_client(&base);
+    let store: Arc<dyn DeltaStore> = Arc::new(InMemoryDeltaStore::new());
+    store
+        .set(
+            "me/drive",
+            &format!("{}/v1.0/me/drive/root/delta?token=expired", base),
+        )
+        .unwrap();
+
+    let mut sync = client
+        .v1()
+        .me()
+        .drive()
+        .delta()
+        .sync("me/drive", store.clone());
+
+    assert_eq!(sync.next().unwrap().unwrap(), DeltaChange::Resync);
+    assert!(sync.next().unwrap().is_err());
+    assert!(sync.next().is_none());
+    assert_eq!(store.get("me/drive").unwrap(), None);
+
+    assert!(requests.recv().is_ok());
+    assert!(requests.recv().is_ok());
+    assert!(requests.recv().is_err());
+}
+
+#[test]
+fn drive_delta_sync() {
+    if Environment::is_appveyor() {
+        return;
+    }
+
+    let _lock = THROTTLE_MUTEX.lock().unwrap();
+    if let Some((id, client)) = OAuthTestClient::ClientCredentials.graph() {
+        let store: Arc<dyn DeltaStore> = Arc::new(InMemoryDeltaStore::new());
+        for change in client
+            .v1()
+            .drives(id.as_str())
+            .drive()
+            .delta()
+            .sync("drive", store.clone())
+        {
+            match change {
+                Ok(DeltaChange::Added(_)) | Ok(DeltaChange::Removed { .. }) => {},
+                Ok(change) => panic!("Expected DeltaChange::Added. Change: {:#?}", change),
+                Err(err) => panic!("Request error. Method: drive delta sync. Error: {:#?}", err),
+            }
+        }
+        let delta_link = store.get("drive").unwrap();
+        assert!(delta_link.is_some());
+
+        for change in client
+            .v1()
+            .drives(id.as_str())
+            .drive()
+            .delta()
+            .sync("drive", store.clone())
+        {
+            match change {
+                Ok(DeltaChange::Added(_)) => panic!("Expected no DeltaChange::Added"),
+                Ok(_) => {},
+                Err(err) => panic!("Request error. Method: drive delta sync. Error: {:#?}", err),
+            }
+        }
+        assert!(store.get("drive").unwrap().is_some());
+    }
+}
@@ -0,0 +1,177 @@
+use graph_rs::http::BlockingHttpClient;
+use graph_rs::models::DirectoryObjectReference;
+use graph_rs::prelude::Graph;
+use test_tools::support::server::LocalServer;
+
+static ID: &str = "b!CbtYWrofwUGBJWnaJkNwoNrBLp_kC3RKklSXPwrdeP3yH8_qmH9xT5Y6RODPNfYI";
+static MEMBER_ID: &str = "87d349ed-44d7-43e1-9a83-5f2406dee5bd";
+
+fn get_graph() -> Graph<BlockingHttpClient> {
+    Graph::new("")
+}
+
+#[test]
+fn directory_roles_url() {
+    let client = get_graph();
+    client.v1().directory_roles().list_roles();
+
+    client.url_ref(|url| {
+        assert_eq!(
+            "https://graph.microsoft.com/v1.0/directoryRoles",
+            url.as_str()
+        );
+    });
+
+    client
+        .v1()
+        .directory_roles()
+        .add_member_by_id(ID, MEMBER_ID);
+
+    client.url_ref(|url| {
+        assert_eq!(
+            &format!(
+                "https://graph.microsoft.com/v1.0/directoryRoles/{}/members/$ref",
+                ID
+            ),
+            url.as_str()
+        );
+    });
+
+    client.v1().directory_roles().remove_member(ID, MEMBER_ID);
+
+    client.url_ref(|url| {
+        assert_eq!(
+            &format!(
+                "https://graph.microsoft.com/v1.0/directoryRoles/{}/members/{}/$ref",
+                ID, MEMBER_ID
+            ),
+            url.as_str()
+        );
+    });
+}
+
+#[test]
+fn directory_objects_url() {
+    let client = get_graph();
+    client
+        .v1()
+        .directory_objects()
+        .get_by_ids(&[ID, MEMBER_ID], &["user"]);
+
+    client.url_ref(|url| {
+        assert_eq!(
+            "https://graph.microsoft.com/v1.0/directoryObjects/getByIds",
+            url.as_str()
+        );
+    });
+
+    client
+        .beta()
+        .directory_objects()
+        .member_objects(ID, &serde_json::json!({ "securityEnabledOnly": true }));
+
+    client.url_ref(|url| {
+        assert_eq!(
+            &format!(
+                "https://graph.microsoft.com/beta/directoryObjects/{}/getMemberObjects",
+                ID
+            ),
+            url.as_str()
+        );
+    });
+}
+
+#[test]
+fn group_member_reference() {
+    let client = get_graph();
+    client.v1().groups(ID).add_owner_by_id(MEMBER_ID);
+
+    client.url_ref(|url| {
+        assert_eq!(
+            &format!("https://graph.microsoft.com/v1.0/groups/{}/owners/$ref", ID),
+            url.as_str()
+        );
+    });
+
+    let reference = DirectoryObjectReference::new("https://graph.microsoft.com/beta/", MEMBER_ID);
+    assert_eq!(
+        serde_json::to_value(&reference).unwrap(),
+        serde_json::json!({
+            "@odata.id":
+                format!(
+                    "https://graph.microsoft.com/beta/directoryObjects/{}",
+                    MEMBER_ID
+                )
+        })
+    );
+}
+
+#[test]
+fn member_reference_uses_base_url() {
+    let no_content = || LocalServer::response("204 No Content", &[], "");
+    let (base, requests) = LocalServer::serve(vec![no_content(), no_content(), no_content()]);
+    let client = get_graph();
+    client.set_custom_endpoint(&base).unwrap();
+
+    client
+        .beta()
+        .groups(ID)
+        .add_member_by_id(MEMBER_ID)
+        .send()
+        .unwrap();
+    client
+        .v1()
+        .groups(ID)
+        .add_owner_by_id(MEMBER_ID)
+        .send()
+        .unwrap();
+    client
+        .v1()
+        .directory_roles()
+        .add_member_by_id(ID, MEMBER_ID)
+        .send()
+        .unwrap();
+
+    for version in &["beta", "v1.0", "v1.0"] {
+        let request = requests.recv().unwrap();
+        let body: serde_json::Value =
+            serde_json::from_str(request.split("\r\n\r\n").nth(1).unwrap()).unwrap();
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "@odata.id": format!("{}/{}/directoryObjects/{}", base, version, MEMBER_ID)
+            })
+        );
+    }
+}
+
+#[test]
+fn user_member_of_url() {
+    let client = get_graph();
+    client.v1().users(ID).list_transitive_member_of();
+
+    client.url_ref(|url| {
+        assert_eq!(
+            &format!(
+                "https://graph.microsoft.com/v1.0/users/{}/transitiveMemberOf",
+                ID
+            ),
+            url.as_str()
+        );
+    });
+
+    client
+        .v1()
+        .users(ID)
+        .check_member_groups(&serde_json::json!({ "groupIds": [MEMBER_ID] }));
+
+    client.url_ref(|url| {
+        assert_eq!(
+            &format!(
+                "https://graph.microsoft.com/v1.0/users/{}/checkMemberGroups",
+                ID
+            ),
+            url.as_str()
+        );
+    });
+}
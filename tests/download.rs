@@ -0,0 +1,167 @@
+use futures::StreamExt;
+use graph_rs::error::{GraphFailure, GraphResult, GraphRsError};
+use graph_rs::models::Hashes;
+use graph_rs::prelude::*;
+use std::ffi::OsString;
+use std::fs;
+use std::path::Path;
+use test_tools::support::cleanup::CleanUp;
+use test_tools::support::server::LocalServer;
+
+fn clean_up(file_location: &str) -> CleanUp {
+    let part = format!("{}.part", file_location);
+    let mut clean_up = CleanUp::new(|| {
+        for file in &[file_location, part.as_str()] {
+            if Path::new(file).exists() {
+                fs::remove_file(Path::new(file)).unwrap();
+            }
+        }
+    });
+    clean_up.rm_files(file_location.into());
+    clean_up.rm_files(part);
+    clean_up
+}
+
+fn partial_content() -> String {
+    LocalServer::response(
+        "206 Partial Content",
+        &[
+            ("Content-Type", "text/plain"),
+            ("Content-Range", "bytes 6-10/11"),
+        ],
+        "world",
+    )
+}
+
+fn whole_content() -> String {
+    LocalServer::response("200 OK", &[("Content-Type", "text/plain")], "hello world")
+}
+
+#[test]
+fn download_resume_partial_file() {
+    let file_location = "./test_files/download_resume.txt";
+    let _clean_up = clean_up(file_location);
+    fs::write("./test_files/download_resume.txt.part", "hello ").unwrap();
+
+    let (base, requests) = LocalServer::serve(vec![partial_content()]);
+    let client = Graph::new("ACCESS_TOKEN");
+    client.set_custom_endpoint(&base).unwrap();
+
+    let download_client = client
+        .v1()
+        .me()
+        .drive()
+        .download(":/download_resume.txt:", "./test_files");
+    download_client.rename(OsString::from("download_resume.txt"));
+    download_client.resume(true);
+    let path = download_client.send().unwrap();
+
+    assert_eq!(path, Path::new(file_location));
+    assert_eq!(fs::read_to_string(file_location).unwrap(), "hello world");
+    assert!(!Path::new("./test_files/download_resume.txt.part").exists());
+
+    // Only the Range request is sent.
+    let request = requests.recv().unwrap().to_lowercase();
+    assert!(request.contains("range: bytes=6-"));
+    assert!(requests.recv().is_err());
+}
+
+#[test]
+fn download_resume_whole_file_returned() {
+    let file_location = "./test_files/download_resume_whole.txt";
+    let _clean_up = clean_up(file_location);
+    fs::write("./test_files/download_resume_whole.txt.part", "hello ").unwrap();
+
+    let (base, requests) = LocalServer::serve(vec![whole_content()]);
+    let client = Graph::new("ACCESS_TOKEN");
+    client.set_custom_endpoint(&base).unwrap();
+
+    let download_client = client
+        .v1()
+        .me()
+        .drive()
+        .download(":/download_resume_whole.txt:", "./test_files");
+    download_client.rename(OsString::from("download_resume_whole.txt"));
+    download_client.resume(true);
+    download_client.send().unwrap();
+
+    // The server ignored the Range header so the .part file is replaced.
+    assert_eq!(fs::read_to_string(file_location).unwrap(), "hello world");
+    let request = requests.recv().unwrap().to_lowercase();
+    assert!(request.contains("range: bytes=6-"));
+    assert!(requests.recv().is_err());
+}
+
+#[tokio::test]
+async fn async_download_resume_partial_file() {
+    let file_location = "./test_files/async_download_resume.txt";
+    let _clean_up = clean_up(file_location);
+    fs::write("./test_files/async_download_resume.txt.part", "hello ").unwrap();
+
+    let (base, requests) = LocalServer::serve(vec![partial_content()]);
+    let client = Graph::new_async("ACCESS_TOKEN");
+    client.set_custom_endpoint(&base).unwrap();
+
+    let download_client = client
+        .v1()
+        .me()
+        .drive()
+        .download(":/async_download_resume.txt:", "./test_files");
+    download_client
+        .rename(OsString::from("async_download_resume.txt"))
+        .await;
+    download_client.resume(true).await;
+    download_client.send().await.unwrap();
+
+    assert_eq!(fs::read_to_string(file_location).unwrap(), "hello world");
+    let request = requests.recv().unwrap().to_lowercase();
+    assert!(request.contains("range: bytes=6-"));
+    assert!(requests.recv().is_err());
+}
+
+async fn stream_with_sha1(sha1: &str) -> Vec<GraphResult<Vec<u8>>> {
+    let (base, requests) = LocalServer::serve(vec![whole_content()]);
+    let client = Graph::new_async("ACCESS_TOKEN");
+    client.set_custom_endpoint(&base).unwrap();
+
+    let download_client = client
+        .v1()
+        .me()
+        .drive()
+        .download(":/async_download_stream.txt:", "./test_files");
+    let mut hashes = Hashes::default();
+    hashes.set_sha1_hash(Some(sha1.into()));
+    download_client.verify_hashes(hashes).await;
+
+    let stream = download_client.stream().await.unwrap();
+    let items = stream
+        .map(|item| item.map(|bytes| bytes.to_vec()))
+        .collect()
+        .await;
+    assert!(requests.recv().is_ok());
+    assert!(requests.recv().is_err());
+    items
+}
+
+#[tokio::test]
+async fn async_download_stream_verify_hashes() {
+    let items = stream_with_sha1("2AAE6C35C94FCFB415DBE95F408B9CE91EE846ED").await;
+    let content: Vec<u8> = items.into_iter().flat_map(|item| item.unwrap()).collect();
+    assert_eq!(content, b"hello world".to_vec());
+}
+
+#[tokio::test]
+async fn async_download_stream_hash_mismatch() {
+    let mut items = stream_with_sha1("A94A8FE5CCB19BA61C4C0873D391E987982FBBD3").await;
+    match items.pop() {
+        Some(Err(GraphFailure::GraphRsError(GraphRsError::DownloadHashMismatch {
+            hash, ..
+        }))) => assert_eq!(hash, "sha1Hash"),
+        item => panic!(
+            "Expected a hash mismatch as the last item. Got: {:#?}",
+            item
+        ),
+    }
+    let content: Vec<u8> = items.into_iter().flat_map(|item| item.unwrap()).collect();
+    assert_eq!(content, b"hello world".to_vec());
+}
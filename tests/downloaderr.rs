@@ -1,4 +1,5 @@
 use graph_rs::error::*;
+use graph_rs::models::Hashes;
 use graph_rs::prelude::*;
 use test_tools::oauthrequest::OAuthTestClient;
 use test_tools::oauthrequest::DRIVE_THROTTLE_MUTEX;
@@ -82,3 +83,17 @@ fn download_is_err_config_dir_no_exists() {
         panic!("Download request should have thrown GraphRsError::DownloadDirNoExists. Instead got successful PathBuf: {:#?}", path);
     }
 }
+
+#[test]
+fn download_verify_hashes_with_range_is_err() {
+    let client = Graph::new("");
+
+    let download_client = client.v1().me().drive().download("", "./test_files");
+
+    let mut hashes = Hashes::default();
+    hashes.set_sha1_hash(Some("A94A8FE5CCB19BA61C4C0873D391E987982FBBD3".into()));
+    download_client.range(0, Some(99)).verify_hashes(hashes);
+
+    let result = download_client.send();
+    assert!(result.is_err());
+}
@@ -32,7 +32,7 @@ fn create_delete_folder() {
             .send();
 
         if let Ok(response) = create_folder_res {
-            let item_id = response.body()["id"].as_str().unwrap();
+            let item_id = response.body().id().as_deref().unwrap();
             thread::sleep(Duration::from_secs(2));
 
             let req = client.v1().drives(id).drive().delete(item_id).send();
@@ -64,8 +64,8 @@ fn list_versions_get_item() {
             .send();
 
         if let Ok(res) = get_item_res {
-            assert!(res.body()["id"].as_str().is_some());
-            let item_id = res.body()["id"].as_str().unwrap();
+            assert!(res.body().id().as_deref().is_some());
+            let item_id = res.body().id().as_deref().unwrap();
 
             let versions_res = client
                 .v1()
@@ -220,7 +220,7 @@ fn drive_update() {
             .send();
 
         if let Ok(response) = req {
-            assert_eq!(response.body()["name"].as_str(), Some("update_test.docx"));
+            assert_eq!(response.body().name().as_deref(), Some("update_test.docx"));
             thread::sleep(Duration::from_secs(2));
 
             let req = client
@@ -237,7 +237,7 @@ fn drive_update() {
 
             if let Ok(response) = req {
                 assert_eq!(
-                    response.body()["name"].as_str(),
+                    response.body().name().as_deref(),
                     Some("update_test_document.docx")
                 );
             } else if let Err(e) = req {
@@ -264,8 +264,8 @@ fn drive_upload_new_and_replace_and_delete() {
             .send();
 
         if let Ok(value) = upload_res {
-            assert!(value.body()["id"].as_str().is_some());
-            let item_id = value.body()["id"].as_str().unwrap();
+            assert!(value.body().id().as_deref().is_some());
+            let item_id = value.body().id().as_deref().unwrap();
 
             let mut file = OpenOptions::new()
                 .write(true)
@@ -284,7 +284,7 @@ fn drive_upload_new_and_replace_and_delete() {
                 .send();
 
             if let Ok(value) = upload_replace {
-                let item_id2 = value.body()["id"].as_str().unwrap();
+                let item_id2 = value.body().id().as_deref().unwrap();
                 assert_eq!(item_id, item_id2);
             } else if let Err(e) = upload_replace {
                 panic!(
@@ -1000,3 +1000,110 @@ pub fn drive_item_activities() {
         "/sites/T5Y6RODPNfYICbtYWrofwUGBJWnaJkNwH9x/drive/root:/Documents/item.txt:/activities",
     );
 }
+
+#[test]
+pub fn drive_item_permissions() {
+    let client = get_drive();
+    let _ = client.v1().me().drive().list_permissions(ID);
+    assert_url_eq(&client, "/me/drive/items/b!CbtYWrofwUGBJWnaJkNwoNrBLp_kC3RKklSXPwrdeP3yH8_qmH9xT5Y6RODPNfYI/permissions");
+
+    let _ = client.v1().drives(RID).drive().delete_permission(ID, RID);
+    assert_url_eq(&client, "/drives/T5Y6RODPNfYICbtYWrofwUGBJWnaJkNwH9x/items/b!CbtYWrofwUGBJWnaJkNwoNrBLp_kC3RKklSXPwrdeP3yH8_qmH9xT5Y6RODPNfYI/permissions/T5Y6RODPNfYICbtYWrofwUGBJWnaJkNwH9x");
+
+    let _ = client
+        .v1()
+        .me()
+        .drive()
+        .create_link(ID, &serde_json::json!({ "type": "view" }));
+    assert_url_eq(&client, "/me/drive/items/b!CbtYWrofwUGBJWnaJkNwoNrBLp_kC3RKklSXPwrdeP3yH8_qmH9xT5Y6RODPNfYI/createLink");
+
+    let _ = client
+        .v1()
+        .sites(RID)
+        .drive()
+        .invite(":/Documents/item.txt:", &serde_json::json!({}));
+    assert_url_eq(
+        &client,
+        "/sites/T5Y6RODPNfYICbtYWrofwUGBJWnaJkNwH9x/drive/root:/Documents/item.txt:/invite",
+    );
+}
+
+#[test]
+pub fn shares() {
+    let client = get_drive();
+    let _ = client.v1().shares().get_drive_item_by_url(
+        "https://onedrive.live.com/redir?resid=1231244193912!12&authKey=1201919!12921!1",
+    );
+    assert_url_eq(&client, "/shares/u!aHR0cHM6Ly9vbmVkcml2ZS5saXZlLmNvbS9yZWRpcj9yZXNpZD0xMjMxMjQ0MTkzOTEyITEyJmF1dGhLZXk9MTIwMTkxOSExMjkyMSEx/driveItem");
+
+    let _ = client.v1().shares().get_permission("s!AkxKb2xU");
+    assert_url_eq(&client, "/shares/s!AkxKb2xU/permission");
+}
+
+#[test]
+pub fn drive_item_path() {
+    let client = get_drive();
+    let _ = client
+        .v1()
+        .me()
+        .drive()
+        .get_item("/Documents/My File#1.txt");
+    assert_url_eq(&client, "/me/drive/root:/Documents/My%20File%231.txt:");
+
+    let _ = client
+        .v1()
+        .drives(RID)
+        .drive()
+        .list_children(":/Documents/Q&A:");
+    assert_url_eq(
+        &client,
+        "/drives/T5Y6RODPNfYICbtYWrofwUGBJWnaJkNwH9x/root:/Documents/Q&A:/children",
+    );
+
+    let _ =
+        client
+            .v1()
+            .me()
+            .drive()
+            .upload_new_content(":/Documents:", "notes 2020.txt", vec![0u8]);
+    assert_url_eq(&client, "/me/drive/root:/Documents/notes%202020.txt:");
+
+    let _ = client
+        .v1()
+        .me()
+        .drive()
+        .upload_new_content(ID, "notes.txt", vec![0u8]);
+    assert_url_eq(
+        &client,
+        "/me/drive/items/b!CbtYWrofwUGBJWnaJkNwoNrBLp_kC3RKklSXPwrdeP3yH8_qmH9xT5Y6RODPNfYI:/notes.txt:",
+    );
+}
+
+#[test]
+pub fn drive_search() {
+    let client = get_drive();
+    let _ = client.v1().me().drive().search("annual report");
+    assert_url_eq(&client, "/me/drive/root/search(q='annual%20report')");
+
+    let _ = client.v1().drives(RID).drive().search("it's");
+    assert_url_eq(
+        &client,
+        "/drives/T5Y6RODPNfYICbtYWrofwUGBJWnaJkNwH9x/root/search(q='it''s')",
+    );
+
+    let _ = client
+        .v1()
+        .sites(RID)
+        .drive()
+        .search_folder(":/Documents:", "budget");
+    assert_url_eq(
+        &client,
+        "/sites/T5Y6RODPNfYICbtYWrofwUGBJWnaJkNwH9x/drive/root:/Documents:/search(q='budget')",
+    );
+
+    let _ = client.v1().me().drive().search_folder(ID, "budget");
+    assert_url_eq(
+        &client,
+        "/me/drive/items/b!CbtYWrofwUGBJWnaJkNwoNrBLp_kC3RKklSXPwrdeP3yH8_qmH9xT5Y6RODPNfYI/search(q='budget')",
+    );
+}
@@ -0,0 +1,149 @@
+use graph_oauth::oauth::{DeviceCode, GrantRequest, GrantType, OAuth};
+use std::convert::TryFrom;
+use test_tools::support::server::LocalServer;
+
+fn oauth(base: &str) -> OAuth {
+    let mut oauth = OAuth::new();
+    oauth
+        .client_id("6731de76-14a6-49ae-97bc-6eba6914391e")
+        .device_code_url(&format!("{}/common/oauth2/v2.0/devicecode", base))
+        .access_token_url(&format!("{}/common/oauth2/v2.0/token", base))
+        .add_scope("Files.Read")
+        .add_scope("offline_access");
+    oauth
+}
+
+fn device_code() -> String {
+    LocalServer::json(
+        "200 OK",
+        r#"{
+            "device_code": "GMMhmHCXhWEzkobqIHGG_EnNYYsAkukHspeYUk9E8",
+            "user_code": "F4KJWXQ8B",
+            "verification_uri": "https://microsoft.com/devicelogin",
+            "expires_in": 900,
+            "interval": 0,
+            "message": "To sign in, use a web browser to open the page https://microsoft.com/devicelogin and enter the code F4KJWXQ8B to authenticate."
+        }"#,
+    )
+}
+
+fn poll_error(error: &str) -> String {
+    LocalServer::json(
+        "400 Bad Request",
+        &format!(
+            "{{\"error\":\"{}\",\"error_description\":\"{}\"}}",
+            error, error
+        ),
+    )
+}
+
+fn access_token() -> String {
+    LocalServer::json(
+        "200 OK",
+        r#"{
+            "token_type": "Bearer",
+            "scope": "Files.Read offline_access",
+            "expires_in": 3600,
+            "access_token": "ASODFIUJ34KJ;LADSK",
+            "refresh_token": "AQABAAAAAAAGV_bv21oQQ4ROqh0_1-tAPrlbf_TrEVJRMW2Cr7cJvYKDh2XsByis2eCF9iBHNqJJVzYR_boX8VfBAZ"
+        }"#,
+    )
+}
+
+#[test]
+fn device_code_access_token_body() {
+    let mut oauth = OAuth::new();
+    oauth
+        .client_id("6731de76-14a6-49ae-97bc-6eba6914391e")
+        .device_code("GMMhmHCXhWEzkobqIHGG_EnNYYsAkukHspeYUk9E8")
+        .add_scope("Files.Read");
+
+    let body = oauth
+        .encode_uri(GrantType::DeviceCode, GrantRequest::AccessToken)
+        .unwrap();
+    assert_eq!(body, "client_id=6731de76-14a6-49ae-97bc-6eba6914391e&grant_type=urn%3Aietf%3Aparams%3Aoauth%3Agrant-type%3Adevice_code&device_code=GMMhmHCXhWEzkobqIHGG_EnNYYsAkukHspeYUk9E8");
+}
+
+#[test]
+fn device_code_from_response() {
+    let device_code = DeviceCode::try_from(
+        r#"{
+            "device_code": "GMMhmHCXhWEzkobqIHGG_EnNYYsAkukHspeYUk9E8",
+            "user_code": "F4KJWXQ8B",
+            "verification_url": "https://microsoft.com/devicelogin",
+            "expires_in": 900
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(device_code.user_code(), "F4KJWXQ8B");
+    assert_eq!(
+        device_code.verification_uri(),
+        "https://microsoft.com/devicelogin"
+    );
+    assert_eq!(device_code.interval(), 5);
+    assert_eq!(device_code.message(), None);
+}
+
+#[test]
+fn device_code_poll() {
+    let (base, requests) = LocalServer::serve(vec![
+        device_code(),
+        poll_error("authorization_pending"),
+        poll_error("authorization_pending"),
+        access_token(),
+    ]);
+    let mut oauth = oauth(&base);
+    let mut request = oauth.build().device_code();
+
+    let device_code = request.device_authorization().unwrap();
+    assert_eq!(device_code.user_code(), "F4KJWXQ8B");
+    assert_eq!(
+        device_code.verification_uri(),
+        "https://microsoft.com/devicelogin"
+    );
+    let authorization = requests.recv().unwrap();
+    assert!(authorization.starts_with("POST /common/oauth2/v2.0/devicecode"));
+
+    let access_token = request.poll(&device_code).unwrap();
+    assert_eq!(access_token.bearer_token(), "ASODFIUJ34KJ;LADSK");
+    assert!(access_token.refresh_token().is_some());
+
+    for _ in 0..3 {
+        let poll = requests.recv().unwrap();
+        assert!(poll.starts_with("POST /common/oauth2/v2.0/token"));
+    }
+}
+
+#[test]
+fn device_code_poll_declined() {
+    let (base, _requests) = LocalServer::serve(vec![
+        device_code(),
+        poll_error("authorization_pending"),
+        poll_error("authorization_declined"),
+    ]);
+    let mut oauth = oauth(&base);
+    let mut request = oauth.build().device_code();
+
+    let device_code = request.device_authorization().unwrap();
+    let result = request.poll(&device_code);
+    assert!(result.is_err());
+    assert!(format!("{:?}", result.unwrap_err()).contains("authorization_declined"));
+}
+
+#[tokio::test]
+async fn async_device_code_poll() {
+    let (base, _requests) = LocalServer::serve(vec![
+        device_code(),
+        poll_error("authorization_pending"),
+        access_token(),
+    ]);
+    let mut oauth = oauth(&base);
+    let mut request = oauth.build_async().device_code();
+
+    let device_code = request.device_authorization().await.unwrap();
+    assert_eq!(device_code.user_code(), "F4KJWXQ8B");
+
+    let access_token = request.poll(&device_code).await.unwrap();
+    assert_eq!(access_token.bearer_token(), "ASODFIUJ34KJ;LADSK");
+    assert!(access_token.refresh_token().is_some());
+}
@@ -0,0 +1,49 @@
+use graph_rs::http::QuickXorHash;
+
+#[test]
+fn quick_xor_hash_empty() {
+    let mut hash = QuickXorHash::new();
+    hash.update(&[]);
+    assert_eq!(hash.finalize(), vec![0; 20]);
+    assert_eq!(hash.base64(), "AAAAAAAAAAAAAAAAAAAAAAAAAAA=");
+}
+
+#[test]
+fn quick_xor_hash_update_in_parts() {
+    let content: Vec<u8> = (0..10_000u32).map(|i| (i * 31 % 251) as u8).collect();
+
+    let mut hash = QuickXorHash::new();
+    hash.update(&content);
+
+    for size in [1, 7, 160, 333, 4096].iter() {
+        let mut parts = QuickXorHash::new();
+        for chunk in content.chunks(*size) {
+            parts.update(chunk);
+        }
+        assert_eq!(hash.base64(), parts.base64());
+    }
+}
+
+#[test]
+fn quick_xor_hash_includes_length() {
+    let mut one = QuickXorHash::new();
+    one.update(&[0]);
+    let mut two = QuickXorHash::new();
+    two.update(&[0, 0]);
+    assert_ne!(one.base64(), two.base64());
+}
+
+// The expected hashes were computed with the reference algorithm where
+// byte i is xored into a 160 bit circular buffer at bit (i * 11) % 160
+// and the length is xored into the last 8 bytes.
+#[test]
+fn quick_xor_hash_known_values() {
+    let mut hash = QuickXorHash::new();
+    hash.update(b"hello world");
+    assert_eq!(hash.base64(), "aCgDG9jwBhDc4Q1yawMZAAAAAAA=");
+
+    let content: Vec<u8> = (0..1000u32).map(|i| (i % 251) as u8).collect();
+    let mut hash = QuickXorHash::new();
+    hash.update(&content);
+    assert_eq!(hash.base64(), "KbphcpColXb1/3Wm950vUzeX1es=");
+}
@@ -0,0 +1,190 @@
+use graph_oauth::oauth::{LoopbackListener, OAuth, OAuthCredential};
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::thread;
+use std::time::Duration;
+use test_tools::support::server::LocalServer;
+
+fn oauth(base: &str) -> OAuth {
+    let mut oauth = OAuth::new();
+    oauth
+        .client_id("6731de76-14a6-49ae-97bc-6eba6914391e")
+        .authorize_url("https://login.microsoftonline.com/common/oauth2/v2.0/authorize")
+        .access_token_url(&format!("{}/common/oauth2/v2.0/token", base))
+        .add_scope("Files.Read")
+        .add_scope("offline_access")
+        .state("12345");
+    oauth
+}
+
+fn access_token() -> String {
+    LocalServer::json(
+        "200 OK",
+        r#"{
+            "token_type": "Bearer",
+            "scope": "Files.Read offline_access",
+            "expires_in": 3600,
+            "access_token": "ASODFIUJ34KJ;LADSK",
+            "refresh_token": "AQABAAAAAAAGV_bv21oQQ4ROqh0_1-tAPrlbf_TrEVJRMW2Cr7cJvYKDh2XsByis2eCF9iBHNqJJVzYR_boX8VfBAZ"
+        }"#,
+    )
+}
+
+// Send a request to the loopback listener the same way the browser
+// does when redirected and return the response.
+fn redirect(port: u16, path: &str) -> String {
+    let mut stream = TcpStream::connect(("127.0.0.1", port)).unwrap();
+    write!(
+        stream,
+        "GET {} HTTP/1.1\r\nHost: localhost:{}\r\n\r\n",
+        path, port
+    )
+    .unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    response
+}
+
+#[test]
+fn loopback_listener_accept() {
+    let listener = LoopbackListener::bind(0).unwrap();
+    let port = listener.port();
+    assert_eq!(
+        listener.redirect_uri(),
+        format!("http://127.0.0.1:{}", port)
+    );
+
+    let handle = thread::spawn(move || {
+        let favicon = redirect(port, "/favicon.ico");
+        assert!(favicon.starts_with("HTTP/1.1 404"));
+        redirect(
+            port,
+            "/?code=M0ab92efe-b6fd-df08-87dc-2c6500a7f84d&state=12345",
+        )
+    });
+
+    let code = listener.accept("12345").unwrap();
+    assert_eq!(code, "M0ab92efe-b6fd-df08-87dc-2c6500a7f84d");
+    assert!(handle.join().unwrap().starts_with("HTTP/1.1 200"));
+}
+
+#[test]
+fn loopback_listener_errors() {
+    let listener = LoopbackListener::bind(0).unwrap();
+    let port = listener.port();
+    let handle = thread::spawn(move || {
+        // A redirect with a different state is rejected and does
+        // not end the authorization.
+        let invalid_state = redirect(
+            port,
+            "/?error=access_denied&error_description=the+user+canceled&state=67890",
+        );
+        assert!(invalid_state.starts_with("HTTP/1.1 400"));
+        let invalid_state = redirect(port, "/?code=M0ab92efe&state=67890");
+        assert!(invalid_state.starts_with("HTTP/1.1 400"));
+        redirect(port, "/?code=M0ab92efe&state=12345")
+    });
+    assert_eq!(listener.accept("12345").unwrap(), "M0ab92efe");
+    assert!(handle.join().unwrap().starts_with("HTTP/1.1 200"));
+
+    let mut listener = LoopbackListener::bind(0).unwrap();
+    listener.timeout(Duration::from_millis(500));
+    let port = listener.port();
+    thread::spawn(move || redirect(port, "/?code=M0ab92efe&state=67890"));
+    assert!(listener.accept("12345").is_err());
+
+    let listener = LoopbackListener::bind(0).unwrap();
+    let port = listener.port();
+    thread::spawn(move || {
+        redirect(
+            port,
+            "/?error=access_denied&error_description=the+user+canceled&state=12345",
+        )
+    });
+    let result = listener.accept("12345");
+    assert!(format!("{:?}", result.unwrap_err()).contains("access_denied: the user canceled"));
+
+    let mut listener = LoopbackListener::bind(0).unwrap();
+    listener.timeout(Duration::from_millis(100));
+    assert!(listener.accept("12345").is_err());
+}
+
+#[test]
+fn loopback_listener_connection_errors() {
+    let listener = LoopbackListener::bind(0).unwrap();
+    let port = listener.port();
+    let handle = thread::spawn(move || {
+        // A connection that is closed without sending a request, such
+        // as a browser preconnect, is dropped and the listener keeps
+        // waiting for the redirect.
+        drop(TcpStream::connect(("127.0.0.1", port)).unwrap());
+        redirect(port, "/?code=M0ab92efe&state=12345")
+    });
+    assert_eq!(listener.accept("12345").unwrap(), "M0ab92efe");
+    assert!(handle.join().unwrap().starts_with("HTTP/1.1 200"));
+}
+
+#[test]
+fn loopback_authorization() {
+    let (base, requests) = LocalServer::serve(vec![access_token()]);
+    let mut listener = LoopbackListener::bind(0).unwrap();
+    listener.open_browser(false);
+    let port = listener.port();
+    let redirect_uri = listener.redirect_uri();
+
+    thread::spawn(move || {
+        redirect(
+            port,
+            "/?code=M0ab92efe-b6fd-df08-87dc-2c6500a7f84d&state=12345",
+        )
+    });
+
+    let mut oauth = oauth(&base);
+    let mut request = oauth.build().authorization_code_grant();
+    let access_token = request.loopback_authorization(listener).unwrap();
+    assert_eq!(access_token.bearer_token(), "ASODFIUJ34KJ;LADSK");
+
+    let token_request = requests.recv().unwrap();
+    assert!(token_request.starts_with("POST /common/oauth2/v2.0/token"));
+
+    let oauth: &OAuth = request.as_ref();
+    assert_eq!(oauth.get(OAuthCredential::RedirectURI), Some(redirect_uri));
+    assert_eq!(
+        oauth.get(OAuthCredential::AccessCode),
+        Some("M0ab92efe-b6fd-df08-87dc-2c6500a7f84d".to_string())
+    );
+    assert!(oauth.contains(OAuthCredential::CodeVerifier));
+    assert_eq!(
+        oauth.get(OAuthCredential::CodeChallengeMethod),
+        Some("S256".to_string())
+    );
+    assert!(oauth.get_access_token().is_some());
+}
+
+#[test]
+fn loopback_authorization_grant() {
+    let listener = LoopbackListener::bind(0).unwrap();
+    let mut oauth = oauth("http://localhost:8000");
+    let mut request = oauth.build().client_credentials();
+    assert!(request.loopback_authorization(listener).is_err());
+}
+
+#[tokio::test]
+async fn async_loopback_authorization() {
+    let (base, _requests) = LocalServer::serve(vec![access_token()]);
+    let mut listener = LoopbackListener::bind(0).unwrap();
+    listener.open_browser(false);
+    let port = listener.port();
+
+    thread::spawn(move || {
+        redirect(
+            port,
+            "/?code=M0ab92efe-b6fd-df08-87dc-2c6500a7f84d&state=12345",
+        )
+    });
+
+    let mut oauth = oauth(&base);
+    let mut request = oauth.build_async().authorization_code_grant();
+    let access_token = request.loopback_authorization(listener).await.unwrap();
+    assert_eq!(access_token.bearer_token(), "ASODFIUJ34KJ;LADSK");
+}
@@ -21,7 +21,7 @@ fn list_and_get_messages() {
         {
             let value = res.body().value().unwrap();
             let value = value[0].clone();
-            let message_id = value["id"].as_str().unwrap();
+            let message_id = value.id().as_deref().unwrap();
 
             let get_req = client
                 .v1()
@@ -34,7 +34,7 @@ fn list_and_get_messages() {
             if let Ok(response) = get_req {
                 println!("{:#?}", response);
                 let value = response.body().clone();
-                let m_id = value["id"].as_str().unwrap();
+                let m_id = value.id().as_deref().unwrap();
                 assert_eq!(m_id, message_id);
             } else if let Err(_) = get_req {
                 panic!("Request error. Method: mail messages get");
@@ -76,7 +76,7 @@ fn mail_create_and_delete_message() {
             .send();
 
         if let Ok(message) = result {
-            let message_id = message.body()["id"].as_str().unwrap();
+            let message_id = message.body().id().as_deref().unwrap();
 
             thread::sleep(Duration::from_secs(2));
             let delete_res = client
@@ -45,6 +45,28 @@ pub fn list_mail_folder_messages() {
     assert_url_eq(&client, "/sites/32p99453/mailFolders/1234/messages");
 }
 
+#[test]
+pub fn mail_folder_messages_delta() {
+    let client = Graph::new("");
+    let _ = client
+        .v1()
+        .me()
+        .mail()
+        .mail_folder()
+        .messages()
+        .delta("32p99453");
+    assert_url_eq(&client, "/me/mailFolders/32p99453/messages/delta");
+
+    let _ = client
+        .v1()
+        .users("32p99453")
+        .mail()
+        .mail_folder()
+        .messages()
+        .delta("1234");
+    assert_url_eq(&client, "/users/32p99453/mailFolders/1234/messages/delta");
+}
+
 #[test]
 pub fn get_messages() {
     let client = Graph::new("");
@@ -753,3 +775,25 @@ fn mail_focused_inbox() {
         "/sites/32p99453/inferenceClassification/overrides/1234",
     );
 }
+
+#[test]
+pub fn mail_folder_well_known_messages() {
+    let client = Graph::new("");
+    let _ = client
+        .v1()
+        .me()
+        .mail()
+        .mail_folder()
+        .messages()
+        .outbox("1234");
+    assert_url_eq(&client, "/me/mailFolders/outbox/messages/1234");
+
+    let _ = client
+        .v1()
+        .users("32p99453")
+        .mail()
+        .mail_folder()
+        .messages()
+        .inbox("1234");
+    assert_url_eq(&client, "/users/32p99453/mailFolders/inbox/messages/1234");
+}
@@ -0,0 +1,198 @@
+use graph_rs::error::GraphResult;
+use graph_rs::header::HeaderValue;
+use graph_rs::http::{
+    AsyncHttpClient, BlockingHttpClient, Middleware, MiddlewareRequest, MiddlewareResponse,
+    Pipeline, RequestClient,
+};
+use graph_rs::url::GraphUrl;
+use reqwest::Method;
+use std::sync::{Arc, Mutex};
+use test_tools::support::server::LocalServer;
+
+struct TelemetryHeader;
+
+impl Middleware for TelemetryHeader {
+    fn before_send(
+        &self,
+        request: &mut MiddlewareRequest,
+    ) -> GraphResult<Option<MiddlewareResponse>> {
+        request
+            .headers
+            .insert("x-telemetry", HeaderValue::from_static("graph-rs"));
+        Ok(None)
+    }
+}
+
+struct ShortCircuit;
+
+impl Middleware for ShortCircuit {
+    fn before_send(
+        &self,
+        _request: &mut MiddlewareRequest,
+    ) -> GraphResult<Option<MiddlewareResponse>> {
+        Ok(Some(MiddlewareResponse::json(
+            200,
+            &serde_json::json!({ "value": "cached" }),
+        )?))
+    }
+}
+
+struct Recorder {
+    name: &'static str,
+    calls: Arc<Mutex<Vec<String>>>,
+}
+
+impl Middleware for Recorder {
+    fn before_send(
+        &self,
+        _request: &mut MiddlewareRequest,
+    ) -> GraphResult<Option<MiddlewareResponse>> {
+        self.calls
+            .lock()
+            .unwrap()
+            .push(format!("before {}", self.name));
+        Ok(None)
+    }
+
+    fn after_receive(&self, response: &mut MiddlewareResponse) -> GraphResult<()> {
+        self.calls
+            .lock()
+            .unwrap()
+            .push(format!("after {} {}", self.name, response.status));
+        Ok(())
+    }
+}
+
+struct RewriteBody;
+
+impl Middleware for RewriteBody {
+    fn before_send(
+        &self,
+        request: &mut MiddlewareRequest,
+    ) -> GraphResult<Option<MiddlewareResponse>> {
+        assert_eq!(request.body(), Some(&b"{\"name\":\"before\"}"[..]));
+        request.set_body(b"{\"name\":\"after middleware\"}".to_vec());
+        Ok(None)
+    }
+}
+
+struct RewriteStatus;
+
+impl Middleware for RewriteStatus {
+    fn after_receive(&self, response: &mut MiddlewareResponse) -> GraphResult<()> {
+        response.status = 200;
+        Ok(())
+    }
+}
+
+fn url(base: &str) -> GraphUrl {
+    GraphUrl::parse(&format!("{}/v1.0/me", base)).unwrap()
+}
+
+#[test]
+fn middleware_adds_header() {
+    let (base, requests) = LocalServer::serve(vec![LocalServer::json("200 OK", "{}")]);
+    let client = BlockingHttpClient::new(url(&base));
+    let mut pipeline = Pipeline::new();
+    pipeline.push(TelemetryHeader);
+    client.set_pipeline(pipeline);
+
+    let response = client.execute::<serde_json::Value>().unwrap();
+    assert_eq!(response.status(), 200);
+    let request = requests.recv().unwrap().to_lowercase();
+    assert!(request.contains("x-telemetry: graph-rs"));
+}
+
+#[test]
+fn middleware_order() {
+    let (base, _) = LocalServer::serve(vec![LocalServer::json("200 OK", "{}")]);
+    let client = BlockingHttpClient::new(url(&base));
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let mut pipeline = Pipeline::new();
+    pipeline.push(Recorder {
+        name: "one",
+        calls: calls.clone(),
+    });
+    pipeline.push(Recorder {
+        name: "two",
+        calls: calls.clone(),
+    });
+    client.set_pipeline(pipeline);
+
+    client.execute::<serde_json::Value>().unwrap();
+    assert_eq!(
+        calls.lock().unwrap().as_slice(),
+        &["before one", "before two", "after two 200", "after one 200"]
+    );
+}
+
+#[test]
+fn middleware_short_circuit() {
+    // Nothing is listening on this port. The request is never sent.
+    let client = BlockingHttpClient::new(url("http://127.0.0.1:9"));
+    let mut pipeline = Pipeline::new();
+    pipeline.push(ShortCircuit);
+    client.set_pipeline(pipeline);
+
+    let response = client.execute::<serde_json::Value>().unwrap();
+    assert_eq!(response.body()["value"].as_str(), Some("cached"));
+}
+
+#[tokio::test]
+async fn async_middleware_short_circuit() {
+    let client = AsyncHttpClient::new(url("http://127.0.0.1:9"));
+    let mut pipeline = Pipeline::new();
+    pipeline.push(ShortCircuit);
+    client.set_pipeline(pipeline);
+
+    let response = client.execute::<serde_json::Value>().await.unwrap();
+    assert_eq!(response.body()["value"].as_str(), Some("cached"));
+}
+
+#[test]
+fn middleware_rewrites_body() {
+    let (base, requests) = LocalServer::serve(vec![LocalServer::json("200 OK", "{}")]);
+    let client = BlockingHttpClient::new(url(&base));
+    client.set_method(Method::POST);
+    client.set_body("{\"name\":\"before\"}");
+    let mut pipeline = Pipeline::new();
+    pipeline.push(RewriteBody);
+    client.set_pipeline(pipeline);
+
+    client.execute::<serde_json::Value>().unwrap();
+    let request = requests.recv().unwrap();
+    assert!(request.to_lowercase().contains("content-length: 27"));
+    assert!(request.ends_with("{\"name\":\"after middleware\"}"));
+}
+
+#[test]
+fn middleware_status_keeps_body() {
+    let (base, _) = LocalServer::serve(vec![LocalServer::json(
+        "202 Accepted",
+        "{\"value\":\"accepted\"}",
+    )]);
+    let client = BlockingHttpClient::new(url(&base));
+    let mut pipeline = Pipeline::new();
+    pipeline.push(RewriteStatus);
+    client.set_pipeline(pipeline);
+
+    let response = client.execute::<serde_json::Value>().unwrap();
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.body()["value"].as_str(), Some("accepted"));
+}
+
+#[tokio::test]
+async fn async_middleware_status_keeps_body() {
+    let (base, _) = LocalServer::serve(vec![LocalServer::json(
+        "202 Accepted",
+        "{\"value\":\"accepted\"}",
+    )]);
+    let client = AsyncHttpClient::new(url(&base));
+    let mut pipeline = Pipeline::new();
+    pipeline.push(RewriteStatus);
+    client.set_pipeline(pipeline);
+
+    let response = client.execute::<serde_json::Value>().await.unwrap();
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.body()["value"].as_str(), Some("accepted"));
+}
@@ -0,0 +1,295 @@
+use graph_rs::http::IntoResponse;
+use graph_rs::models::{Attachment, DirectoryObject, DriveItem, Event, Message, PlannerTask};
+use graph_rs::prelude::*;
+
+#[test]
+fn drive_item() {
+    let drive_item: DriveItem = serde_json::from_value(serde_json::json!({
+        "@odata.context": "https://graph.microsoft.com/v1.0/$metadata#users('48d31887-5fad-4d73-a9f5-3c356e68a038')/drive/items/$entity",
+        "@microsoft.graph.downloadUrl": "https://contoso.sharepoint.com/download.aspx?UniqueId=4a2f6a94",
+        "id": "01BYE5RZZ6FUE5272C5JCY3L7CLZ7XOUYM",
+        "name": "CR-227 Project",
+        "size": 1015204,
+        "eTag": "\"{4A2F6A94-47AA-4E9B-A2E6-0F4E4D18F0F4},6\"",
+        "parentReference": {
+            "driveId": "b!-RIj2DuyvEyV1T4NlOaMHk8XkS_I8MdFlUCq1BlcjgmhRfAj3-Z8RY2VpuvV_tpd",
+            "driveType": "business",
+            "path": "/drive/root:"
+        },
+        "file": {
+            "mimeType": "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+            "hashes": {
+                "quickXorHash": "6YPvw2bSyZcvKE9Lmn58E9IM24E="
+            }
+        },
+        "shared": {
+            "scope": "users"
+        }
+    }))
+    .unwrap();
+
+    assert_eq!(
+        drive_item.id().as_deref(),
+        Some("01BYE5RZZ6FUE5272C5JCY3L7CLZ7XOUYM")
+    );
+    assert_eq!(drive_item.size(), &Some(1015204));
+    assert!(drive_item.is_file());
+    assert!(!drive_item.is_folder());
+    assert_eq!(
+        drive_item.download_url().as_deref(),
+        Some("https://contoso.sharepoint.com/download.aspx?UniqueId=4a2f6a94")
+    );
+    assert_eq!(
+        drive_item
+            .parent_reference()
+            .as_ref()
+            .and_then(|parent| parent.path().as_deref()),
+        Some("/drive/root:")
+    );
+    let hashes = drive_item
+        .file()
+        .as_ref()
+        .unwrap()
+        .hashes()
+        .as_ref()
+        .unwrap();
+    assert_eq!(
+        hashes.quick_xor_hash().as_deref(),
+        Some("6YPvw2bSyZcvKE9Lmn58E9IM24E=")
+    );
+
+    // Properties that are not part of the model are kept.
+    assert_eq!(
+        drive_item.additional_data()["shared"],
+        serde_json::json!({ "scope": "users" })
+    );
+    let value = serde_json::to_value(&drive_item).unwrap();
+    assert_eq!(value["shared"]["scope"], "users");
+    assert_eq!(value["parentReference"]["driveType"], "business");
+    assert!(value.get("folder").is_none());
+}
+
+#[test]
+fn collection_of_messages() {
+    let collection: Collection<Message> = serde_json::from_value(serde_json::json!({
+        "@odata.nextLink": "https://graph.microsoft.com/v1.0/me/messages?$skip=10",
+        "value": [
+            {
+                "id": "AAMkAGUAAAwTW09AAA=",
+                "subject": "You have late tasks!",
+                "isRead": false,
+                "body": {
+                    "contentType": "html",
+                    "content": "<html><body></body></html>"
+                },
+                "from": {
+                    "emailAddress": {
+                        "name": "Microsoft Planner",
+                        "address": "noreply@planner.office365.com"
+                    }
+                },
+                "toRecipients": [
+                    {
+                        "emailAddress": {
+                            "name": "Samantha Booth",
+                            "address": "samanthab@contoso.onmicrosoft.com"
+                        }
+                    }
+                ]
+            }
+        ]
+    }))
+    .unwrap();
+
+    assert_eq!(
+        collection.odata_next_link().map(|s| s.as_str()),
+        Some("https://graph.microsoft.com/v1.0/me/messages?$skip=10")
+    );
+    let message = collection.index(0).unwrap();
+    assert_eq!(message.subject().as_deref(), Some("You have late tasks!"));
+    assert_eq!(message.is_read(), &Some(false));
+    assert_eq!(
+        message
+            .from()
+            .as_ref()
+            .and_then(|from| from.email_address().as_ref())
+            .and_then(|email| email.address().as_deref()),
+        Some("noreply@planner.office365.com")
+    );
+    assert_eq!(message.to_recipients().as_ref().unwrap().len(), 1);
+}
+
+#[test]
+fn event_type() {
+    let event: Event = serde_json::from_value(serde_json::json!({
+        "id": "AAMkAGIAAAoZDOFAAA=",
+        "subject": "Orientation",
+        "type": "singleInstance",
+        "start": {
+            "dateTime": "2017-04-21T10:00:00.0000000",
+            "timeZone": "Pacific Standard Time"
+        },
+        "attendees": [
+            {
+                "type": "required",
+                "status": {
+                    "response": "none",
+                    "time": "0001-01-01T00:00:00Z"
+                },
+                "emailAddress": {
+                    "name": "Samantha Booth",
+                    "address": "samanthab@contoso.onmicrosoft.com"
+                }
+            }
+        ]
+    }))
+    .unwrap();
+
+    assert_eq!(event.event_type().as_deref(), Some("singleInstance"));
+    assert_eq!(
+        event
+            .start()
+            .as_ref()
+            .and_then(|start| start.time_zone().as_deref()),
+        Some("Pacific Standard Time")
+    );
+    let attendee = &event.attendees().as_ref().unwrap()[0];
+    assert_eq!(attendee.attendee_type().as_deref(), Some("required"));
+    assert!(event.additional_data().is_empty());
+}
+
+#[test]
+fn directory_objects() {
+    let collection: Collection<DirectoryObject> = serde_json::from_value(serde_json::json!({
+        "value": [
+            {
+                "@odata.type": "#microsoft.graph.user",
+                "id": "87d349ed-44d7-43e1-9a83-5f2406dee5bd",
+                "displayName": "Adele Vance",
+                "userPrincipalName": "AdeleV@contoso.onmicrosoft.com"
+            },
+            {
+                "@odata.type": "#microsoft.graph.group",
+                "id": "02bd9fd6-8f93-4758-87c3-1fb73740a315",
+                "displayName": "HR Taskforce",
+                "securityEnabled": false
+            },
+            {
+                "@odata.type": "#microsoft.graph.device",
+                "id": "6a59ea83-02bd-468f-a40b-f2c3d1821983"
+            }
+        ]
+    }))
+    .unwrap();
+
+    match collection.index(0).unwrap() {
+        DirectoryObject::User(user) => {
+            assert_eq!(
+                user.user_principal_name().as_deref(),
+                Some("AdeleV@contoso.onmicrosoft.com")
+            );
+        },
+        other => panic!("Expected a user. Found: {:#?}", other),
+    }
+    match collection.index(1).unwrap() {
+        DirectoryObject::Group(group) => {
+            assert_eq!(group.display_name().as_deref(), Some("HR Taskforce"));
+            assert_eq!(group.security_enabled(), &Some(false));
+        },
+        other => panic!("Expected a group. Found: {:#?}", other),
+    }
+    let device = collection.index(2).unwrap();
+    assert!(matches!(device, DirectoryObject::Other(_)));
+    assert_eq!(device.odata_type(), Some("#microsoft.graph.device"));
+    assert_eq!(device.id(), Some("6a59ea83-02bd-468f-a40b-f2c3d1821983"));
+
+    // The @odata.type is kept when serializing.
+    let value = serde_json::to_value(collection.index(0).unwrap()).unwrap();
+    assert_eq!(value["@odata.type"], "#microsoft.graph.user");
+}
+
+#[test]
+fn attachments() {
+    let attachments: Vec<Attachment> = serde_json::from_value(serde_json::json!([
+        {
+            "@odata.type": "#microsoft.graph.fileAttachment",
+            "id": "AAMkADA1M-zAAA=",
+            "name": "menu.txt",
+            "contentType": "text/plain",
+            "size": 1053,
+            "isInline": false,
+            "contentBytes": "bWFjIGFuZCBjaGVlc2UgdG9kYXk="
+        },
+        {
+            "@odata.type": "#microsoft.graph.referenceAttachment",
+            "id": "AAMkADA1M-CJKtzmnlcqVgqI=",
+            "name": "Personal pictures",
+            "sourceUrl": "https://contoso.com/personal/pictures",
+            "isFolder": true
+        },
+        {
+            "id": "AAMkADA1M-zAAB=",
+            "name": "unknown"
+        }
+    ]))
+    .unwrap();
+
+    match &attachments[0] {
+        Attachment::File(file) => {
+            assert_eq!(
+                file.content_bytes().as_deref(),
+                Some("bWFjIGFuZCBjaGVlc2UgdG9kYXk=")
+            );
+            assert_eq!(file.size(), &Some(1053));
+        },
+        other => panic!("Expected a file attachment. Found: {:#?}", other),
+    }
+    match &attachments[1] {
+        Attachment::Reference(reference) => {
+            assert_eq!(reference.is_folder(), &Some(true));
+        },
+        other => panic!("Expected a reference attachment. Found: {:#?}", other),
+    }
+    assert_eq!(attachments[1].name(), Some("Personal pictures"));
+    assert!(matches!(attachments[2], Attachment::Other(_)));
+    assert_eq!(attachments[2].odata_type(), None);
+    assert_eq!(attachments[2].id(), Some("AAMkADA1M-zAAB="));
+}
+
+#[test]
+fn planner_task_assignments() {
+    let task: PlannerTask = serde_json::from_value(serde_json::json!({
+        "@odata.etag": "W/\"JzEtVGFzayAgQEBAQEBAQEBAQEBAQEBAWCc=\"",
+        "id": "01gzSlKkIUSUl6DF_EilrmQAKDhh",
+        "title": "title-value",
+        "percentComplete": 50,
+        "assignments": {
+            "fbab97d0-4932-4511-b675-204639209557": {
+                "@odata.type": "#microsoft.graph.plannerAssignment",
+                "assignedDateTime": "2015-03-25T18:36:49.2407981Z",
+                "orderHint": "RWk1"
+            }
+        }
+    }))
+    .unwrap();
+
+    assert_eq!(task.percent_complete(), &Some(50));
+    let assignment = &task.assignments().as_ref().unwrap()["fbab97d0-4932-4511-b675-204639209557"];
+    assert_eq!(assignment.order_hint().as_deref(), Some("RWk1"));
+    assert_eq!(
+        task.additional_data()["@odata.etag"],
+        "W/\"JzEtVGFzayAgQEBAQEBAQEBAQEBAQEBAWCc=\""
+    );
+}
+
+#[test]
+fn typed_request_builders() {
+    // The request builders default to the models and the json method can
+    // still be used to get the response as a serde_json::Value.
+    let client = Graph::new("ACCESS_TOKEN");
+    let _: IntoResponse<DriveItem, _> = client.v1().me().drive().get_item("ITEM_ID");
+    let _: IntoResponse<Collection<Message>, _> =
+        client.v1().users("USER_ID").mail().messages().list();
+    let _: IntoResponse<Collection<DirectoryObject>, _> =
+        client.v1().groups("GROUP_ID").list_members();
+}
@@ -0,0 +1,125 @@
+use graph_rs::client::Graph;
+use graph_rs::http::BlockingHttpClient;
+use graph_rs::oauth::{AccessToken, Authority, AzureCloud, OAuth, OAuthCredential};
+use std::convert::TryFrom;
+
+#[test]
+fn authority_urls() {
+    let authority = Authority::Tenant("9188040d-6c67-4c5b-b112-36a304b66dad".into());
+    assert_eq!(
+        authority.authorize_url(AzureCloud::UsGovernment),
+        "https://login.microsoftonline.us/9188040d-6c67-4c5b-b112-36a304b66dad/oauth2/v2.0/authorize"
+    );
+    assert_eq!(
+        authority.token_url(AzureCloud::China),
+        "https://login.chinacloudapi.cn/9188040d-6c67-4c5b-b112-36a304b66dad/oauth2/v2.0/token"
+    );
+    assert_eq!(
+        Authority::Organizations.logout_url(AzureCloud::Germany),
+        "https://login.microsoftonline.de/organizations/oauth2/v2.0/logout"
+    );
+    assert_eq!(
+        Authority::Consumers.device_code_url(AzureCloud::Global),
+        "https://login.microsoftonline.com/consumers/oauth2/v2.0/devicecode"
+    );
+
+    let authority = Authority::B2C {
+        tenant: "contoso".into(),
+        policy: "B2C_1_signupsignin".into(),
+    };
+    assert_eq!(
+        authority.token_url(AzureCloud::Global),
+        "https://contoso.b2clogin.com/contoso.onmicrosoft.com/B2C_1_signupsignin/oauth2/v2.0/token"
+    );
+    assert_eq!(
+        authority.open_id_configuration_url(AzureCloud::China),
+        "https://contoso.b2clogin.cn/contoso.onmicrosoft.com/B2C_1_signupsignin/v2.0/.well-known/openid-configuration"
+    );
+}
+
+#[test]
+fn oauth_authority() {
+    let mut oauth = OAuth::new();
+    oauth.authority(&Authority::Common, AzureCloud::UsGovernmentDoD);
+    assert_eq!(oauth.get_azure_cloud(), AzureCloud::UsGovernmentDoD);
+    assert_eq!(
+        oauth.get(OAuthCredential::AuthorizeURL),
+        Some("https://login.microsoftonline.us/common/oauth2/v2.0/authorize".to_string())
+    );
+    assert_eq!(
+        oauth.get(OAuthCredential::RefreshTokenURL),
+        Some("https://login.microsoftonline.us/common/oauth2/v2.0/token".to_string())
+    );
+    assert!(oauth.contains(OAuthCredential::DeviceCodeURL));
+
+    oauth.authority(
+        &Authority::B2C {
+            tenant: "contoso".into(),
+            policy: "B2C_1_signupsignin".into(),
+        },
+        AzureCloud::Global,
+    );
+    assert_eq!(oauth.get_azure_cloud(), AzureCloud::Global);
+    assert_eq!(
+        oauth.get(OAuthCredential::LogoutURL),
+        Some(
+            "https://contoso.b2clogin.com/contoso.onmicrosoft.com/B2C_1_signupsignin/oauth2/v2.0/logout"
+                .to_string()
+        )
+    );
+    assert!(!oauth.contains(OAuthCredential::DeviceCodeURL));
+}
+
+#[test]
+fn graph_azure_cloud() {
+    let client = Graph::new("ACCESS_TOKEN");
+    client.set_azure_cloud(AzureCloud::UsGovernment);
+    assert_eq!(client.azure_cloud(), AzureCloud::UsGovernment);
+    assert!(client.is_v1());
+
+    client.v1().me().drive();
+    client.url_ref(|url| {
+        assert_eq!(url.to_string(), "https://graph.microsoft.us/v1.0/me");
+    });
+
+    client.beta().me().drive();
+    client.url_ref(|url| {
+        assert_eq!(url.to_string(), "https://graph.microsoft.us/beta/me");
+    });
+
+    client.set_azure_cloud(AzureCloud::China);
+    assert!(client.is_beta());
+    client.beta().me().drive();
+    client.url_ref(|url| {
+        assert_eq!(
+            url.to_string(),
+            "https://microsoftgraph.chinacloudapi.cn/beta/me"
+        );
+    });
+
+    let client = Graph::new_async("ACCESS_TOKEN");
+    client.set_azure_cloud(AzureCloud::UsGovernmentDoD);
+    client.v1().me().drive();
+    client.url_ref(|url| {
+        assert_eq!(url.to_string(), "https://dod-graph.microsoft.us/v1.0/me");
+    });
+}
+
+#[test]
+fn graph_azure_cloud_from_oauth() {
+    let mut oauth = OAuth::new();
+    oauth.authority(&Authority::Organizations, AzureCloud::Germany);
+    oauth.access_token(AccessToken::new(
+        "Bearer",
+        3600,
+        "User.Read",
+        "ACCESS_TOKEN",
+    ));
+
+    let client: Graph<BlockingHttpClient> = Graph::try_from(&oauth).unwrap();
+    assert_eq!(client.azure_cloud(), AzureCloud::Germany);
+    client.v1().me().drive();
+    client.url_ref(|url| {
+        assert_eq!(url.to_string(), "https://graph.microsoft.de/v1.0/me");
+    });
+}
@@ -0,0 +1,122 @@
+use graph_rs::subscriptions::{
+    EncryptionCertificate, LifecycleEvent, Notification, NotificationReceiver,
+};
+use std::fs;
+
+fn encryption_certificate(private_key: &str) -> EncryptionCertificate {
+    let certificate = fs::read("./test_files/certificate/certificate.pem").unwrap();
+    let private_key = fs::read(private_key).unwrap();
+    EncryptionCertificate::from_pem("testCertificateId", &certificate, &private_key).unwrap()
+}
+
+#[test]
+fn validation_token() {
+    assert_eq!(
+        NotificationReceiver::validation_token(
+            "https://localhost:8000/notifications?validationToken=Validation%3a+Testing+client+application+reachability+for+subscription+Request-Id%3a+f0d4b4b2"
+        ),
+        Some(
+            "Validation: Testing client application reachability for subscription Request-Id: f0d4b4b2"
+                .to_string()
+        )
+    );
+    assert_eq!(
+        NotificationReceiver::validation_token("validationToken=token"),
+        Some("token".to_string())
+    );
+    assert_eq!(
+        NotificationReceiver::validation_token("https://localhost:8000/notifications"),
+        None
+    );
+}
+
+#[test]
+fn parse_notifications() {
+    let body = serde_json::json!({
+        "value": [
+            {
+                "subscriptionId": "10493aa0-4d69-4d4e-9f9d-5a6a0c5d5cb4",
+                "subscriptionExpirationDateTime": "2020-08-01T11:00:00+00:00",
+                "clientState": "secretClientState",
+                "changeType": "updated",
+                "resource": "Users/7bd1d6e5/Messages/AAMkAGE0",
+                "tenantId": "84bd8158-6d4d-4958-8b9f-9d6445542f95"
+            },
+            {
+                "subscriptionId": "10493aa0-4d69-4d4e-9f9d-5a6a0c5d5cb4",
+                "subscriptionExpirationDateTime": "2020-08-01T11:00:00+00:00",
+                "clientState": "secretClientState",
+                "lifecycleEvent": "reauthorizationRequired",
+                "resource": "Users/7bd1d6e5/Messages",
+                "tenantId": "84bd8158-6d4d-4958-8b9f-9d6445542f95"
+            }
+        ]
+    });
+    let body = serde_json::to_vec(&body).unwrap();
+
+    let mut receiver = NotificationReceiver::new();
+    receiver.client_state("secretClientState");
+    let notifications = receiver.parse(&body).unwrap();
+    assert_eq!(notifications.len(), 2);
+
+    match &notifications[0] {
+        Notification::Change(change) => {
+            assert_eq!(change.change_type().as_deref(), Some("updated"));
+            assert_eq!(
+                change.resource().as_deref(),
+                Some("Users/7bd1d6e5/Messages/AAMkAGE0")
+            );
+        },
+        _ => panic!("Expected a change notification"),
+    }
+    match &notifications[1] {
+        Notification::Lifecycle(event, _) => {
+            assert_eq!(event, &LifecycleEvent::ReauthorizationRequired)
+        },
+        _ => panic!("Expected a lifecycle notification"),
+    }
+
+    // Notifications with a different client state are not parsed.
+    receiver.client_state("otherClientState");
+    assert!(receiver.parse(&body).is_err());
+}
+
+#[test]
+fn decrypt_notification() {
+    let body = fs::read("./test_files/notifications/encrypted_notification.json").unwrap();
+    for private_key in [
+        "./test_files/certificate/private_key.pem",
+        "./test_files/certificate/rsa_private_key.pem",
+    ]
+    .iter()
+    {
+        let mut receiver = NotificationReceiver::new();
+        receiver
+            .client_state("secretClientState")
+            .encryption_certificate(encryption_certificate(private_key));
+
+        let notifications = receiver.parse(&body).unwrap();
+        let resource = receiver
+            .decrypt(notifications[0].change_notification())
+            .unwrap()
+            .unwrap();
+        assert_eq!(resource["id"].as_str(), Some("AAMkAGE0"));
+        assert_eq!(resource["subject"].as_str(), Some("Lunch"));
+    }
+}
+
+#[test]
+fn decrypt_invalid_signature() {
+    let body = fs::read("./test_files/notifications/encrypted_notification.json").unwrap();
+    let mut receiver = NotificationReceiver::new();
+    receiver.encryption_certificate(encryption_certificate(
+        "./test_files/certificate/private_key.pem",
+    ));
+
+    let notifications = receiver.parse(&body).unwrap();
+    let mut notification = notifications[0].change_notification().clone();
+    let mut encrypted_content = notification.encrypted_content().clone().unwrap();
+    encrypted_content.set_data_signature(Some(base64::encode(&[0; 32])));
+    notification.set_encrypted_content(Some(encrypted_content));
+    assert!(receiver.decrypt(&notification).is_err());
+}
@@ -29,9 +29,9 @@ fn list_get_notebooks_and_sections() {
             let mut found_test_notebook = false;
             let mut notebook_id = String::new();
             for value in vec.iter() {
-                if value["displayName"].as_str().unwrap().eq("TestNotebook") {
+                if value.display_name().as_deref() == Some("TestNotebook") {
                     found_test_notebook = true;
-                    notebook_id.push_str(value["id"].as_str().unwrap());
+                    notebook_id.push_str(value.id().as_deref().unwrap());
                 }
             }
 
@@ -47,7 +47,7 @@ fn list_get_notebooks_and_sections() {
             if let Ok(notebook) = get_notebook {
                 assert_eq!(
                     "TestNotebook",
-                    notebook.body()["displayName"].as_str().unwrap()
+                    notebook.body().display_name().as_deref().unwrap()
                 );
             } else if let Err(e) = get_notebook {
                 panic!(
@@ -66,7 +66,7 @@ fn list_get_notebooks_and_sections() {
 
             if let Ok(collection) = sections {
                 let vec = collection.into_body().into_inner();
-                let section_name = vec[0]["displayName"].as_str().unwrap();
+                let section_name = vec[0].display_name().as_deref().unwrap();
                 assert_eq!("TestSection", section_name);
             } else if let Err(e) = sections {
                 panic!(
@@ -99,7 +99,7 @@ fn create_delete_page() {
             .send();
 
         if let Ok(page) = res {
-            let page_id = page.body()["id"].as_str().unwrap();
+            let page_id = page.body().id().as_deref().unwrap();
 
             thread::sleep(Duration::from_secs(5));
             let delete_res = client
@@ -0,0 +1,183 @@
+use futures::StreamExt;
+use graph_error::GraphResult;
+use graph_rs::models::User;
+use graph_rs::prelude::*;
+use std::sync::mpsc::Receiver;
+use test_tools::oauthrequest::{Environment, OAuthTestClient};
+use test_tools::oauthrequest::{ASYNC_THROTTLE_MUTEX, THROTTLE_MUTEX};
+use test_tools::support::server::LocalServer;
+
+// Three pages of users. The first two pages link to the next page.
+fn serve_pages(count: usize) -> (String, Receiver<String>) {
+    LocalServer::serve_with(|base| {
+        let pages = vec![
+            format!(
+                "{{\"value\":[{{\"id\":\"1\"}},{{\"id\":\"2\"}}],\"@odata.nextLink\":\"{}/v1.0/users?skiptoken=2\"}}",
+                base
+            ),
+            format!(
+                "{{\"value\":[{{\"id\":\"3\"}},{{\"id\":\"4\"}}],\"@odata.nextLink\":\"{}/v1.0/users?skiptoken=3\"}}",
+                base
+            ),
+            "{\"value\":[{\"id\":\"5\"}]}".to_string(),
+        ];
+        pages
+            .iter()
+            .take(count)
+            .map(|page| LocalServer::json("200 OK", page))
+            .collect()
+    })
+}
+
+fn user_ids(users: Vec<GraphResult<User>>) -> Vec<String> {
+    users
+        .into_iter()
+        .map(|user| user.unwrap().id().clone().unwrap())
+        .collect()
+}
+
+fn assert_requests(requests: Receiver<String>, paths: &[&str]) {
+    for path in paths {
+        let request = requests.recv().unwrap();
+        assert!(
+            request.starts_with(&format!("GET {} ", path)),
+            "Expected request for {}. Request: {}",
+            path,
+            request
+        );
+    }
+    assert!(requests.recv().is_err());
+}
+
+#[test]
+fn paged_follows_next_link() {
+    let (base, requests) = serve_pages(3);
+    let client = Graph::new("ACCESS_TOKEN");
+    client.set_custom_endpoint(&base).unwrap();
+
+    let mut users = client.v1().users("").list().paged();
+    let ids = user_ids(users.by_ref().collect());
+
+    assert_eq!(ids, vec!["1", "2", "3", "4", "5"]);
+    assert_eq!(users.pages(), 3);
+    assert_requests(
+        requests,
+        &[
+            "/v1.0/users",
+            "/v1.0/users?skiptoken=2",
+            "/v1.0/users?skiptoken=3",
+        ],
+    );
+}
+
+#[test]
+fn paged_stops_at_max_pages() {
+    let (base, requests) = serve_pages(2);
+    let client = Graph::new("ACCESS_TOKEN");
+    client.set_custom_endpoint(&base).unwrap();
+
+    let mut users = client.v1().users("").list().paged().max_pages(2);
+    let ids = user_ids(users.by_ref().collect());
+
+    assert_eq!(ids, vec!["1", "2", "3", "4"]);
+    assert_eq!(users.pages(), 2);
+    assert_requests(requests, &["/v1.0/users", "/v1.0/users?skiptoken=2"]);
+}
+
+#[test]
+fn paged_stops_at_max_items() {
+    let (base, requests) = serve_pages(2);
+    let client = Graph::new("ACCESS_TOKEN");
+    client.set_custom_endpoint(&base).unwrap();
+
+    let mut users = client.v1().users("").list().paged().max_items(3);
+    let ids = user_ids(users.by_ref().collect());
+
+    assert_eq!(ids, vec!["1", "2", "3"]);
+    assert_eq!(users.pages(), 2);
+    assert_requests(requests, &["/v1.0/users", "/v1.0/users?skiptoken=2"]);
+}
+
+#[tokio::test]
+async fn paged_stream_follows_next_link() {
+    let (base, requests) = serve_pages(3);
+    let client = Graph::new_async("ACCESS_TOKEN");
+    client.set_custom_endpoint(&base).unwrap();
+
+    let users: Vec<GraphResult<User>> = client
+        .v1()
+        .users("")
+        .list()
+        .paged()
+        .max_items(4)
+        .stream()
+        .collect()
+        .await;
+
+    assert_eq!(user_ids(users), vec!["1", "2", "3", "4"]);
+    assert_requests(requests, &["/v1.0/users", "/v1.0/users?skiptoken=2"]);
+}
+
+#[test]
+fn paged_items() {
+    if Environment::is_appveyor() {
+        return;
+    }
+
+    let _lock = THROTTLE_MUTEX.lock().unwrap();
+    if let Some((id, client)) = OAuthTestClient::ClientCredentials.graph() {
+        let mut count = 0;
+        for user in client.v1().users(id.as_str()).list().paged().max_items(3) {
+            if let Err(err) = user {
+                panic!("Request error. Method: users list paged. Error: {:#?}", err);
+            }
+            count += 1;
+        }
+        assert!(count > 0 && count <= 3);
+    }
+}
+
+#[test]
+fn paged_max_pages() {
+    if Environment::is_appveyor() {
+        return;
+    }
+
+    let _lock = THROTTLE_MUTEX.lock().unwrap();
+    if let Some((id, client)) = OAuthTestClient::ClientCredentials.graph() {
+        let v1 = client.v1();
+        let request = v1.users(id.as_str());
+        let mut users = request.list().paged().max_pages(1);
+        let count = users.by_ref().filter(|user| user.is_ok()).count();
+
+        assert!(count > 0);
+        assert_eq!(users.pages(), 1);
+    }
+}
+
+#[tokio::test]
+async fn paged_stream() {
+    if Environment::is_travis() || Environment::is_appveyor() {
+        return;
+    }
+
+    let _lock = ASYNC_THROTTLE_MUTEX.lock().await;
+    if let Some((id, client)) = OAuthTestClient::ClientCredentials.graph_async().await {
+        let users: Vec<_> = client
+            .v1()
+            .users(id.as_str())
+            .list()
+            .paged()
+            .max_items(3)
+            .stream()
+            .collect()
+            .await;
+
+        assert!(!users.is_empty() && users.len() <= 3);
+        for user in users {
+            if let Err(err) = user {
+                panic!("Request error. Method: users list paged. Error: {:#?}", err);
+            }
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use graph_rs::error::{GraphFailure, GraphHeaders};
+use graph_rs::header::{HeaderMap, HeaderValue, RETRY_AFTER};
+use graph_rs::http::{AsyncHttpClient, BlockingHttpClient, RequestClient, RetryPolicy};
+use graph_rs::url::GraphUrl;
+use std::sync::mpsc::Receiver;
+use std::time::Duration;
+use test_tools::support::server::LocalServer;
+
+fn throttled() -> String {
+    LocalServer::response(
+        "429 Too Many Requests",
+        &[("Retry-After", "0"), ("Content-Type", "application/json")],
+        "{}",
+    )
+}
+
+fn ok() -> String {
+    LocalServer::json("200 OK", "{\"value\":\"ok\"}")
+}
+
+fn url(base: &str) -> GraphUrl {
+    GraphUrl::parse(&format!("{}/v1.0/me", base)).unwrap()
+}
+
+// Each attempt is sent to the server and there are no more requests.
+fn assert_attempts(requests: Receiver<String>, attempts: usize) {
+    for _ in 0..attempts {
+        assert!(requests.recv().unwrap().starts_with("GET /v1.0/me "));
+    }
+    assert!(requests.recv().is_err());
+}
+
+#[test]
+fn retry_policy_delay() {
+    let policy = RetryPolicy::new(3)
+        .base_delay(Duration::from_secs(1))
+        .max_delay(Duration::from_secs(3))
+        .jitter(false);
+
+    let headers = GraphHeaders::default();
+    assert_eq!(policy.delay(1, &headers), Duration::from_secs(1));
+    assert_eq!(policy.delay(2, &headers), Duration::from_secs(2));
+    assert_eq!(policy.delay(3, &headers), Duration::from_secs(3));
+
+    let mut header_map = HeaderMap::new();
+    header_map.insert(RETRY_AFTER, HeaderValue::from_static("2"));
+    let headers = GraphHeaders::from(header_map);
+    assert_eq!(headers.retry_after(), Some(2));
+    assert_eq!(policy.delay(3, &headers), Duration::from_secs(2));
+
+    // Retry-After is capped at the max delay.
+    let mut header_map = HeaderMap::new();
+    header_map.insert(RETRY_AFTER, HeaderValue::from_static("10"));
+    let headers = GraphHeaders::from(header_map);
+    assert_eq!(headers.retry_after(), Some(10));
+    assert_eq!(policy.delay(1, &headers), Duration::from_secs(3));
+}
+
+#[test]
+fn retry_throttled_request() {
+    let (base, requests) = LocalServer::serve(vec![throttled(), throttled(), ok()]);
+    let client = BlockingHttpClient::new(url(&base));
+    client.set_retry_policy(RetryPolicy::new(3));
+
+    let response = client.execute::<serde_json::Value>().unwrap();
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.body()["value"].as_str(), Some("ok"));
+    assert_attempts(requests, 3);
+}
+
+#[test]
+fn retry_attempts_exhausted() {
+    let (base, requests) = LocalServer::serve(vec![throttled(), throttled()]);
+    let client = BlockingHttpClient::new(url(&base));
+    client.set_retry_policy(RetryPolicy::new(2));
+
+    match client.execute::<serde_json::Value>() {
+        Err(GraphFailure::GraphError(error)) => {
+            assert_eq!(error.code, 429);
+            assert_eq!(error.retry_after(), Some(0));
+        },
+        _ => panic!("Expected GraphFailure::GraphError for status 429"),
+    }
+    assert_attempts(requests, 2);
+}
+
+#[tokio::test]
+async fn async_retry_throttled_request() {
+    let (base, requests) = LocalServer::serve(vec![throttled(), ok()]);
+    let client = AsyncHttpClient::new(url(&base));
+    client.set_retry_policy(RetryPolicy::new(2));
+
+    let response = client.execute::<serde_json::Value>().await.unwrap();
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.body()["value"].as_str(), Some("ok"));
+    assert_attempts(requests, 2);
+}
@@ -0,0 +1,58 @@
+use graph_rs::http::BlockingHttpClient;
+use graph_rs::prelude::Graph;
+
+static ID: &str = "7f105c7d-2dc5-4530-97cd-4e7ae6534c07";
+
+fn get_graph() -> Graph<BlockingHttpClient> {
+    Graph::new("")
+}
+
+#[test]
+fn subscriptions_url() {
+    let client = get_graph();
+    client.v1().subscriptions().list();
+
+    client.url_ref(|url| {
+        assert_eq!(
+            "https://graph.microsoft.com/v1.0/subscriptions",
+            url.as_str()
+        );
+    });
+
+    client.beta().subscriptions().get(ID);
+
+    client.url_ref(|url| {
+        assert_eq!(
+            &format!("https://graph.microsoft.com/beta/subscriptions/{}", ID),
+            url.as_str()
+        );
+    });
+}
+
+#[test]
+fn renew_subscription_url() {
+    let client = get_graph();
+    client
+        .v1()
+        .subscriptions()
+        .renew(ID, "2020-08-01T11:00:00.0000000Z");
+
+    client.url_ref(|url| {
+        assert_eq!(
+            &format!("https://graph.microsoft.com/v1.0/subscriptions/{}", ID),
+            url.as_str()
+        );
+    });
+
+    client.v1().subscriptions().reauthorize(ID);
+
+    client.url_ref(|url| {
+        assert_eq!(
+            &format!(
+                "https://graph.microsoft.com/v1.0/subscriptions/{}/reauthorize",
+                ID
+            ),
+            url.as_str()
+        );
+    });
+}
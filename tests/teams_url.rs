@@ -0,0 +1,157 @@
+use graph_rs::http::BlockingHttpClient;
+use graph_rs::prelude::Graph;
+
+static ID: &str = "b!CbtYWrofwUGBJWnaJkNwoNrBLp_kC3RKklSXPwrdeP3yH8_qmH9xT5Y6RODPNfYI";
+static CHANNEL_ID: &str = "19:09fc54a3141a45d0bc769cf506d2e079@thread.skype";
+static MESSAGE_ID: &str = "1590776551682";
+
+fn get_graph() -> Graph<BlockingHttpClient> {
+    Graph::new("")
+}
+
+#[test]
+fn teams_url() {
+    let client = get_graph();
+    client.v1().teams().get_team(ID);
+
+    client.url_ref(|url| {
+        assert_eq!(
+            &format!("https://graph.microsoft.com/v1.0/teams/{}", ID),
+            url.as_str()
+        );
+    });
+
+    client.v1().me().list_joined_teams();
+
+    client.url_ref(|url| {
+        assert_eq!(
+            "https://graph.microsoft.com/v1.0/me/joinedTeams",
+            url.as_str()
+        );
+    });
+
+    client.v1().users(ID).list_joined_teams();
+
+    client.url_ref(|url| {
+        assert_eq!(
+            &format!("https://graph.microsoft.com/v1.0/users/{}/joinedTeams", ID),
+            url.as_str()
+        );
+    });
+}
+
+#[test]
+fn team_channels_url() {
+    let client = get_graph();
+    client.v1().teams().channels().list_channels(ID);
+
+    client.url_ref(|url| {
+        assert_eq!(
+            &format!("https://graph.microsoft.com/v1.0/teams/{}/channels", ID),
+            url.as_str()
+        );
+    });
+
+    client
+        .v1()
+        .teams()
+        .channels()
+        .update_channel(ID, CHANNEL_ID, &serde_json::json!({}));
+
+    client.url_ref(|url| {
+        assert_eq!(
+            &format!(
+                "https://graph.microsoft.com/v1.0/teams/{}/channels/{}",
+                ID, CHANNEL_ID
+            ),
+            url.as_str()
+        );
+    });
+}
+
+#[test]
+fn channel_messages_url() {
+    let client = get_graph();
+    client
+        .v1()
+        .teams()
+        .messages()
+        .list_replies(ID, CHANNEL_ID, MESSAGE_ID);
+
+    client.url_ref(|url| {
+        assert_eq!(
+            &format!(
+                "https://graph.microsoft.com/v1.0/teams/{}/channels/{}/messages/{}/replies",
+                ID, CHANNEL_ID, MESSAGE_ID
+            ),
+            url.as_str()
+        );
+    });
+
+    client
+        .beta()
+        .teams()
+        .tabs()
+        .get_tab(ID, CHANNEL_ID, MESSAGE_ID);
+
+    client.url_ref(|url| {
+        assert_eq!(
+            &format!(
+                "https://graph.microsoft.com/beta/teams/{}/channels/{}/tabs/{}",
+                ID, CHANNEL_ID, MESSAGE_ID
+            ),
+            url.as_str()
+        );
+    });
+}
+
+#[test]
+fn team_members_and_apps_url() {
+    let client = get_graph();
+    client.v1().teams().members().list_members(ID);
+
+    client.url_ref(|url| {
+        assert_eq!(
+            &format!("https://graph.microsoft.com/v1.0/teams/{}/members", ID),
+            url.as_str()
+        );
+    });
+
+    client
+        .v1()
+        .teams()
+        .installed_apps()
+        .upgrade_installed_app(ID, MESSAGE_ID);
+
+    client.url_ref(|url| {
+        assert_eq!(
+            &format!(
+                "https://graph.microsoft.com/v1.0/teams/{}/installedApps/{}/upgrade",
+                ID, MESSAGE_ID
+            ),
+            url.as_str()
+        );
+    });
+}
+
+#[test]
+fn chats_url() {
+    let client = get_graph();
+    client.v1().me().list_chats();
+
+    client.url_ref(|url| {
+        assert_eq!("https://graph.microsoft.com/v1.0/me/chats", url.as_str());
+    });
+
+    client.v1().chats().list_messages(CHANNEL_ID);
+
+    client.url_ref(|url| {
+        assert_eq!(
+            &format!(
+                "https://graph.microsoft.com/v1.0/chats/{}/messages",
+                CHANNEL_ID
+            ),
+            url.as_str()
+        );
+    });
+}
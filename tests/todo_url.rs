@@ -0,0 +1,118 @@
+use graph_rs::http::BlockingHttpClient;
+use graph_rs::prelude::Graph;
+
+static RID: &str = "T5Y6RODPNfYICbtYWrofwUGBJWnaJkNwH9x";
+static ID: &str = "AAMkADIyAAAAABrJAAA=";
+static TASK_ID: &str = "AAkALgAAAAAAHYQDEapmEc2byACqAC-EWg0AAAAA";
+
+fn get_graph() -> Graph<BlockingHttpClient> {
+    Graph::new("")
+}
+
+#[test]
+fn todo_lists_url() {
+    let client = get_graph();
+    client.v1().me().todo().list_lists();
+
+    client.url_ref(|url| {
+        assert_eq!(
+            "https://graph.microsoft.com/v1.0/me/todo/lists",
+            url.as_str()
+        );
+    });
+
+    client.v1().users(RID).todo().get_list(ID);
+
+    client.url_ref(|url| {
+        assert_eq!(
+            &format!(
+                "https://graph.microsoft.com/v1.0/users/{}/todo/lists/{}",
+                RID, ID
+            ),
+            url.as_str()
+        );
+    });
+}
+
+#[test]
+fn todo_tasks_url() {
+    let client = get_graph();
+    client.v1().me().todo().tasks().get_task(ID, TASK_ID);
+
+    client.url_ref(|url| {
+        assert_eq!(
+            &format!(
+                "https://graph.microsoft.com/v1.0/me/todo/lists/{}/tasks/{}",
+                ID, TASK_ID
+            ),
+            url.as_str()
+        );
+    });
+
+    client.v1().me().todo().tasks().delta(ID);
+
+    client.url_ref(|url| {
+        assert_eq!(
+            &format!(
+                "https://graph.microsoft.com/v1.0/me/todo/lists/{}/tasks/delta",
+                ID
+            ),
+            url.as_str()
+        );
+    });
+
+    client
+        .v1()
+        .users(RID)
+        .todo()
+        .tasks()
+        .create_extension(ID, TASK_ID, &serde_json::json!({}));
+
+    client.url_ref(|url| {
+        assert_eq!(
+            &format!(
+                "https://graph.microsoft.com/v1.0/users/{}/todo/lists/{}/tasks/{}/extensions",
+                RID, ID, TASK_ID
+            ),
+            url.as_str()
+        );
+    });
+}
+
+#[test]
+fn todo_linked_resources_and_checklist_items_url() {
+    let client = get_graph();
+    client
+        .v1()
+        .me()
+        .todo()
+        .linked_resources()
+        .list_linked_resources(ID, TASK_ID);
+
+    client.url_ref(|url| {
+        assert_eq!(
+            &format!(
+                "https://graph.microsoft.com/v1.0/me/todo/lists/{}/tasks/{}/linkedResources",
+                ID, TASK_ID
+            ),
+            url.as_str()
+        );
+    });
+
+    client
+        .v1()
+        .me()
+        .todo()
+        .checklist_items()
+        .delete_checklist_item(ID, TASK_ID, RID);
+
+    client.url_ref(|url| {
+        assert_eq!(
+            &format!(
+                "https://graph.microsoft.com/v1.0/me/todo/lists/{}/tasks/{}/checklistItems/{}",
+                ID, TASK_ID, RID
+            ),
+            url.as_str()
+        );
+    });
+}
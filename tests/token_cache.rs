@@ -0,0 +1,143 @@
+use graph_oauth::oauth::{
+    AccessToken, FileTokenCache, InMemoryTokenCache, OAuth, TokenCache, TokenCacheKey,
+};
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+use test_tools::support::cleanup::CleanUp;
+
+fn oauth() -> OAuth {
+    let mut oauth = OAuth::new();
+    oauth
+        .client_id("client_id")
+        .client_secret("client_secret")
+        .redirect_uri("http://localhost:8000/redirect")
+        .access_token_url("https://login.microsoftonline.com/tenant_id/oauth2/v2.0/token")
+        .login_hint("user@example.com")
+        .add_scope("Files.Read")
+        .add_scope("offline_access");
+    oauth
+}
+
+fn access_token() -> AccessToken {
+    AccessToken::new(
+        "Bearer",
+        3600,
+        "Files.Read offline_access",
+        "ASODFIUJ34KJ;LADSK",
+    )
+}
+
+fn clean_up(file_location: &str) -> CleanUp {
+    let mut clean_up = CleanUp::new(|| {
+        if Path::new(file_location).exists() {
+            fs::remove_file(Path::new(file_location)).unwrap();
+        }
+    });
+    clean_up.rm_files(file_location.into());
+    clean_up
+}
+
+#[test]
+fn token_cache_key_from_oauth() {
+    let key = TokenCacheKey::from(&oauth());
+    assert_eq!(key.client_id(), "client_id");
+    assert_eq!(key.tenant(), Some("tenant_id"));
+    assert_eq!(key.account(), Some("user@example.com"));
+    assert_eq!(key.scopes().len(), 2);
+    assert_eq!(
+        key,
+        TokenCacheKey::new(
+            "client_id",
+            Some("tenant_id"),
+            Some("user@example.com"),
+            vec!["offline_access", "Files.Read"]
+        )
+    );
+}
+
+#[test]
+fn in_memory_token_cache() {
+    let cache = InMemoryTokenCache::new();
+    let key = TokenCacheKey::from(&oauth());
+    assert_eq!(cache.get(&key).unwrap(), None);
+
+    cache.set(&key, &access_token()).unwrap();
+    let cached = cache.get(&key).unwrap().unwrap();
+    assert_eq!(cached.bearer_token(), "ASODFIUJ34KJ;LADSK");
+
+    cache.remove(&key).unwrap();
+    assert_eq!(cache.get(&key).unwrap(), None);
+}
+
+#[test]
+fn file_token_cache() {
+    let file_location = "./test_files/token_cache.json";
+    let _clean_up = clean_up(file_location);
+
+    let cache = FileTokenCache::new(file_location);
+    let key = TokenCacheKey::from(&oauth());
+    cache.set(&key, &access_token()).unwrap();
+
+    let content = fs::read_to_string(file_location).unwrap();
+    assert!(content.contains("ASODFIUJ34KJ;LADSK"));
+
+    let cache = FileTokenCache::new(file_location);
+    let cached = cache.get(&key).unwrap().unwrap();
+    assert_eq!(cached.bearer_token(), "ASODFIUJ34KJ;LADSK");
+    assert!(!cached.is_expired());
+}
+
+#[cfg(unix)]
+#[test]
+fn file_token_cache_permissions() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let file_location = "./test_files/token_cache_permissions.json";
+    let _clean_up = clean_up(file_location);
+
+    let cache = FileTokenCache::new(file_location);
+    let key = TokenCacheKey::from(&oauth());
+    cache.set(&key, &access_token()).unwrap();
+    cache.set(&key, &access_token()).unwrap();
+
+    let metadata = fs::metadata(file_location).unwrap();
+    assert_eq!(metadata.permissions().mode() & 0o777, 0o600);
+    assert!(!Path::new("./test_files/token_cache_permissions.json.tmp").exists());
+}
+
+#[test]
+fn encrypted_file_token_cache() {
+    let file_location = "./test_files/token_cache_encrypted.json";
+    let _clean_up = clean_up(file_location);
+
+    let cache = FileTokenCache::encrypted(file_location, &[7; 32]).unwrap();
+    let key = TokenCacheKey::from(&oauth());
+    cache.set(&key, &access_token()).unwrap();
+
+    let content = fs::read_to_string(file_location).unwrap();
+    assert!(!content.contains("ASODFIUJ34KJ;LADSK"));
+
+    let cache = FileTokenCache::encrypted(file_location, &[7; 32]).unwrap();
+    let cached = cache.get(&key).unwrap().unwrap();
+    assert_eq!(cached.bearer_token(), "ASODFIUJ34KJ;LADSK");
+
+    let cache = FileTokenCache::encrypted(file_location, &[8; 32]).unwrap();
+    assert!(cache.get(&key).is_err());
+    assert!(FileTokenCache::encrypted(file_location, &[7; 16]).is_err());
+}
+
+#[test]
+fn access_token_request_reads_cache() {
+    let cache: Arc<dyn TokenCache> = Arc::new(InMemoryTokenCache::new());
+    let mut oauth = oauth();
+    oauth.token_cache_arc(cache.clone());
+    cache
+        .set(&oauth.token_cache_key(), &access_token())
+        .unwrap();
+
+    // The cached access token is returned without sending the request.
+    let mut request = oauth.build().client_credentials();
+    let access_token = request.access_token().send().unwrap();
+    assert_eq!(access_token.bearer_token(), "ASODFIUJ34KJ;LADSK");
+}
@@ -0,0 +1,252 @@
+use async_trait::async_trait;
+use graph_rs::client::Graph;
+use graph_rs::error::GraphResult;
+use graph_rs::http::{
+    AsyncHttpClient, BlockingHttpClient, OAuthTokenProvider, RequestClient, TokenProvider,
+};
+use graph_rs::oauth::{GrantType, OAuth};
+use graph_rs::prelude::Delta;
+use graph_rs::url::GraphUrl;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use test_tools::support::server::LocalServer;
+
+// Returns "token-0" until the token is refreshed and then
+// "token-1", "token-2", etc. for each refresh.
+#[derive(Default)]
+struct CountingProvider {
+    refreshed: AtomicUsize,
+}
+
+#[async_trait]
+impl TokenProvider for CountingProvider {
+    fn bearer_token(&self) -> GraphResult<String> {
+        Ok(format!("token-{}", self.refreshed.load(Ordering::SeqCst)))
+    }
+
+    fn refresh(&self) -> GraphResult<String> {
+        self.refreshed.fetch_add(1, Ordering::SeqCst);
+        self.bearer_token()
+    }
+
+    async fn bearer_token_async(&self) -> GraphResult<String> {
+        self.bearer_token()
+    }
+
+    async fn refresh_async(&self) -> GraphResult<String> {
+        self.refresh()
+    }
+}
+
+fn invalid_token() -> String {
+    LocalServer::response(
+        "401 Unauthorized",
+        &[
+            ("Content-Type", "application/json"),
+            ("WWW-Authenticate", "Bearer error=\"invalid_token\""),
+        ],
+        "{}",
+    )
+}
+
+fn ok() -> String {
+    LocalServer::json("200 OK", "{\"value\":\"ok\"}")
+}
+
+// A delta page with a next link followed by the last page. The request
+// for the second page fails once with an invalid token.
+fn serve_delta_pages() -> (String, std::sync::mpsc::Receiver<String>) {
+    LocalServer::serve_with(|base| {
+        vec![
+            LocalServer::json(
+                "200 OK",
+                &format!(
+                    "{{\"value\":[{{\"id\":\"1\"}}],\"@odata.nextLink\":\"{}/v1.0/me/drive/root/delta?token=2\"}}",
+                    base
+                ),
+            ),
+            invalid_token(),
+            LocalServer::json(
+                "200 OK",
+                &format!(
+                    "{{\"value\":[{{\"id\":\"2\"}}],\"@odata.deltaLink\":\"{}/v1.0/me/drive/root/delta?token=3\"}}",
+                    base
+                ),
+            ),
+        ]
+    })
+}
+
+fn assert_delta_requests(requests: std::sync::mpsc::Receiver<String>) {
+    let tokens = ["token-0", "token-0", "token-1"];
+    for token in tokens.iter() {
+        let request = requests.recv().unwrap().to_lowercase();
+        assert!(request.contains(&format!("authorization: bearer {}", token)));
+        assert_eq!(request.matches("authorization:").count(), 1);
+    }
+    assert!(requests.recv().is_err());
+}
+
+fn url(base: &str) -> GraphUrl {
+    GraphUrl::parse(&format!("{}/v1.0/me", base)).unwrap()
+}
+
+#[test]
+fn token_provider_bearer_token() {
+    let (base, requests) = LocalServer::serve(vec![ok()]);
+    let client = BlockingHttpClient::new(url(&base));
+    client.set_token("stale");
+    client.set_token_provider(Some(Arc::new(CountingProvider::default())));
+
+    let response = client.execute::<serde_json::Value>().unwrap();
+    assert_eq!(response.status(), 200);
+    let request = requests.recv().unwrap().to_lowercase();
+    assert!(request.contains("authorization: bearer token-0"));
+}
+
+#[test]
+fn token_provider_refresh_on_invalid_token() {
+    let (base, requests) = LocalServer::serve(vec![invalid_token(), ok()]);
+    let provider = Arc::new(CountingProvider::default());
+    let client = BlockingHttpClient::new(url(&base));
+    client.set_token_provider(Some(provider.clone()));
+
+    let response = client.execute::<serde_json::Value>().unwrap();
+    assert_eq!(response.status(), 200);
+    assert_eq!(provider.refreshed.load(Ordering::SeqCst), 1);
+
+    let first = requests.recv().unwrap().to_lowercase();
+    let second = requests.recv().unwrap().to_lowercase();
+    assert!(first.contains("authorization: bearer token-0"));
+    assert!(second.contains("authorization: bearer token-1"));
+    assert_eq!(client.token(), "token-1");
+}
+
+#[tokio::test]
+async fn async_token_provider_refresh_on_invalid_token() {
+    let (base, requests) = LocalServer::serve(vec![invalid_token(), ok()]);
+    let provider = Arc::new(CountingProvider::default());
+    let client = AsyncHttpClient::new(url(&base));
+    client.set_token_provider(Some(provider.clone()));
+
+    let response = client.execute::<serde_json::Value>().await.unwrap();
+    assert_eq!(response.status(), 200);
+    assert_eq!(provider.refreshed.load(Ordering::SeqCst), 1);
+
+    let _ = requests.recv().unwrap();
+    let second = requests.recv().unwrap().to_lowercase();
+    assert!(second.contains("authorization: bearer token-1"));
+}
+
+#[test]
+fn token_provider_bearer_token_for_built_request() {
+    let (base, requests) = LocalServer::serve(vec![ok()]);
+    let client = Graph::new("stale");
+    client.set_custom_endpoint(&base).unwrap();
+    client.set_token_provider(CountingProvider::default());
+
+    let response = client.v1().me().settings().build().send().unwrap();
+    assert_eq!(response.status(), 200);
+    let request = requests.recv().unwrap().to_lowercase();
+    assert!(request.contains("authorization: bearer token-0"));
+}
+
+#[tokio::test]
+async fn async_token_provider_bearer_token_for_built_request() {
+    let (base, requests) = LocalServer::serve(vec![ok()]);
+    let client = Graph::new_async("stale");
+    client.set_custom_endpoint(&base).unwrap();
+    client.set_token_provider(CountingProvider::default());
+
+    let response = client
+        .v1()
+        .me()
+        .settings()
+        .build()
+        .await
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 200);
+    let request = requests.recv().unwrap().to_lowercase();
+    assert!(request.contains("authorization: bearer token-0"));
+}
+
+#[test]
+fn token_provider_refresh_for_delta_next_link() {
+    let (base, requests) = serve_delta_pages();
+    let client = Graph::new("stale");
+    client.set_custom_endpoint(&base).unwrap();
+    client.set_token_provider(CountingProvider::default());
+
+    let receiver = client.v1().me().drive().delta().send();
+    let mut pages = 0;
+    for delta in receiver.iter() {
+        match delta {
+            Delta::Next(_) => pages += 1,
+            Delta::Done(err) => {
+                assert!(err.is_none(), "Delta error: {:#?}", err);
+                break;
+            },
+        }
+    }
+    assert_eq!(pages, 2);
+    assert_delta_requests(requests);
+}
+
+#[tokio::test]
+async fn async_token_provider_refresh_for_delta_next_link() {
+    let (base, requests) = serve_delta_pages();
+    let client = Graph::new_async("stale");
+    client.set_custom_endpoint(&base).unwrap();
+    client.set_token_provider(CountingProvider::default());
+
+    let mut receiver = client.v1().me().drive().delta().send().await;
+    let mut pages = 0;
+    while let Some(delta) = receiver.recv().await {
+        match delta {
+            Delta::Next(_) => pages += 1,
+            Delta::Done(err) => {
+                assert!(err.is_none(), "Delta error: {:#?}", err);
+                break;
+            },
+        }
+    }
+    assert_eq!(pages, 2);
+    assert_delta_requests(requests);
+}
+
+#[tokio::test]
+async fn oauth_token_provider_single_async_refresh() {
+    let access_token = LocalServer::json(
+        "200 OK",
+        r#"{
+            "token_type": "Bearer",
+            "scope": "https://graph.microsoft.com/.default",
+            "expires_in": 3600,
+            "access_token": "ASODFIUJ34KJ;LADSK"
+        }"#,
+    );
+    let (base, requests) = LocalServer::serve(vec![access_token]);
+    let mut oauth = OAuth::new();
+    oauth
+        .client_id("bb301aaa-1201-4259-a230923fds32")
+        .client_secret("CLDIE3F")
+        .access_token_url(&format!("{}/tenant_id/oauth2/v2.0/token", base))
+        .add_scope("https://graph.microsoft.com/.default");
+    let provider = OAuthTokenProvider::new(oauth, GrantType::ClientCredentials);
+
+    // Only the first request sends a token request. The others wait for
+    // it and use the new token.
+    let (first, second, third) = futures::join!(
+        provider.bearer_token_async(),
+        provider.bearer_token_async(),
+        provider.bearer_token_async()
+    );
+    assert_eq!(first.unwrap(), "ASODFIUJ34KJ;LADSK");
+    assert_eq!(second.unwrap(), "ASODFIUJ34KJ;LADSK");
+    assert_eq!(third.unwrap(), "ASODFIUJ34KJ;LADSK");
+
+    assert!(requests.recv().is_ok());
+    assert!(requests.recv().is_err());
+}
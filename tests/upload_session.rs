@@ -0,0 +1,274 @@
+use graph_rs::http::{
+    AsyncIterator, ChunkedFile, NextSession, UploadContent, UploadSessionClient,
+    UploadSessionState, DEFAULT_UPLOAD_CHUNK_SIZE, UPLOAD_CHUNK_MULTIPLE,
+};
+use std::fs;
+use std::io::Cursor;
+use std::path::Path;
+use std::sync::mpsc::Receiver;
+use test_tools::support::cleanup::CleanUp;
+use test_tools::support::server::LocalServer;
+
+fn chunked_file(file_location: &str, size: usize) -> (CleanUp, Vec<u8>) {
+    let mut clean_up = CleanUp::new(|| {
+        if Path::new(file_location).exists() {
+            fs::remove_file(Path::new(file_location)).unwrap();
+        }
+    });
+    clean_up.rm_files(file_location.into());
+
+    let content: Vec<u8> = (0..size).map(|i| (i % 251) as u8).collect();
+    fs::write(file_location, &content).unwrap();
+    (clean_up, content)
+}
+
+#[test]
+fn chunked_file_chunks() {
+    let file_location = "./test_files/chunked_file.bin";
+    let (_clean_up, content) = chunked_file(file_location, 1_000_000);
+
+    let mut chunks = ChunkedFile::new(file_location).unwrap();
+    assert_eq!(chunks.chunk_size(), DEFAULT_UPLOAD_CHUNK_SIZE);
+    assert!(chunks.set_chunk_size(1000).is_err());
+    assert!(chunks.set_chunk_size(192 * UPLOAD_CHUNK_MULTIPLE).is_err());
+    chunks.set_chunk_size(UPLOAD_CHUNK_MULTIPLE).unwrap();
+
+    let mut ranges = Vec::new();
+    let mut uploaded = Vec::new();
+    while let Some((body, content_length, content_range)) = chunks.next_chunk().unwrap() {
+        assert_eq!(body.len() as u64, content_length);
+        uploaded.extend(body);
+        ranges.push(content_range);
+        chunks.advance(content_length);
+    }
+
+    assert_eq!(uploaded, content);
+    assert_eq!(
+        ranges,
+        vec![
+            "bytes 0-327679/1000000",
+            "bytes 327680-655359/1000000",
+            "bytes 655360-983039/1000000",
+            "bytes 983040-999999/1000000",
+        ]
+    );
+    assert!(chunks.is_empty());
+}
+
+#[test]
+fn chunked_file_next_expected_ranges() {
+    let file_location = "./test_files/chunked_file_ranges.bin";
+    let (_clean_up, content) = chunked_file(file_location, 1_000_000);
+
+    let mut chunks = ChunkedFile::new(file_location).unwrap();
+    chunks.set_chunk_size(UPLOAD_CHUNK_MULTIPLE).unwrap();
+    chunks.set_next_expected_ranges(&["655360-"]).unwrap();
+    assert_eq!(chunks.bytes_remaining(), 344_640);
+
+    let (body, content_length, content_range) = chunks.next_chunk().unwrap().unwrap();
+    assert_eq!(content_length, 327_680);
+    assert_eq!(content_range, "bytes 655360-983039/1000000");
+    assert_eq!(body.as_slice(), &content[655_360..983_040]);
+
+    chunks.set_next_expected_ranges(&["10-19", "30-"]).unwrap();
+    assert_eq!(chunks.bytes_remaining(), 999_980);
+    assert!(chunks.set_next_expected_ranges(&["10-1000000"]).is_err());
+    assert!(chunks.set_next_expected_ranges(&["a-"]).is_err());
+}
+
+#[test]
+fn upload_session_state() {
+    let file_location = "./test_files/upload_session_state.bin";
+    let (_clean_up, _) = chunked_file(file_location, 1_000);
+
+    let mut session = UploadSessionClient::new(serde_json::json!({
+        "uploadUrl": "https://sn3302.up.1drv.com/up/fe6987415ace7X4e1eF866337",
+        "expirationDateTime": "2015-01-29T09:21:55.523Z"
+    }))
+    .unwrap();
+    session.set_file(file_location.into()).unwrap();
+    assert!(session.set_chunk_size(1000).is_err());
+    session.set_chunk_size(2 * UPLOAD_CHUNK_MULTIPLE).unwrap();
+    assert!(session.has_next());
+    assert_eq!(session.bytes_remaining(), 1_000);
+
+    let state = session.state();
+    assert_eq!(
+        state,
+        UploadSessionState {
+            upload_url: "https://sn3302.up.1drv.com/up/fe6987415ace7X4e1eF866337".into(),
+            file: file_location.into(),
+            chunk_size: 2 * UPLOAD_CHUNK_MULTIPLE,
+            expiration_date_time: Some("2015-01-29T09:21:55.523Z".into()),
+        }
+    );
+
+    let json = serde_json::to_string(&state).unwrap();
+    let state_from_json: UploadSessionState = serde_json::from_str(&json).unwrap();
+    assert_eq!(state, state_from_json);
+
+    session.from_range(100, 199, file_location).unwrap();
+    assert_eq!(session.bytes_remaining(), 100);
+    assert!(session.from_range(100, 1_000, file_location).is_err());
+}
+
+fn read_chunks(chunks: &mut ChunkedFile) -> (Vec<u8>, Vec<String>) {
+    let mut ranges = Vec::new();
+    let mut uploaded = Vec::new();
+    while let Some((body, content_length, content_range)) = chunks.next_chunk().unwrap() {
+        assert_eq!(body.len() as u64, content_length);
+        uploaded.extend(body);
+        ranges.push(content_range);
+        chunks.advance(content_length);
+    }
+    (uploaded, ranges)
+}
+
+#[test]
+fn chunked_file_from_bytes() {
+    let content: Vec<u8> = (0..700_000).map(|i| (i % 251) as u8).collect();
+
+    let mut chunks = ChunkedFile::from_content(UploadContent::from(content.clone())).unwrap();
+    chunks.set_chunk_size(UPLOAD_CHUNK_MULTIPLE).unwrap();
+    assert_eq!(chunks.file_size(), 700_000);
+    assert!(chunks.file().as_os_str().is_empty());
+
+    let (uploaded, ranges) = read_chunks(&mut chunks);
+    assert_eq!(uploaded, content);
+    assert_eq!(
+        ranges,
+        vec![
+            "bytes 0-327679/700000",
+            "bytes 327680-655359/700000",
+            "bytes 655360-699999/700000",
+        ]
+    );
+}
+
+#[test]
+fn chunked_file_from_reader() {
+    let content: Vec<u8> = (0..700_000).map(|i| (i % 251) as u8).collect();
+
+    let reader = UploadContent::reader(Cursor::new(content.clone()), 700_000);
+    let mut chunks = ChunkedFile::from_content(reader).unwrap();
+    chunks.set_chunk_size(UPLOAD_CHUNK_MULTIPLE).unwrap();
+
+    // A chunk that failed is read again from the bytes kept from the reader.
+    let (first, _, _) = chunks.next_chunk().unwrap().unwrap();
+    let (again, content_length, _) = chunks.next_chunk().unwrap().unwrap();
+    assert_eq!(first, again);
+    chunks.advance(content_length);
+
+    chunks.set_next_expected_ranges(&["300000-"]).unwrap();
+    let (body, _, content_range) = chunks.next_chunk().unwrap().unwrap();
+    assert_eq!(content_range, "bytes 300000-627679/700000");
+    assert_eq!(body.as_slice(), &content[300_000..627_680]);
+
+    // The reader can not be read again from before the bytes that are kept.
+    chunks.set_next_expected_ranges(&["0-"]).unwrap();
+    assert!(chunks.next_chunk().is_err());
+}
+
+#[test]
+fn upload_content_read_to_vec() {
+    let content: Vec<u8> = (0..1_000).map(|i| (i % 251) as u8).collect();
+
+    let reader = UploadContent::reader(Cursor::new(content.clone()), 1_000);
+    assert_eq!(reader.content_length().unwrap(), 1_000);
+    assert_eq!(reader.read_to_vec().unwrap(), content);
+
+    let reader = UploadContent::reader(Cursor::new(content), 2_000);
+    assert!(reader.read_to_vec().is_err());
+}
+
+// The second chunk fails with a 503 and is sent again from the
+// nextExpectedRanges of the session. The response to the second chunk
+// skips ahead to the last chunk.
+fn serve_upload_session() -> (String, Receiver<String>) {
+    LocalServer::serve(vec![
+        LocalServer::json("202 Accepted", r#"{"nextExpectedRanges":["327680-"]}"#),
+        LocalServer::response(
+            "503 Service Unavailable",
+            &[("Content-Type", "application/json"), ("Retry-After", "0")],
+            r#"{"error":{"code":"serviceNotAvailable","message":"Service unavailable"}}"#,
+        ),
+        LocalServer::json("200 OK", r#"{"nextExpectedRanges":["327680-"]}"#),
+        LocalServer::json("202 Accepted", r#"{"nextExpectedRanges":["983040-"]}"#),
+        LocalServer::json("201 Created", "{}"),
+    ])
+}
+
+fn upload_content() -> Vec<u8> {
+    (0..3 * UPLOAD_CHUNK_MULTIPLE as usize + 100)
+        .map(|i| (i % 251) as u8)
+        .collect()
+}
+
+fn assert_upload_requests(requests: Receiver<String>) {
+    let expected = [
+        Some("bytes 0-327679/983140"),
+        Some("bytes 327680-655359/983140"),
+        None,
+        Some("bytes 327680-655359/983140"),
+        Some("bytes 983040-983139/983140"),
+    ];
+    for content_range in expected.iter() {
+        let request = requests.recv().unwrap();
+        let head = request.split("\r\n\r\n").next().unwrap().to_lowercase();
+        match content_range {
+            Some(content_range) => {
+                assert!(head.starts_with("put /upload "));
+                assert!(head.contains(&format!("content-range: {}", content_range)));
+            },
+            None => {
+                assert!(head.starts_with("get /upload "));
+                assert!(!head.contains("content-range:"));
+            },
+        }
+    }
+    assert!(requests.recv().is_err());
+}
+
+#[test]
+fn upload_session_chunk_retry() {
+    let (base, requests) = serve_upload_session();
+    let mut session = UploadSessionClient::new(serde_json::json!({
+        "uploadUrl": format!("{}/upload", base),
+    }))
+    .unwrap();
+    session
+        .set_content(UploadContent::from(upload_content()))
+        .unwrap();
+    session.set_chunk_size(UPLOAD_CHUNK_MULTIPLE).unwrap();
+    session.set_chunk_retries(1);
+
+    let next: Vec<NextSession> = session.by_ref().map(|next| next.unwrap()).collect();
+    assert_eq!(next.len(), 3);
+    assert!(matches!(next.last(), Some(NextSession::Done(_))));
+    assert!(!session.has_next());
+    assert_upload_requests(requests);
+}
+
+#[tokio::test]
+async fn async_upload_session_chunk_retry() {
+    let (base, requests) = serve_upload_session();
+    let mut session = UploadSessionClient::new_async(serde_json::json!({
+        "uploadUrl": format!("{}/upload", base),
+    }))
+    .unwrap();
+    session
+        .set_content_async(UploadContent::from(upload_content()))
+        .await
+        .unwrap();
+    session.set_chunk_size(UPLOAD_CHUNK_MULTIPLE).unwrap();
+    session.set_chunk_retries(1);
+
+    let mut next = Vec::new();
+    while let Some(result) = session.next().await {
+        next.push(result.unwrap());
+    }
+    assert_eq!(next.len(), 3);
+    assert!(matches!(next.last(), Some(NextSession::Done(_))));
+    assert!(!session.has_next());
+    assert_upload_requests(requests);
+}